@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER move_group_ancestors ON groups;
+DROP FUNCTION move_group_ancestors();
@@ -0,0 +1,42 @@
+-- Your SQL goes here
+CREATE FUNCTION move_group_ancestors() RETURNS trigger AS $move_group_ancestors$
+    BEGIN
+        IF NEW.parent IS NOT DISTINCT FROM OLD.parent THEN
+            RETURN NEW;
+        END IF;
+
+        IF NEW.parent = NEW.id OR EXISTS (
+            SELECT 1
+            FROM group_ancestors
+            WHERE group_id = NEW.parent AND ancestor_group_id = NEW.id
+        ) THEN
+            RAISE EXCEPTION 'group % cannot be moved below itself', NEW.id;
+        END IF;
+
+        DELETE FROM group_ancestors
+        WHERE group_id IN (
+                SELECT group_id FROM group_ancestors WHERE ancestor_group_id = NEW.id
+                UNION SELECT NEW.id
+            )
+            AND ancestor_group_id IN (
+                SELECT ancestor_group_id FROM group_ancestors WHERE group_id = NEW.id
+            );
+
+        IF NEW.parent IS NOT NULL THEN
+            INSERT INTO group_ancestors(group_id, ancestor_group_id)
+            SELECT subtree.group_id, new_ancestors.ancestor_group_id
+            FROM (
+                SELECT group_id FROM group_ancestors WHERE ancestor_group_id = NEW.id
+                UNION SELECT NEW.id
+            ) AS subtree
+            CROSS JOIN (
+                SELECT ancestor_group_id FROM group_ancestors WHERE group_id = NEW.parent
+                UNION SELECT NEW.parent
+            ) AS new_ancestors;
+        END IF;
+        RETURN NEW;
+    END;
+$move_group_ancestors$ LANGUAGE plpgsql;
+
+CREATE TRIGGER move_group_ancestors AFTER UPDATE OF parent ON groups
+    FOR EACH ROW EXECUTE PROCEDURE move_group_ancestors();
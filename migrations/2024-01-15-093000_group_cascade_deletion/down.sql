@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE revoked_user_permissions;
+
+ALTER TABLE task_packages
+    DROP COLUMN deleted_with;
+
+ALTER TABLE groups
+    DROP COLUMN deleted_with,
+    DROP COLUMN deleted_at;
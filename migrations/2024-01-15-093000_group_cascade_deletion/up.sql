@@ -0,0 +1,24 @@
+-- Your SQL goes here
+ALTER TABLE groups
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_with UUID REFERENCES groups(id);
+
+ALTER TABLE task_packages
+    ADD COLUMN deleted_with UUID;
+
+CREATE TABLE revoked_user_permissions (
+    user_permission_id UUID NOT NULL,
+    access_type ACCESS_TYPE NOT NULL,
+    user_id UUID NOT NULL,
+    ressource VARCHAR(45) NOT NULL,
+    group_id UUID NOT NULL,
+    permission BOOLEAN NOT NULL,
+    set_permission BOOLEAN NOT NULL,
+    set_set_permission BOOLEAN NOT NULL,
+    deleted_with UUID NOT NULL,
+    PRIMARY KEY (user_permission_id, access_type),
+    FOREIGN KEY (user_id) REFERENCES users(id),
+    FOREIGN KEY (group_id) REFERENCES groups(id),
+    FOREIGN KEY (ressource) REFERENCES ressources(key_value),
+    FOREIGN KEY (deleted_with) REFERENCES groups(id)
+);
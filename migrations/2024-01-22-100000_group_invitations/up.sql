@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE group_invitations (
+    id UUID DEFAULT uuid_generate_v4() PRIMARY KEY,
+    group_id UUID NOT NULL,
+    code VARCHAR(16) NOT NULL UNIQUE,
+    email VARCHAR(100),
+    role VARCHAR(45),
+    max_uses INT CHECK (max_uses > 0),
+    uses INT NOT NULL DEFAULT 0,
+    expires_at TIMESTAMP WITH TIME ZONE,
+    state STATE NOT NULL DEFAULT 'active',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT(NOW()) NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT(NOW()) NOT NULL,
+    created_from UUID NOT NULL,
+    FOREIGN KEY (group_id) REFERENCES groups(id),
+    FOREIGN KEY (role) REFERENCES roles(value_key),
+    FOREIGN KEY (created_from) REFERENCES users(id)
+);
+
+CREATE INDEX group_invitations_email_idx ON group_invitations (email) WHERE email IS NOT NULL;
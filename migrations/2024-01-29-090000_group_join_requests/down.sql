@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE group_join_requests;
+ALTER TABLE groups DROP COLUMN join_policy;
+DROP TYPE JOIN_REQUEST_STATE;
+DROP TYPE JOIN_POLICY;
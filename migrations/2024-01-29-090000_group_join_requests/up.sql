@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TYPE JOIN_POLICY AS ENUM('closed', 'request', 'open');
+CREATE TYPE JOIN_REQUEST_STATE AS ENUM('pending', 'approved', 'rejected');
+
+ALTER TABLE groups ADD COLUMN join_policy JOIN_POLICY NOT NULL DEFAULT 'closed';
+
+CREATE TABLE group_join_requests (
+    id UUID DEFAULT uuid_generate_v4() PRIMARY KEY,
+    group_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    message VARCHAR(500),
+    state JOIN_REQUEST_STATE NOT NULL DEFAULT 'pending',
+    decided_at TIMESTAMP WITH TIME ZONE,
+    decided_from UUID,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT(NOW()) NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT(NOW()) NOT NULL,
+    FOREIGN KEY (group_id) REFERENCES groups(id),
+    FOREIGN KEY (user_id) REFERENCES users(id),
+    FOREIGN KEY (decided_from) REFERENCES users(id)
+);
+
+-- A user can only have one open request per group
+CREATE UNIQUE INDEX group_join_requests_pending_idx ON group_join_requests (group_id, user_id) WHERE state = 'pending';
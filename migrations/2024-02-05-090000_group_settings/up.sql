@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE groups ADD COLUMN settings JSONB NOT NULL DEFAULT '{}'::jsonb;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX groups_settings_idx;
+DROP INDEX groups_name_trgm_idx;
+DROP INDEX groups_search_text_trgm_idx;
+
+ALTER TABLE groups DROP COLUMN search_text;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE groups ADD COLUMN search_text TEXT NOT NULL
+    GENERATED ALWAYS AS (name || ' ' || coalesce(settings->>'description', '')) STORED;
+
+CREATE INDEX groups_search_text_trgm_idx ON groups USING GIN (search_text gin_trgm_ops);
+CREATE INDEX groups_name_trgm_idx ON groups USING GIN (name gin_trgm_ops);
+CREATE INDEX groups_settings_idx ON groups USING GIN (settings jsonb_path_ops);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX group_members_group_id_joined_at_idx;
+
+ALTER TABLE group_members DROP COLUMN role;
+ALTER TABLE group_members DROP COLUMN joined_at;
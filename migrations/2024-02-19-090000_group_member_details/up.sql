@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE group_members ADD COLUMN joined_at TIMESTAMPTZ NOT NULL DEFAULT now();
+ALTER TABLE group_members ADD COLUMN role VARCHAR(45);
+
+CREATE INDEX group_members_group_id_joined_at_idx ON group_members (group_id, joined_at);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE answers DROP COLUMN task_revision;
+ALTER TABLE tasks DROP COLUMN task_revision;
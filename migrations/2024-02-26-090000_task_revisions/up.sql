@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE tasks ADD COLUMN task_revision INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE answers ADD COLUMN task_revision INTEGER NOT NULL DEFAULT 1;
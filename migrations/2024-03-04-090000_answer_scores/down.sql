@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE solution_attempts DROP COLUMN max_score;
+ALTER TABLE solution_attempts DROP COLUMN score;
+ALTER TABLE answers DROP COLUMN max_score;
+ALTER TABLE answers DROP COLUMN score;
+ALTER TABLE tasks DROP COLUMN points;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+ALTER TABLE tasks ADD COLUMN points DOUBLE PRECISION NOT NULL DEFAULT 1;
+
+ALTER TABLE answers ADD COLUMN score DOUBLE PRECISION NOT NULL DEFAULT 0;
+ALTER TABLE answers ADD COLUMN max_score DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+ALTER TABLE solution_attempts ADD COLUMN score DOUBLE PRECISION NOT NULL DEFAULT 0;
+ALTER TABLE solution_attempts ADD COLUMN max_score DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+UPDATE answers SET
+    score = CASE WHEN correct THEN 1 ELSE 0 END,
+    max_score = 1
+WHERE state = 'done';
+
+UPDATE solution_attempts SET
+    score = totals.score,
+    max_score = totals.max_score
+FROM (
+    SELECT solution_attempt_id, SUM(score) AS score, SUM(max_score) AS max_score
+    FROM answers
+    WHERE state = 'done'
+    GROUP BY solution_attempt_id
+) AS totals
+WHERE solution_attempts.id = totals.solution_attempt_id;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE answers ADD COLUMN seed BIGINT;
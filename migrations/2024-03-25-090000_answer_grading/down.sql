@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+UPDATE answers SET state = 'done' WHERE state = 'grading';
+
+ALTER TYPE ANSWER_STATE RENAME TO ANSWER_STATE_OLD;
+CREATE TYPE ANSWER_STATE AS ENUM('active', 'deleted', 'done');
+
+ALTER TABLE answers ALTER COLUMN state DROP DEFAULT;
+ALTER TABLE answers ALTER COLUMN state TYPE ANSWER_STATE USING state::TEXT::ANSWER_STATE;
+ALTER TABLE answers ALTER COLUMN state SET DEFAULT 'done';
+
+DROP TYPE ANSWER_STATE_OLD;
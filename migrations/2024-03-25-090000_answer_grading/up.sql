@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE ANSWER_STATE ADD VALUE 'grading';
@@ -10,6 +10,12 @@ pub struct Config {
     pub mongodb_database_name: String,
     pub jwt_secret: String,
     pub use_seeder: bool,
+    pub sandbox_python: String,
+    pub sandbox_rustc: String,
+    pub sandbox_uid: u32,
+    pub sandbox_gid: u32,
+    pub sandbox_mounts: String,
+    pub grading_workers: usize,
 }
 
 impl Config {
@@ -35,6 +41,30 @@ impl Config {
             Ok(v) => v.to_string().parse::<bool>().unwrap(),
             Err(_) => false,
         };
+        let sandbox_python = match env::var("SANDBOX_PYTHON") {
+            Ok(v) => v.to_string(),
+            Err(_) => "python3".to_string(),
+        };
+        let sandbox_rustc = match env::var("SANDBOX_RUSTC") {
+            Ok(v) => v.to_string(),
+            Err(_) => "rustc".to_string(),
+        };
+        let sandbox_uid = match env::var("SANDBOX_UID") {
+            Ok(v) => v.to_string().parse::<u32>().unwrap(),
+            Err(_) => 65534,
+        };
+        let sandbox_gid = match env::var("SANDBOX_GID") {
+            Ok(v) => v.to_string().parse::<u32>().unwrap(),
+            Err(_) => 65534,
+        };
+        let sandbox_mounts = match env::var("SANDBOX_MOUNTS") {
+            Ok(v) => v.to_string(),
+            Err(_) => "/bin:/lib:/lib64:/usr:/etc/ld.so.cache:/etc/alternatives".to_string(),
+        };
+        let grading_workers = match env::var("GRADING_WORKERS") {
+            Ok(v) => v.to_string().parse::<usize>().unwrap(),
+            Err(_) => std::thread::available_parallelism().map(|workers| workers.get()).unwrap_or(1),
+        };
 
         Config { 
             database_url,
@@ -42,6 +72,12 @@ impl Config {
             mongodb_database_name,
             jwt_secret,
             use_seeder,
+            sandbox_python,
+            sandbox_rustc,
+            sandbox_uid,
+            sandbox_gid,
+            sandbox_mounts,
+            grading_workers,
         }
     }
 }
\ No newline at end of file
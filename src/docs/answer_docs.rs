@@ -1,18 +1,22 @@
 use utoipa::OpenApi;
 
+use crate::grading::grader::GradeFeedback;
 use crate::handlers;
 use crate::models::answer::{
     AnswersResponse, AnswerResponse,
     UpdateAnswerSchema, UpdatedAnswerResponse,
 };
 
+use crate::models::task::TaskResponse;
 use crate::models::util::AnswerState;
+use crate::models::validation::{ValidationErrorSchema, ValidationErrorEntry};
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         handlers::groups::group_id::answers::answer_id::answer_id::fetch_answer,
         handlers::groups::group_id::answers::answer_id::answer_id::update_answer,
+        handlers::groups::group_id::answers::answer_id::answer_id::fetch_answer_task,
     ), 
     components(schemas(
         AnswersResponse,
@@ -20,6 +24,10 @@ use crate::models::util::AnswerState;
         UpdateAnswerSchema,
         UpdatedAnswerResponse,
         AnswerState,
+        TaskResponse,
+        ValidationErrorSchema,
+        ValidationErrorEntry,
+        GradeFeedback,
     )), 
     tags(
         (name="answer", description = "These endpoints provide options to view and edit user-submitted responses."),
@@ -1,4 +1,4 @@
-use super::{user_docs, auth_docs, groups_docs, member_docs, task_docs, task_packages_docs, solution_attempts_docs, answer_docs, permission_docs};
+use super::{user_docs, auth_docs, groups_docs, member_docs, invitation_docs, join_request_docs, task_docs, task_packages_docs, solution_attempts_docs, answer_docs, permission_docs};
 
 pub struct ApiDoc;
 
@@ -9,6 +9,8 @@ impl utoipa::OpenApi for ApiDoc {
         open_api.merge(auth_docs::ApiDoc::openapi());
         open_api.merge(groups_docs::ApiDoc::openapi());
         open_api.merge(member_docs::ApiDoc::openapi());
+        open_api.merge(invitation_docs::ApiDoc::openapi());
+        open_api.merge(join_request_docs::ApiDoc::openapi());
         open_api.merge(task_docs::ApiDoc::openapi());
         open_api.merge(task_packages_docs::ApiDoc::openapi());
         open_api.merge(solution_attempts_docs::ApiDoc::openapi());
@@ -1,21 +1,31 @@
 use utoipa::OpenApi;
 
 use crate::models::groups::{AddPermissionSchema, CreateGroupSchema, 
-    CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse, 
+    CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse, UpdateGroupSchema, CloneGroupSchema, CloneGroupResponse, GroupTreeResponse, GroupDescendantsResponse, GroupDescendantResponse, 
     GroupAddMemberSchema, GroupRemoveMemberSchema, PermissionSchema, AddPermissionResponse, GroupsSchema,
-    GroupPagingResponse};
+    GroupPagingResponse, GroupSearchSchema, GroupSearchResponse, GroupSearchResult};
 use crate::models::permissions::{OptionalUserAccessType, PermissionListResponse, PermissionInfo, PermissionListResponseWithCount};
 use crate::handlers;
-use crate::models::util::{PagingSchema, OrderDir, State};
+use crate::models::util::{PagingSchema, OrderDir, State, JoinPolicy};
+use crate::models::group_settings::GroupSettings;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         handlers::groups::groups::create_group,
+        handlers::groups::groups::search_groups,
         handlers::user::groups::groups::get_groups,
+        handlers::user::groups::groups::leave_group,
         handlers::groups::group_id::group_id::get_group,
         handlers::groups::group_id::group_id::get_group_meta_data,
+        handlers::groups::group_id::group_id::update_group,
+        handlers::groups::group_id::group_id::get_group_tree,
+        handlers::groups::group_id::group_id::get_group_descendants,
         handlers::groups::group_id::group_id::delete_group,
+        handlers::groups::group_id::group_id::restore_group,
+        handlers::groups::group_id::group_id::clone_group,
+        handlers::groups::group_id::settings::settings::get_group_settings,
+        handlers::groups::group_id::settings::settings::update_group_settings,
         handlers::user::user::get_my_global_permissions,
         handlers::users::user_id::user_id::add_permissions_to_user,
         handlers::groups::group_id::users::user_id::user_id::get_group_permissions_from_user,
@@ -23,8 +33,19 @@ use crate::models::util::{PagingSchema, OrderDir, State};
     components(schemas(
         CreateGroupSchema,
         CreateGroupResponse,
+        UpdateGroupSchema,
+        CloneGroupSchema,
+        CloneGroupResponse,
+        GroupTreeResponse,
+        GroupDescendantsResponse,
+        GroupDescendantResponse,
         GroupInfoResponse,
+        GroupSearchSchema,
+        GroupSearchResponse,
+        GroupSearchResult,
         GroupMetaDataResponse,
+        JoinPolicy,
+        GroupSettings,
         GroupAddMemberSchema,
         AddPermissionSchema,
         AddPermissionResponse,
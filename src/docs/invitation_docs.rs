@@ -0,0 +1,26 @@
+use utoipa::OpenApi;
+
+use crate::models::invitations::{CreateInvitationSchema, RedeemInvitationSchema,
+    InvitationResponse, InvitationListResponse, RedeemedInvitationResponse};
+use crate::handlers;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::groups::group_id::invitations::invitations::create_invitation,
+        handlers::groups::group_id::invitations::invitations::get_invitations,
+        handlers::groups::group_id::invitations::invitations::revoke_invitation,
+        handlers::user::invitations::invitations::redeem_invitation,
+    ), 
+    components(schemas(
+        CreateInvitationSchema,
+        RedeemInvitationSchema,
+        InvitationResponse,
+        InvitationListResponse,
+        RedeemedInvitationResponse,
+    )), 
+    tags(
+        (name="invitation", description = "Join codes and invitations for groups."),
+    ), 
+)]
+pub struct ApiDoc;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use utoipa::OpenApi;
+
+use crate::models::join_requests::{CreateJoinRequestSchema, JoinRequestResponse, JoinRequestListResponse};
+use crate::models::util::JoinRequestState;
+use crate::handlers;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::groups::group_id::join_requests::join_requests::create_join_request,
+        handlers::groups::group_id::join_requests::join_requests::get_join_requests,
+        handlers::groups::group_id::join_requests::join_requests::approve_join_request,
+        handlers::groups::group_id::join_requests::join_requests::reject_join_request,
+        handlers::user::join_requests::join_requests::get_my_join_requests,
+    ), 
+    components(schemas(
+        CreateJoinRequestSchema,
+        JoinRequestResponse,
+        JoinRequestListResponse,
+        JoinRequestState,
+    )), 
+    tags(
+        (name="join_request", description = "Requests of users to join a group and their approval."),
+    ), 
+)]
+pub struct ApiDoc;
\ No newline at end of file
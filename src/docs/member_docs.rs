@@ -1,7 +1,9 @@
 use utoipa::OpenApi;
 
 use crate::models::members::{MemberInfoResponse, 
-    MembersPagingSchema, MemberListResponse, MemberListWithCountResponse};
+    MembersPagingSchema, MemberListResponse, MemberListWithCountResponse, OnError, MemberEntryStatus,
+    MemberEntryResult, MemberBatchResponse, GroupMemberResponse, MemberPermissionResponse, MemberSort};
+use crate::models::roster::{ImportMembersForm, MissingUserStrategy, ImportRowStatus, ImportRowResult, ImportMembersResponse};
 use crate::handlers;
 
 #[derive(OpenApi)]
@@ -10,12 +12,26 @@ use crate::handlers;
         handlers::groups::group_id::members::members::add_member_to_group,
         handlers::groups::group_id::members::members::remove_member_from_group,
         handlers::groups::group_id::members::members::get_group_members,
+        handlers::groups::group_id::members::roster::import_members,
+        handlers::groups::group_id::members::roster::export_members,
     ), 
     components(schemas(
         MemberInfoResponse, 
         MembersPagingSchema, 
         MemberListResponse,
         MemberListWithCountResponse,
+        GroupMemberResponse,
+        MemberPermissionResponse,
+        MemberSort,
+        OnError,
+        MemberEntryStatus,
+        MemberEntryResult,
+        MemberBatchResponse,
+        ImportMembersForm,
+        MissingUserStrategy,
+        ImportRowStatus,
+        ImportRowResult,
+        ImportMembersResponse,
     )), 
     tags(
         (name="member", description = "Group members."),
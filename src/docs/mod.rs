@@ -3,6 +3,8 @@ mod auth_docs;
 mod user_docs;
 mod groups_docs;
 mod member_docs;
+mod invitation_docs;
+mod join_request_docs;
 mod task_docs;
 mod task_packages_docs;
 mod solution_attempts_docs;
@@ -4,6 +4,7 @@ use crate::handlers;
 use crate::models::task::{
     CreateTaskRequest,
     CreateTaskResponse,
+    UpdateTaskRequest,
     TaskPagingResponse,
     TasksResponse,
     TaskResponse,
@@ -13,8 +14,19 @@ use crate::models::task::{
     SchemaPagingSchema,
     RemoveTasksFromPackageResponse,
     RemoveTasksFromPackageSchema,
-    TaskDoc
+    TaskDoc,
+    SchemaVersionRequest,
+    SchemaCheckResponse,
+    SchemaCheckTaskEntry,
+    SchemaCheckAnswerEntry,
+    TaskMetadata,
+    Difficulty,
+    TaskFacets,
+    FacetCount,
+    TaskSort,
 };
+use crate::models::validation::{ValidationErrorSchema, ValidationErrorEntry};
+use crate::repository::mongodb::SchemaDoc;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -22,8 +34,12 @@ use crate::models::task::{
         handlers::tasks::tasks::create_task,
         handlers::tasks::tasks::create_schema,
         handlers::tasks::tasks::fetch_schemas,
+        handlers::tasks::tasks::create_schema_version,
+        handlers::tasks::tasks::deprecate_schema,
+        handlers::tasks::tasks::check_schema,
         handlers::tasks::task_id::task_id::fetch_task,
         handlers::tasks::task_id::task_id::delete_task,
+        handlers::tasks::task_id::task_id::update_task,
         handlers::tasks::task_id::task_id::fetch_task_solution,
         handlers::tasks::tasks::fetch_tasks,
         handlers::groups::group_id::task_packages::task_package_id::tasks::tasks::add_tasks_to_package,
@@ -33,6 +49,7 @@ use crate::models::task::{
     components(schemas(
         CreateTaskRequest,
         CreateTaskResponse,
+        UpdateTaskRequest,
         TaskPagingResponse,
         TasksResponse,
         TaskResponse,
@@ -42,7 +59,19 @@ use crate::models::task::{
         SchemaPagingSchema,
         RemoveTasksFromPackageResponse,
         RemoveTasksFromPackageSchema,
-        TaskDoc
+        TaskDoc,
+        SchemaDoc,
+        SchemaVersionRequest,
+        SchemaCheckResponse,
+        SchemaCheckTaskEntry,
+        SchemaCheckAnswerEntry,
+        TaskMetadata,
+        Difficulty,
+        TaskFacets,
+        FacetCount,
+        TaskSort,
+        ValidationErrorSchema,
+        ValidationErrorEntry,
     )), 
     tags(
         (name="task", description = "task endpoints."),
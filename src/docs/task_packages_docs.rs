@@ -11,7 +11,8 @@ use crate::models::task_package::{
 use crate::models::task::{
     AddTasksToPackageSchema, AddedTasksToPackageResponse,
     Task, TasksFromPackageResponse,
-    RemoveTasksFromPackageSchema
+    RemoveTasksFromPackageSchema,
+    UpdateTaskPointsSchema, TaskPoints,
 };
 
 use crate::models::util::TaskPackageType;
@@ -23,6 +24,7 @@ use crate::models::util::TaskPackageType;
         handlers::groups::group_id::task_packages::task_packages::fetch_task_packages,
         handlers::groups::group_id::task_packages::task_package_id::users::user_id::user_id::fetch_task_package_statistic,
         handlers::groups::group_id::task_packages::task_package_id::tasks::tasks::get_tasks_infos_from_package,
+        handlers::groups::group_id::task_packages::task_package_id::tasks::tasks::update_task_points,
     ), 
     components(schemas(
         CreateTaskPackageSchema,
@@ -34,6 +36,8 @@ use crate::models::util::TaskPackageType;
         Task,
         TasksFromPackageResponse,
         RemoveTasksFromPackageSchema,
+        UpdateTaskPointsSchema,
+        TaskPoints,
         TaskPackageType,
         TaskPackageUserStatisticResponse,
         TaskPackageUserStatisticValue,
@@ -0,0 +1,306 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use mongodb::bson::{Bson, Document};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `Finite-Automaton` tasks, whose solutions and answers are automata over the `alphabet` of the task.
+///
+/// An answer is correct if it accepts the same language as the reference automaton. Both automata may be
+/// nondeterministic, with transitions on the empty symbol `""` as ε-transitions, unless the task is `deterministic`.
+/// The languages are compared on the product of both automata, determinised on the fly and explored breadth first,
+/// so a mismatch is reported with a shortest word accepted by only one of them.
+pub struct AutomatonGrader;
+
+struct Automaton {
+    /// Transitions by state and symbol, the empty symbol standing for ε-transitions.
+    transitions: HashMap<(usize, String), BTreeSet<usize>>,
+    start: usize,
+    accepting: HashSet<usize>,
+}
+
+/// A set of states of an automaton, the state of its determinised automaton.
+type StateSet = BTreeSet<usize>;
+
+/// Limits of the automata, matching the schema of the task type, so they can be compared in reasonable time.
+const MAX_SYMBOLS: usize = 20;
+const MAX_STATES: usize = 100;
+const MAX_TRANSITIONS: usize = 2000;
+
+/// How many states of the product of both automata are explored before the comparison is given up.
+const MAX_EXPLORED_STATES: usize = 100_000;
+
+/// The result of comparing the languages of two automata.
+enum Comparison {
+    Equal,
+    /// A shortest word accepted by only one of the automata.
+    Different(Vec<String>),
+    /// The product of the automata has more than `MAX_EXPLORED_STATES` states.
+    TooLarge,
+}
+
+fn automaton_error(instance_path: String, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path,
+        schema_path: String::new(),
+        keyword: "automaton".to_string(),
+        message,
+    }
+}
+
+fn string_list(document: &Document, key: &str) -> Vec<String> {
+    document.get_array(key)
+        .map(|values| values.iter().filter_map(Bson::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Checks that the list at `key` has at most `max` entries.
+fn check_length(document: &Document, key: &str, max: usize, errors: &mut Vec<ValidationErrorEntry>) {
+    let length = document.get_array(key).map(|values| values.len()).unwrap_or_default();
+
+    if length > max {
+        errors.push(automaton_error(format!("/{}", key), format!("At most {} entries are allowed, but there are {}", max, length)));
+    }
+}
+
+impl Automaton {
+    /// Reads an automaton, collecting every state and symbol it uses without defining it.
+    fn parse(task: &Document, automaton: &Document) -> Result<Automaton, Vec<ValidationErrorEntry>> {
+        let task_alphabet: HashSet<String> = string_list(task, "alphabet").into_iter().collect();
+        let alphabet = string_list(automaton, "alphabet");
+        let deterministic = task.get_bool("deterministic").unwrap_or(false);
+        let mut errors = vec![];
+
+        check_length(automaton, "states", MAX_STATES, &mut errors);
+        check_length(automaton, "alphabet", MAX_SYMBOLS, &mut errors);
+        check_length(automaton, "transitions", MAX_TRANSITIONS, &mut errors);
+
+        // oversized automata are rejected before their transitions are read
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let states: HashMap<String, usize> = string_list(automaton, "states").into_iter()
+            .enumerate()
+            .map(|(index, state)| (state, index))
+            .collect();
+
+        for (index, symbol) in alphabet.iter().enumerate() {
+            if !task_alphabet.contains(symbol) {
+                errors.push(automaton_error(format!("/alphabet/{}", index), format!("\"{}\" is no symbol of the alphabet of the task", symbol)));
+            }
+        }
+
+        let start = automaton.get_str("start").unwrap_or_default();
+        let start = match states.get(start) {
+            Some(start) => *start,
+            None => {
+                errors.push(automaton_error("/start".to_string(), format!("The start state \"{}\" is no state", start)));
+                0
+            },
+        };
+
+        let mut accepting = HashSet::new();
+
+        for (index, state) in string_list(automaton, "accepting").iter().enumerate() {
+            match states.get(state) {
+                Some(state) => { accepting.insert(*state); },
+                None => errors.push(automaton_error(format!("/accepting/{}", index), format!("\"{}\" is no state", state))),
+            }
+        }
+
+        let mut transitions: HashMap<(usize, String), BTreeSet<usize>> = HashMap::new();
+        let transition_list = automaton.get_array("transitions").map(|transitions| transitions.as_slice()).unwrap_or_default();
+
+        for (index, transition) in transition_list.iter().enumerate() {
+            let transition = match transition.as_document() {
+                Some(transition) => transition,
+                None => continue,
+            };
+            let path = format!("/transitions/{}", index);
+            let from = transition.get_str("from").unwrap_or_default();
+            let to = transition.get_str("to").unwrap_or_default();
+            let symbol = transition.get_str("symbol").unwrap_or_default();
+
+            if !states.contains_key(from) {
+                errors.push(automaton_error(format!("{}/from", path), format!("\"{}\" is no state", from)));
+            }
+
+            if !states.contains_key(to) {
+                errors.push(automaton_error(format!("{}/to", path), format!("\"{}\" is no state", to)));
+            }
+
+            if symbol.is_empty() && deterministic {
+                errors.push(automaton_error(format!("{}/symbol", path), "A deterministic automaton has no ε-transitions".to_string()));
+            } else if !symbol.is_empty() && !alphabet.iter().any(|letter| letter == symbol) {
+                errors.push(automaton_error(format!("{}/symbol", path), format!("\"{}\" is no symbol of the alphabet", symbol)));
+            }
+
+            if let (Some(from_index), Some(to_index)) = (states.get(from), states.get(to)) {
+                let targets = transitions.entry((*from_index, symbol.to_string())).or_default();
+                targets.insert(*to_index);
+
+                if deterministic && targets.len() > 1 {
+                    errors.push(automaton_error(path, format!("A deterministic automaton has at most one transition from \"{}\" on \"{}\"", from, symbol)));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Automaton { transitions, start, accepting })
+    }
+
+    /// Adds every state reachable through ε-transitions.
+    fn closure(&self, mut states: StateSet) -> StateSet {
+        let mut pending: Vec<usize> = states.iter().copied().collect();
+
+        while let Some(state) = pending.pop() {
+            for target in self.transitions.get(&(state, String::new())).into_iter().flatten() {
+                if states.insert(*target) {
+                    pending.push(*target);
+                }
+            }
+        }
+
+        states
+    }
+
+    fn start_states(&self) -> StateSet {
+        self.closure(BTreeSet::from([self.start]))
+    }
+
+    fn step(&self, states: &StateSet, symbol: &str) -> StateSet {
+        let targets = states.iter()
+            .flat_map(|state| self.transitions.get(&(*state, symbol.to_string())).into_iter().flatten())
+            .copied()
+            .collect();
+
+        self.closure(targets)
+    }
+
+    fn accepts(&self, states: &StateSet) -> bool {
+        states.iter().any(|state| self.accepting.contains(state))
+    }
+}
+
+/// Searches the product of both automata breadth first for a shortest word accepted by only one of them,
+/// trying the symbols in the order of the alphabet. Determinising may blow up the number of states, so the search
+/// gives up after `MAX_EXPLORED_STATES` states.
+fn compare(first: &Automaton, second: &Automaton, alphabet: &[String]) -> Comparison {
+    let start = (first.start_states(), second.start_states());
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, vec![])]);
+
+    while let Some(((first_states, second_states), word)) = queue.pop_front() {
+        if first.accepts(&first_states) != second.accepts(&second_states) {
+            return Comparison::Different(word);
+        }
+
+        for symbol in alphabet {
+            let next = (first.step(&first_states, symbol), second.step(&second_states, symbol));
+
+            if visited.insert(next.clone()) {
+                if visited.len() > MAX_EXPLORED_STATES {
+                    return Comparison::TooLarge;
+                }
+
+                let mut next_word = word.clone();
+                next_word.push(symbol.clone());
+                queue.push_back((next, next_word));
+            }
+        }
+    }
+
+    Comparison::Equal
+}
+
+/// Writes a word, separating the symbols by spaces if one of them is longer than a single character.
+fn display_word(word: &[String]) -> String {
+    if word.is_empty() {
+        return "ε".to_string();
+    }
+
+    if word.iter().all(|symbol| symbol.chars().count() == 1) {
+        return word.concat();
+    }
+
+    word.join(" ")
+}
+
+impl Grader for AutomatonGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let solution = match Automaton::parse(task, solution) {
+            Ok(solution) => solution,
+            Err(_) => return Grade::new(0.0, 1.0, vec![GradeFeedback {
+                path: String::new(),
+                correct: false,
+                message: "The automaton of the solution is invalid".to_string(),
+            }]),
+        };
+
+        let answer = match Automaton::parse(task, answer) {
+            Ok(answer) => answer,
+            Err(errors) => return Grade::new(0.0, 1.0, errors.into_iter().map(|error| GradeFeedback {
+                path: error.instance_path,
+                correct: false,
+                message: error.message,
+            }).collect()),
+        };
+
+        let mut alphabet = string_list(task, "alphabet");
+        alphabet.sort();
+
+        let feedback = match compare(&answer, &solution, &alphabet) {
+            Comparison::Equal => GradeFeedback {
+                path: String::new(),
+                correct: true,
+                message: "The automaton accepts the language of the solution".to_string(),
+            },
+            Comparison::TooLarge => GradeFeedback {
+                path: String::new(),
+                correct: false,
+                message: "The automaton is too large to be compared with the solution".to_string(),
+            },
+            Comparison::Different(word) => {
+                let accepted = word.iter().fold(answer.start_states(), |states, symbol| answer.step(&states, symbol));
+
+                GradeFeedback {
+                    path: String::new(),
+                    correct: false,
+                    message: if answer.accepts(&accepted) {
+                        format!("The automaton accepts \"{}\", which is not in the language", display_word(&word))
+                    } else {
+                        format!("The automaton does not accept \"{}\", which is in the language", display_word(&word))
+                    },
+                }
+            },
+        };
+
+        Grade::new(if feedback.correct { 1.0 } else { 0.0 }, 1.0, vec![feedback])
+    }
+
+    fn validate_task(&self, task: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let mut errors = vec![];
+
+        check_length(task, "alphabet", MAX_SYMBOLS, &mut errors);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Automaton::parse(task, solution).map(|_| ())
+    }
+
+    fn validate_answer(&self, task: &Document, answer: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Automaton::parse(task, answer).map(|_| ())
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::{BTreeSet, HashSet};
+
+use mongodb::bson::{Bson, Document};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `CYK-Algorithm` tasks by running CYK on the grammar in Chomsky normal form and the word of the task.
+///
+/// The table is stored row by row from left to right, starting with the cells of the single symbols of the word
+/// and ending with the cell of the whole word. Every cell scores one point if it lists exactly the variables deriving its substring.
+pub struct CykGrader;
+
+struct Grammar {
+    variables: HashSet<String>,
+    /// Productions `A -> BC`.
+    binary_productions: Vec<(String, String, String)>,
+    /// Productions `A -> a`.
+    terminal_productions: Vec<(String, String)>,
+    word: Vec<String>,
+}
+
+fn cnf_error(instance_path: &str, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path: instance_path.to_string(),
+        schema_path: String::new(),
+        keyword: "cnf".to_string(),
+        message,
+    }
+}
+
+fn string_list(task: &Document, key: &str) -> Vec<String> {
+    task.get_array(key)
+        .map(|values| values.iter().filter_map(Bson::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Splits `word` into terminals, preferring the longest terminal at every position that still lets the rest of the
+/// word be split.
+fn split_word(word: &str, terminals: &HashSet<String>) -> Option<Vec<String>> {
+    // `next[i]` is the terminal to take at byte `i` so that the rest of the word can be split
+    let mut next: Vec<Option<&String>> = vec![None; word.len() + 1];
+
+    for start in (0..word.len()).rev().filter(|start| word.is_char_boundary(*start)) {
+        next[start] = terminals.iter()
+            .filter(|terminal| !terminal.is_empty() && word[start..].starts_with(terminal.as_str()))
+            .filter(|terminal| start + terminal.len() == word.len() || next[start + terminal.len()].is_some())
+            .max_by_key(|terminal| terminal.len());
+    }
+
+    let mut symbols = vec![];
+    let mut position = 0;
+
+    while position < word.len() {
+        let terminal = next[position]?;
+
+        symbols.push(terminal.clone());
+        position += terminal.len();
+    }
+
+    Some(symbols)
+}
+
+impl Grammar {
+    /// Reads the grammar `(V, T, P, S)` and the word `w` of a task, collecting every violation of the Chomsky normal form.
+    fn parse(task: &Document) -> Result<Grammar, Vec<ValidationErrorEntry>> {
+        let variables: HashSet<String> = string_list(task, "V").into_iter().collect();
+        let terminals: HashSet<String> = string_list(task, "T").into_iter().collect();
+        let start = task.get_str("S").unwrap_or_default();
+        let mut errors = vec![];
+
+        if !variables.contains(start) {
+            errors.push(cnf_error("/S", format!("The start symbol \"{}\" is not a variable", start)));
+        }
+
+        for (index, terminal) in string_list(task, "T").iter().enumerate() {
+            if variables.contains(terminal) {
+                errors.push(cnf_error(&format!("/T/{}", index), format!("\"{}\" is a variable and a terminal", terminal)));
+            }
+        }
+
+        let mut binary_productions = vec![];
+        let mut terminal_productions = vec![];
+        let mut start_produces_empty_word = false;
+        let mut start_on_right_side = false;
+
+        for (index, production) in task.get_array("P").map(|p| p.as_slice()).unwrap_or_default().iter().enumerate() {
+            let production = match production.as_document() {
+                Some(production) => production,
+                None => continue,
+            };
+            let left = production.get_str("l").unwrap_or_default();
+
+            if !variables.contains(left) {
+                errors.push(cnf_error(&format!("/P/{}/l", index), format!("\"{}\" is not a variable", left)));
+            }
+
+            for (right_index, right) in production.get_array("r").map(|r| r.as_slice()).unwrap_or_default().iter().enumerate() {
+                let path = format!("/P/{}/r/{}", index, right_index);
+                let right: Vec<&str> = right.as_array()
+                    .map(|symbols| symbols.iter().filter_map(Bson::as_str).collect())
+                    .unwrap_or_default();
+
+                match right.as_slice() {
+                    [] if left == start => start_produces_empty_word = true,
+                    [terminal] if terminals.contains(*terminal) => {
+                        terminal_productions.push((left.to_string(), terminal.to_string()));
+                    },
+                    [first, second] if variables.contains(*first) && variables.contains(*second) => {
+                        start_on_right_side |= *first == start || *second == start;
+                        binary_productions.push((left.to_string(), first.to_string(), second.to_string()));
+                    },
+                    _ => errors.push(cnf_error(&path, format!(
+                        "\"{} -> {}\" is neither of the form A -> BC nor A -> a",
+                        left, right.join(" ")
+                    ))),
+                }
+            }
+        }
+
+        if start_produces_empty_word && start_on_right_side {
+            errors.push(cnf_error("/P", format!(
+                "The start symbol \"{}\" produces the empty word but occurs on the right side of a production", start
+            )));
+        }
+
+        let word = task.get_str("w").unwrap_or_default();
+        let word = match split_word(word, &terminals) {
+            Some(word) if !word.is_empty() => word,
+            _ => {
+                errors.push(cnf_error("/w", format!("\"{}\" is not a non-empty word over the terminals", word)));
+                vec![]
+            }
+        };
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Grammar { variables, binary_productions, terminal_productions, word })
+    }
+
+    /// Computes the CYK table row by row, see [`CykGrader`].
+    fn table(&self) -> Vec<BTreeSet<String>> {
+        let n = self.word.len();
+        let mut rows: Vec<Vec<BTreeSet<String>>> = vec![];
+
+        rows.push(self.word.iter().map(|symbol| {
+            self.terminal_productions.iter()
+                .filter(|(_, terminal)| terminal == symbol)
+                .map(|(variable, _)| variable.clone())
+                .collect()
+        }).collect());
+
+        for length in 2..=n {
+            let row = (0..=n - length).map(|start| {
+                let mut cell = BTreeSet::new();
+
+                for split in 1..length {
+                    let left = &rows[split - 1][start];
+                    let right = &rows[length - split - 1][start + split];
+
+                    for (variable, first, second) in &self.binary_productions {
+                        if left.contains(first) && right.contains(second) {
+                            cell.insert(variable.clone());
+                        }
+                    }
+                }
+
+                cell
+            }).collect();
+
+            rows.push(row);
+        }
+
+        rows.into_iter().flatten().collect()
+    }
+
+    /// The substring of the word a cell of the table stands for.
+    fn cell_substrings(&self) -> Vec<String> {
+        let n = self.word.len();
+
+        (1..=n).flat_map(|length| {
+            (0..=n - length).map(move |start| self.word[start..start + length].concat())
+        }).collect()
+    }
+}
+
+impl Grader for CykGrader {
+    fn grade(&self, task: &Document, _solution: &Document, answer: &Document) -> Grade {
+        let grammar = match Grammar::parse(task) {
+            Ok(grammar) => grammar,
+            Err(_) => return Grade::new(0.0, 1.0, vec![GradeFeedback {
+                path: String::new(),
+                correct: false,
+                message: "The grammar of the task is not in Chomsky normal form".to_string(),
+            }]),
+        };
+
+        let answer_cells = answer.get_array("solution").map(|cells| cells.as_slice()).unwrap_or_default();
+        let mut score = 0.0;
+
+        let feedback: Vec<GradeFeedback> = grammar.table().into_iter()
+            .zip(grammar.cell_substrings())
+            .enumerate()
+            .map(|(index, (cell, substring))| {
+                let answer_cell: Option<BTreeSet<String>> = answer_cells.get(index)
+                    .and_then(Bson::as_document)
+                    .map(|cell| cell.keys().cloned().collect());
+
+                let correct = answer_cell.as_ref() == Some(&cell);
+                let message = match answer_cell {
+                    _ if correct => format!("The cell of \"{}\" is correct", substring),
+                    None => format!("The cell of \"{}\" is missing", substring),
+                    Some(answer_cell) if answer_cell.iter().any(|variable| !grammar.variables.contains(variable)) =>
+                        format!("The cell of \"{}\" contains symbols which are no variables", substring),
+                    Some(answer_cell) if answer_cell.is_subset(&cell) =>
+                        format!("The cell of \"{}\" is missing variables", substring),
+                    Some(_) => format!("The cell of \"{}\" contains variables which do not derive it", substring),
+                };
+
+                if correct {
+                    score += 1.0;
+                }
+
+                GradeFeedback {
+                    path: format!("/solution/{}", index),
+                    correct,
+                    message,
+                }
+            })
+            .collect();
+
+        Grade::new(score, feedback.len() as f64, feedback)
+    }
+
+    fn validate_task(&self, task: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Grammar::parse(task).map(|_| ())
+    }
+
+    fn solve(&self, task: &Document) -> Option<Document> {
+        let table = Grammar::parse(task).ok()?.table();
+
+        let cells: Vec<Bson> = table.into_iter()
+            .map(|cell| Bson::Document(cell.into_iter().map(|variable| (variable, Bson::Null)).collect()))
+            .collect();
+
+        let mut solution = Document::new();
+        solution.insert("solution", cells);
+
+        Some(solution)
+    }
+}
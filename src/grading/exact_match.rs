@@ -0,0 +1,23 @@
+use mongodb::bson::Document;
+
+use crate::models::util::compare_json_objects_ignore_order;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Awards the full score if the answer equals the reference solution, ignoring the order of object keys.
+pub struct ExactMatchGrader;
+
+impl Grader for ExactMatchGrader {
+    fn grade(&self, _task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let correct = match (serde_json::to_value(answer), serde_json::to_value(solution)) {
+            (Ok(answer), Ok(solution)) => compare_json_objects_ignore_order(&answer, &solution),
+            _ => false,
+        };
+
+        Grade::new(if correct { 1.0 } else { 0.0 }, 1.0, vec![GradeFeedback {
+            path: String::new(),
+            correct,
+            message: if correct { "The answer is correct" } else { "The answer does not match the solution" }.to_string(),
+        }])
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::HashMap, sync::Arc};
+
+use mongodb::bson::Document;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{config::Config, models::validation::ValidationErrorEntry};
+
+use super::{
+    automaton::AutomatonGrader,
+    cyk::CykGrader,
+    exact_match::ExactMatchGrader,
+    multiple_choice::MultipleChoiceGrader,
+    numeric::NumericGrader,
+    ordering::{MatchingGrader, OrderingGrader},
+    programming::ProgrammingGrader,
+    sql::SqlGrader,
+    text::TextGrader,
+};
+
+/// Result of grading a single answer.
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Grade {
+    pub score: f64,
+    pub max_score: f64,
+    pub feedback: Vec<GradeFeedback>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GradeFeedback {
+    /// JSON pointer to the graded part of the answer, e.g. `/table/0/1`. Empty for the answer itself.
+    pub path: String,
+    pub correct: bool,
+    pub message: String,
+}
+
+impl Grade {
+    pub fn new(score: f64, max_score: f64, feedback: Vec<GradeFeedback>) -> Self {
+        Grade { score, max_score, feedback }
+    }
+
+    /// The share of the max score reached, between `0` and `1`.
+    pub fn share(&self) -> f64 {
+        if self.max_score <= 0.0 {
+            return 1.0;
+        }
+
+        (self.score / self.max_score).clamp(0.0, 1.0)
+    }
+}
+
+/// Grades the answers of one task type.
+pub trait Grader: Send + Sync {
+    /// Grades `answer` against the reference `solution` of the task document `task`.
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade;
+
+    /// Checks a task document for constraints its JSON schema cannot express. Accepts every task by default.
+    fn validate_task(&self, _task: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Ok(())
+    }
+
+    /// Checks the reference solution of a task document for constraints its JSON schema cannot express,
+    /// e.g. regular expressions which do not compile. Accepts every solution by default.
+    fn validate_solution(&self, _task: &Document, _solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Ok(())
+    }
+
+    /// Checks an answer for constraints its JSON schema cannot express when it is submitted, e.g. states of automata
+    /// which are used without being defined. Accepts every answer by default.
+    fn validate_answer(&self, _task: &Document, _answer: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        Ok(())
+    }
+
+    /// Computes the reference solution of a task document, if it can be derived from the task alone.
+    /// Tasks of graders returning `None` keep the solution written by their author.
+    fn solve(&self, _task: &Document) -> Option<Document> {
+        None
+    }
+
+    /// Whether grading takes too long to wait for it when a solution attempt is finished, e.g. because programs are run.
+    /// Answers of such graders are graded in the background after the attempt is finished.
+    fn grades_in_background(&self) -> bool {
+        false
+    }
+}
+
+/// Maps task types to their grader. Task types without a registered grader are graded by exact match.
+#[derive(Clone)]
+pub struct GraderRegistry {
+    graders: HashMap<String, Arc<dyn Grader>>,
+    default_grader: Arc<dyn Grader>,
+}
+
+impl Default for GraderRegistry {
+    /// Creates the registry with the graders of the built-in task types which need no configuration.
+    fn default() -> Self {
+        let mut registry = GraderRegistry {
+            graders: HashMap::new(),
+            default_grader: Arc::new(ExactMatchGrader),
+        };
+
+        registry.register("Multiple-Choice", Arc::new(MultipleChoiceGrader));
+        registry.register("CYK-Algorithm", Arc::new(CykGrader));
+        registry.register("Numeric", Arc::new(NumericGrader));
+        registry.register("Short-Text", Arc::new(TextGrader));
+        registry.register("Ordering", Arc::new(OrderingGrader));
+        registry.register("Matching", Arc::new(MatchingGrader));
+        registry.register("Finite-Automaton", Arc::new(AutomatonGrader));
+        registry.register("SQL", Arc::new(SqlGrader));
+
+        registry
+    }
+}
+
+impl GraderRegistry {
+    /// Creates the registry with the graders of the built-in task types.
+    pub fn new(env: &Config) -> Self {
+        let mut registry = GraderRegistry::default();
+
+        registry.register("Programming", Arc::new(ProgrammingGrader::new(env)));
+
+        registry
+    }
+
+    /// Registers `grader` for `task_type`, replacing the grader registered before.
+    pub fn register(&mut self, task_type: &str, grader: Arc<dyn Grader>) {
+        self.graders.insert(task_type.to_string(), grader);
+    }
+
+    pub fn get(&self, task_type: &str) -> &dyn Grader {
+        self.graders.get(task_type).unwrap_or(&self.default_grader).as_ref()
+    }
+}
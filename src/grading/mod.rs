@@ -0,0 +1,12 @@
+pub mod grader;
+pub mod exact_match;
+pub mod cyk;
+pub mod automaton;
+pub mod multiple_choice;
+pub mod numeric;
+pub mod text;
+pub mod ordering;
+pub mod programming;
+pub mod sandbox;
+pub mod sql;
+pub mod template;
\ No newline at end of file
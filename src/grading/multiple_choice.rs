@@ -0,0 +1,191 @@
+use std::collections::BTreeSet;
+
+use mongodb::bson::{Bson, Document};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::{
+    grader::{Grade, GradeFeedback, Grader},
+    template::{Generator, PlaceholderGenerator, SeededRng},
+};
+
+/// Grades `Multiple-Choice` tasks. The solution is the index of the correct option of `answers`, or the list of
+/// indices of all correct options for tasks with several correct options.
+///
+/// With the default `all_or_nothing` scoring of the task only the exact selection of correct options scores.
+/// With the `partial` scoring every selected correct option scores one point and every selected wrong option costs
+/// the `penalty` of the task, one point by default, without scoring less than zero.
+pub struct MultipleChoiceGrader;
+
+/// Shuffles the options of `Multiple-Choice` tasks which enable `shuffle`, so every attempt shows them in another order.
+pub struct MultipleChoiceGenerator;
+
+/// The selected options of a solution or answer, `None` for values which are no index.
+fn selection(document: &Document) -> Vec<Option<usize>> {
+    let index = |value: &Bson| match value {
+        Bson::Int32(index) => usize::try_from(*index).ok(),
+        Bson::Int64(index) => usize::try_from(*index).ok(),
+        _ => None,
+    };
+
+    match document.get("solution") {
+        Some(Bson::Array(indices)) => indices.iter().map(index).collect(),
+        Some(value) => vec![index(value)],
+        None => vec![],
+    }
+}
+
+fn option_name(task: &Document, index: usize) -> String {
+    task.get_array("answers").ok()
+        .and_then(|options| options.get(index))
+        .and_then(Bson::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn option_count(task: &Document) -> usize {
+    task.get_array("answers").map(|options| options.len()).unwrap_or_default()
+}
+
+fn map_index(value: &Bson, permutation: &[usize]) -> Bson {
+    let original = match value {
+        Bson::Int32(index) => usize::try_from(*index).ok().and_then(|index| permutation.get(index)),
+        Bson::Int64(index) => usize::try_from(*index).ok().and_then(|index| permutation.get(index)),
+        _ => None,
+    };
+
+    match original {
+        Some(original) => Bson::Int64(*original as i64),
+        None => value.clone(),
+    }
+}
+
+impl Grader for MultipleChoiceGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let correct_options: BTreeSet<usize> = selection(solution).into_iter().flatten().collect();
+        let picks = selection(answer);
+        let multiple_picks = matches!(answer.get("solution"), Some(Bson::Array(_)));
+
+        let mut selected = BTreeSet::new();
+        let mut feedback = vec![];
+
+        for (position, pick) in picks.iter().enumerate() {
+            let path = if multiple_picks { format!("/solution/{}", position) } else { "/solution".to_string() };
+
+            let (correct, message) = match pick {
+                Some(pick) if !selected.insert(*pick) => (false, format!("\"{}\" is selected more than once", option_name(task, *pick))),
+                Some(pick) if correct_options.contains(pick) => (true, format!("\"{}\" is correct", option_name(task, *pick))),
+                Some(pick) if *pick < option_count(task) => (false, format!("\"{}\" is wrong", option_name(task, *pick))),
+                _ => (false, "The selection is no option of the task".to_string()),
+            };
+
+            feedback.push(GradeFeedback { path, correct, message });
+        }
+
+        let hits = selected.intersection(&correct_options).count();
+        let wrong_picks = feedback.iter().filter(|feedback| !feedback.correct).count();
+        let missing = correct_options.len() - hits;
+
+        if missing > 0 {
+            feedback.push(GradeFeedback {
+                path: "/solution".to_string(),
+                correct: false,
+                message: format!("{} correct options are not selected", missing),
+            });
+        }
+
+        match task.get_str("scoring") {
+            Ok("partial") => {
+                let penalty = task.get_f64("penalty").ok()
+                    .or_else(|| task.get_i32("penalty").ok().map(f64::from))
+                    .or_else(|| task.get_i64("penalty").ok().map(|penalty| penalty as f64))
+                    .unwrap_or(1.0);
+                let score = (hits as f64 - penalty * wrong_picks as f64).max(0.0);
+
+                Grade::new(score, correct_options.len().max(1) as f64, feedback)
+            },
+            _ => {
+                let correct = missing == 0 && wrong_picks == 0;
+
+                Grade::new(if correct { 1.0 } else { 0.0 }, 1.0, feedback)
+            },
+        }
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let options = option_count(task);
+        let multiple_picks = matches!(solution.get("solution"), Some(Bson::Array(_)));
+        let mut selected = BTreeSet::new();
+        let mut errors = vec![];
+
+        let error = |instance_path: String, message: String| ValidationErrorEntry {
+            instance_path,
+            schema_path: String::new(),
+            keyword: "options".to_string(),
+            message,
+        };
+
+        let picks = selection(solution);
+
+        if picks.is_empty() {
+            errors.push(error("/solution".to_string(), "At least one option has to be correct".to_string()));
+        }
+
+        for (position, pick) in picks.iter().enumerate() {
+            let path = if multiple_picks { format!("/solution/{}", position) } else { "/solution".to_string() };
+
+            match pick {
+                Some(pick) if *pick < options && selected.insert(*pick) => (),
+                Some(pick) if *pick < options => errors.push(error(path, format!("\"{}\" is listed more than once", option_name(task, *pick)))),
+                _ => errors.push(error(path, format!("The solution has to be one of the {} options", options))),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+impl Generator for MultipleChoiceGenerator {
+    fn generate(&self, task: &Document, solution: &Document, parameters: &Document, rng: &mut SeededRng) -> Result<(Document, Document), Vec<ValidationErrorEntry>> {
+        PlaceholderGenerator.generate(task, solution, parameters, rng)
+    }
+
+    fn permutation(&self, task: &Document, rng: &mut SeededRng) -> Option<Vec<usize>> {
+        if !task.get_bool("shuffle").unwrap_or(false) || option_count(task) < 2 {
+            return None;
+        }
+
+        Some(rng.permutation(option_count(task)))
+    }
+
+    fn shuffle(&self, task: &Document, permutation: &[usize]) -> Document {
+        let options = match task.get_array("answers") {
+            Ok(options) if options.len() == permutation.len() && permutation.iter().all(|index| *index < options.len()) => options,
+            _ => return task.clone(),
+        };
+
+        let shuffled: Vec<Bson> = permutation.iter().map(|index| options[*index].clone()).collect();
+
+        let mut task = task.clone();
+        task.insert("answers", shuffled);
+
+        task
+    }
+
+    fn unshuffle_answer(&self, answer: &Document, permutation: &[usize]) -> Document {
+        let solution = match answer.get("solution") {
+            Some(Bson::Array(picks)) => Bson::Array(picks.iter().map(|pick| map_index(pick, permutation)).collect()),
+            Some(pick) => map_index(pick, permutation),
+            None => return answer.clone(),
+        };
+
+        let mut answer = answer.clone();
+        answer.insert("solution", solution);
+
+        answer
+    }
+}
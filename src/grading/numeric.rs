@@ -0,0 +1,113 @@
+use mongodb::bson::{Bson, Document};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `Numeric` tasks, accepting answers within the tolerance of the task around the reference solution.
+///
+/// The tolerance is the larger one of the `absolute` tolerance and the `relative` tolerance times the solution.
+/// Answers and solutions may be given in any of the `units` of the task, which map unit names to their factor
+/// relative to the `unit` of the task. Answers without a unit are read in the unit of the task.
+pub struct NumericGrader;
+
+/// Differences below this share of the solution are rounding errors of unit conversions, not wrong answers.
+const ROUNDING_ERROR: f64 = 1e-9;
+
+fn number(value: Option<&Bson>) -> Option<f64> {
+    match value? {
+        Bson::Int32(value) => Some(*value as f64),
+        Bson::Int64(value) => Some(*value as f64),
+        Bson::Double(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// The factor converting values in `unit` to the unit of the task, `None` for units the task does not accept.
+fn unit_factor(task: &Document, unit: Option<&str>) -> Option<f64> {
+    let unit = match unit {
+        Some(unit) if Some(unit) != task.get_str("unit").ok() => unit,
+        _ => return Some(1.0),
+    };
+
+    task.get_document("units").ok()
+        .and_then(|units| number(units.get(unit)))
+        .filter(|factor| factor.is_finite() && *factor > 0.0)
+}
+
+/// The value of a solution or answer in the unit of the task.
+fn value(task: &Document, document: &Document) -> Result<f64, String> {
+    let value = number(document.get("solution")).ok_or("The answer is not a number".to_string())?;
+    let unit = document.get_str("unit").ok();
+
+    match unit_factor(task, unit) {
+        Some(factor) => Ok(value * factor),
+        None => Err(format!("The unit \"{}\" is not accepted", unit.unwrap_or_default())),
+    }
+}
+
+fn unit_error(instance_path: &str, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path: instance_path.to_string(),
+        schema_path: String::new(),
+        keyword: "unit".to_string(),
+        message,
+    }
+}
+
+impl Grader for NumericGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let tolerance = task.get_document("tolerance").ok();
+        let absolute = number(tolerance.and_then(|tolerance| tolerance.get("absolute"))).unwrap_or(0.0);
+        let relative = number(tolerance.and_then(|tolerance| tolerance.get("relative"))).unwrap_or(0.0);
+
+        let result = value(task, solution).and_then(|solution| Ok((solution, value(task, answer)?)));
+
+        let (correct, message) = match result {
+            Ok((solution, answer)) => {
+                let allowed = absolute.max(relative * solution.abs()).max(ROUNDING_ERROR * solution.abs().max(1.0));
+
+                if (answer - solution).abs() <= allowed {
+                    (true, "The answer is correct".to_string())
+                } else {
+                    (false, "The answer is not within the tolerance of the solution".to_string())
+                }
+            },
+            Err(message) => (false, message),
+        };
+
+        Grade::new(if correct { 1.0 } else { 0.0 }, 1.0, vec![GradeFeedback {
+            path: "/solution".to_string(),
+            correct,
+            message,
+        }])
+    }
+
+    fn validate_task(&self, task: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let units = match task.get_document("units") {
+            Ok(units) => units,
+            Err(_) => return Ok(()),
+        };
+
+        let errors: Vec<ValidationErrorEntry> = units.iter()
+            .filter(|(_, factor)| !number(Some(factor)).is_some_and(|factor| factor.is_finite() && factor > 0.0))
+            .map(|(unit, _)| unit_error(&format!("/units/{}", unit), format!("The factor of \"{}\" has to be a positive number", unit)))
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let unit = solution.get_str("unit").ok();
+
+        if unit_factor(task, unit).is_none() {
+            return Err(vec![unit_error("/unit", format!("The unit \"{}\" is not accepted by the task", unit.unwrap_or_default()))]);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use mongodb::bson::{Bson, Document};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `Ordering` tasks, whose solutions list the indices of the `items` of the task in the correct order.
+///
+/// With the `position` scoring of the task every item in its correct position scores one point. With the `pairwise`
+/// scoring every pair of items the answer orders like the solution scores one point, so a single misplaced item
+/// only costs the pairs it is part of.
+pub struct OrderingGrader;
+
+/// Grades `Matching` tasks, whose solutions list the index of the matching item of `right` for every item of `left`.
+/// Every correctly matched item of `left` scores one point.
+pub struct MatchingGrader;
+
+fn indices(document: &Document) -> Vec<Option<usize>> {
+    document.get_array("solution")
+        .map(|indices| indices.iter().map(|index| match index {
+            Bson::Int32(index) => usize::try_from(*index).ok(),
+            Bson::Int64(index) => usize::try_from(*index).ok(),
+            _ => None,
+        }).collect())
+        .unwrap_or_default()
+}
+
+fn item_count(task: &Document, key: &str) -> usize {
+    task.get_array(key).map(|items| items.len()).unwrap_or_default()
+}
+
+fn item_name(task: &Document, key: &str, index: usize) -> String {
+    task.get_array(key).ok()
+        .and_then(|items| items.get(index))
+        .and_then(Bson::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn solution_error(instance_path: &str, keyword: &str, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path: instance_path.to_string(),
+        schema_path: String::new(),
+        keyword: keyword.to_string(),
+        message,
+    }
+}
+
+/// Scores every position of the answer holding the item of the solution.
+fn grade_positions(task: &Document, solution: &[Option<usize>], answer: &[Option<usize>]) -> Grade {
+    let feedback: Vec<GradeFeedback> = solution.iter().enumerate().map(|(position, item)| {
+        let correct = answer.get(position) == Some(item);
+
+        GradeFeedback {
+            path: format!("/solution/{}", position),
+            correct,
+            message: match answer.get(position).copied().flatten() {
+                _ if correct => format!("\"{}\" is in the correct position", item_name(task, "items", item.unwrap_or_default())),
+                Some(answer_item) => format!("\"{}\" is not in the correct position", item_name(task, "items", answer_item)),
+                None => format!("The item in position {} is missing", position + 1),
+            },
+        }
+    }).collect();
+
+    let score = feedback.iter().filter(|feedback| feedback.correct).count();
+
+    Grade::new(score as f64, solution.len() as f64, feedback)
+}
+
+/// Scores every pair of items the answer orders like the solution. Items missing in the answer are in no pair.
+fn grade_pairs(solution: &[Option<usize>], answer: &[Option<usize>]) -> Grade {
+    let mut answer_positions = HashMap::new();
+
+    for (position, item) in answer.iter().enumerate() {
+        answer_positions.entry(*item).or_insert(position);
+    }
+
+    let mut pairs = 0;
+    let mut agreeing_pairs = 0;
+
+    for (first_position, first) in solution.iter().enumerate() {
+        for second in &solution[first_position + 1..] {
+            pairs += 1;
+
+            if let (Some(first), Some(second)) = (answer_positions.get(first), answer_positions.get(second)) {
+                if first < second {
+                    agreeing_pairs += 1;
+                }
+            }
+        }
+    }
+
+    let correct = agreeing_pairs == pairs && answer.len() == solution.len();
+
+    Grade::new(agreeing_pairs as f64, pairs as f64, vec![GradeFeedback {
+        path: "/solution".to_string(),
+        correct,
+        message: format!("{} of {} pairs of items are in the correct order", agreeing_pairs, pairs),
+    }])
+}
+
+impl Grader for OrderingGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let solution = indices(solution);
+        let answer = indices(answer);
+
+        match task.get_str("scoring") {
+            Ok("pairwise") if solution.len() > 1 => grade_pairs(&solution, &answer),
+            _ => grade_positions(task, &solution, &answer),
+        }
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let items = item_count(task, "items");
+        let solution = indices(solution);
+        let mut seen = vec![false; items];
+        let mut errors = vec![];
+
+        if solution.len() != items {
+            errors.push(solution_error("/solution", "permutation", format!("The solution has to order all {} items", items)));
+        }
+
+        for (position, item) in solution.iter().enumerate() {
+            match item {
+                Some(item) if *item < items && !seen[*item] => seen[*item] = true,
+                _ => errors.push(solution_error(
+                    &format!("/solution/{}", position), "permutation", "Every item has to occur exactly once".to_string()
+                )),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+impl Grader for MatchingGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let answer = indices(answer);
+
+        let feedback: Vec<GradeFeedback> = indices(solution).iter().enumerate().map(|(index, item)| {
+            let correct = answer.get(index) == Some(item);
+
+            GradeFeedback {
+                path: format!("/solution/{}", index),
+                correct,
+                message: match answer.get(index) {
+                    _ if correct => format!("\"{}\" is matched correctly", item_name(task, "left", index)),
+                    Some(_) => format!("\"{}\" is not matched correctly", item_name(task, "left", index)),
+                    None => format!("The match of \"{}\" is missing", item_name(task, "left", index)),
+                },
+            }
+        }).collect();
+
+        let score = feedback.iter().filter(|feedback| feedback.correct).count();
+
+        Grade::new(score as f64, feedback.len() as f64, feedback)
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let left = item_count(task, "left");
+        let right = item_count(task, "right");
+        let solution = indices(solution);
+        let mut errors = vec![];
+
+        if solution.len() != left {
+            errors.push(solution_error("/solution", "matching", format!("The solution has to match all {} items", left)));
+        }
+
+        for (index, item) in solution.iter().enumerate() {
+            if !item.is_some_and(|item| item < right) {
+                errors.push(solution_error(
+                    &format!("/solution/{}", index), "matching", format!("The match has to be an item of the {} right items", right)
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    ffi::OsString,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use mongodb::bson::{Bson, Document};
+
+use crate::{config::Config, models::validation::ValidationErrorEntry};
+
+use super::{
+    grader::{Grade, GradeFeedback, Grader},
+    sandbox::{self, Access, Limits, Output, Sandbox, Termination},
+};
+
+/// Grades `Programming` tasks by running the submitted `code` on the hidden `tests` of the solution in the sandbox.
+///
+/// Every test passes the `input` on stdin and expects the `output` on stdout, ignoring trailing whitespace, and scores
+/// its `points`, one by default. Programs are written in the `language` of the task, `python` or `rust`, and run with
+/// the `limits` of the task. Rust programs are compiled once before the tests are run.
+pub struct ProgrammingGrader {
+    python: String,
+    rustc: String,
+    sandbox: Sandbox,
+}
+
+/// Time and memory limit of a single test if the task does not set them.
+const DEFAULT_TIME_MS: u64 = 2000;
+const DEFAULT_MEMORY_MB: u64 = 256;
+
+/// Bounds of the limits a task may set, the same as in the task schema.
+const MAX_TIME_MS: u64 = 10000;
+const MIN_MEMORY_MB: u64 = 16;
+const MAX_MEMORY_MB: u64 = 1024;
+
+/// The output kept of every run, longer output is cut off.
+const OUTPUT_BYTES: u64 = 1 << 20;
+
+/// Processes and threads of a program, shared with the programs graded at the same time.
+const PROCESSES: u64 = 64;
+
+/// Limits of compiling a Rust program, which is more expensive than running the tests.
+const COMPILE_LIMITS: Limits = Limits {
+    cpu_seconds: 30,
+    memory_bytes: 2 << 30,
+    wall_time: Duration::from_secs(60),
+    output_bytes: 64 << 20,
+    processes: 256,
+};
+
+/// Environment variables the Rust toolchain needs to be found, the programs themselves get an empty environment.
+const COMPILER_ENVIRONMENT: [&str; 5] = ["PATH", "HOME", "RUSTUP_HOME", "RUSTUP_TOOLCHAIN", "CARGO_HOME"];
+
+/// A prepared program, the executable and the arguments running it.
+struct Program {
+    executable: PathBuf,
+    args: Vec<OsString>,
+}
+
+struct TestCase {
+    name: String,
+    input: String,
+    output: String,
+    points: f64,
+}
+
+fn number(value: Option<&Bson>) -> Option<f64> {
+    match value? {
+        Bson::Int32(value) => Some(*value as f64),
+        Bson::Int64(value) => Some(*value as f64),
+        Bson::Double(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn test_cases(solution: &Document) -> Vec<TestCase> {
+    let tests = solution.get_array("tests").map(|tests| tests.as_slice()).unwrap_or_default();
+
+    tests.iter().filter_map(Bson::as_document).enumerate().map(|(index, test)| TestCase {
+        name: test.get_str("name").map(str::to_string).unwrap_or_else(|_| format!("{}", index + 1)),
+        input: test.get_str("input").unwrap_or_default().to_string(),
+        output: test.get_str("output").unwrap_or_default().to_string(),
+        points: number(test.get("points")).unwrap_or(1.0),
+    }).collect()
+}
+
+/// The limits of a single test, clamped to the bounds of the task schema for documents which were not validated against it.
+fn limits(task: &Document) -> Limits {
+    let limits = task.get_document("limits").ok();
+    let time_ms = number(limits.and_then(|limits| limits.get("time_ms")))
+        .map(|time| (time as u64).clamp(1, MAX_TIME_MS))
+        .unwrap_or(DEFAULT_TIME_MS);
+    let memory_mb = number(limits.and_then(|limits| limits.get("memory_mb")))
+        .map(|memory| (memory as u64).clamp(MIN_MEMORY_MB, MAX_MEMORY_MB))
+        .unwrap_or(DEFAULT_MEMORY_MB);
+
+    Limits {
+        cpu_seconds: time_ms.div_ceil(1000).max(1),
+        memory_bytes: memory_mb << 20,
+        wall_time: Duration::from_millis(time_ms),
+        output_bytes: OUTPUT_BYTES,
+        processes: PROCESSES,
+    }
+}
+
+/// Compares outputs line by line, ignoring trailing whitespace of the lines and trailing empty lines.
+fn same_output(expected: &str, actual: &str) -> bool {
+    let lines = |output: &str| -> Vec<String> {
+        let mut lines: Vec<String> = output.lines().map(|line| line.trim_end().to_string()).collect();
+
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    };
+
+    lines(expected) == lines(actual)
+}
+
+fn failure(message: String, max_score: f64) -> Grade {
+    Grade::new(0.0, max_score, vec![GradeFeedback {
+        path: "/code".to_string(),
+        correct: false,
+        message,
+    }])
+}
+
+impl ProgrammingGrader {
+    /// Creates the grader with the interpreter and compiler configured in `SANDBOX_PYTHON` and `SANDBOX_RUSTC`.
+    pub fn new(env: &Config) -> Self {
+        ProgrammingGrader {
+            python: env.sandbox_python.clone(),
+            rustc: env.sandbox_rustc.clone(),
+            sandbox: Sandbox::new(env),
+        }
+    }
+
+    /// Writes the program to `directory` and returns how to run it in the sandbox, compiling it first if necessary.
+    /// Files are passed by their name relative to the workspace, so messages do not show where it is.
+    fn prepare(&self, language: &str, code: &str, directory: &Path) -> Result<Program, String> {
+        let write = |file: &'static str| -> Result<&'static str, String> {
+            fs::write(directory.join(file), code).map_err(|_| "The program could not be prepared".to_string())?;
+
+            Ok(file)
+        };
+
+        match language {
+            "python" => Ok(Program {
+                executable: PathBuf::from(&self.python),
+                args: vec!["-I".into(), write("main.py")?.into()],
+            }),
+            "rust" => {
+                let source = write("main.rs")?;
+
+                let mut compiler = Command::new(&self.rustc);
+                compiler.env_clear()
+                    .envs(COMPILER_ENVIRONMENT.iter().filter_map(|key| std::env::var_os(key).map(|value| (key, value))))
+                    .args(["--edition", "2021", "-O", "-o", "main", source]);
+
+                let output = self.sandbox.run(&mut compiler, directory, Access::ReadWrite, "", &COMPILE_LIMITS).map_err(unavailable)?;
+
+                // the messages of the compiler may quote any file it can read, e.g. through `include_str!`
+                if !output.success() {
+                    return Err("The program does not compile".to_string());
+                }
+
+                Ok(Program { executable: Path::new(sandbox::WORKSPACE).join("main"), args: vec![] })
+            },
+            language => Err(format!("The language \"{}\" is not supported", language)),
+        }
+    }
+}
+
+impl Program {
+    /// Runs the program on the input of `test` with an empty environment and a read-only workspace.
+    fn run(&self, sandbox: &Sandbox, directory: &Path, test: &TestCase, limits: &Limits) -> io::Result<Output> {
+        let mut command = Command::new(&self.executable);
+        command.args(&self.args).env_clear();
+
+        sandbox.run(&mut command, directory, Access::ReadOnly, &test.input, limits)
+    }
+}
+
+fn unavailable(_: io::Error) -> String {
+    "The program could not be run in the sandbox".to_string()
+}
+
+/// Describes the result of a test. The output on stderr is left out, programs could reveal the hidden tests through it.
+fn test_feedback(test: &TestCase, output: &Output) -> GradeFeedback {
+    let passed = output.success() && same_output(&test.output, &output.stdout);
+
+    let message = match output.termination {
+        _ if passed => format!("Test \"{}\" passed", test.name),
+        Termination::TimedOut | Termination::Signaled(libc::SIGXCPU) => format!("Test \"{}\" exceeded the time limit", test.name),
+        Termination::Exited(0) => format!("Test \"{}\" failed, the output is wrong", test.name),
+        Termination::Exited(code) => format!("Test \"{}\" failed with exit code {}", test.name, code),
+        Termination::Signaled(signal) => format!("Test \"{}\" was terminated by signal {}", test.name, signal),
+    };
+
+    GradeFeedback {
+        path: "/code".to_string(),
+        correct: passed,
+        message,
+    }
+}
+
+impl Grader for ProgrammingGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let language = task.get_str("language").unwrap_or_default();
+        let limits = limits(task);
+        let tests = test_cases(solution);
+        let max_score = tests.iter().map(|test| test.points).sum::<f64>();
+
+        let code = match answer.get_str("code") {
+            Ok(code) => code,
+            Err(_) => return failure("The answer contains no program".to_string(), max_score),
+        };
+
+        let directory = match tempfile::tempdir() {
+            Ok(directory) => directory,
+            Err(err) => return failure(unavailable(err), max_score),
+        };
+
+        let program = match self.prepare(language, code, directory.path()) {
+            Ok(program) => program,
+            Err(message) => return failure(message, max_score),
+        };
+
+        let mut score = 0.0;
+        let mut feedback = vec![];
+
+        for test in &tests {
+            let test_feedback = match program.run(&self.sandbox, directory.path(), test, &limits) {
+                Ok(output) => test_feedback(test, &output),
+                Err(err) => GradeFeedback {
+                    path: "/code".to_string(),
+                    correct: false,
+                    message: unavailable(err),
+                },
+            };
+
+            if test_feedback.correct {
+                score += test.points;
+            }
+
+            feedback.push(test_feedback);
+        }
+
+        Grade::new(score, max_score, feedback)
+    }
+
+    fn validate_solution(&self, _task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        if test_cases(solution).is_empty() {
+            return Err(vec![ValidationErrorEntry {
+                instance_path: "/tests".to_string(),
+                schema_path: String::new(),
+                keyword: "tests".to_string(),
+                message: "The solution needs at least one test".to_string(),
+            }]);
+        }
+
+        Ok(())
+    }
+
+    fn grades_in_background(&self) -> bool {
+        true
+    }
+}
+
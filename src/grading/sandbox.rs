@@ -0,0 +1,518 @@
+use std::{
+    collections::HashSet,
+    ffi::{CStr, CString},
+    fs,
+    io::{self, Read, Write},
+    os::unix::{ffi::OsStrExt, fs::chown, process::{CommandExt, ExitStatusExt}},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    ptr,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::config::Config;
+
+/// Limits of a process run in the sandbox.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// CPU time in seconds. The kernel terminates the process with `SIGXCPU` once it is used up.
+    pub cpu_seconds: u64,
+    /// Size of the address space in bytes, allocations beyond it fail.
+    pub memory_bytes: u64,
+    /// Time the process may run, including time it sleeps or waits. It is killed with its process group afterwards.
+    pub wall_time: Duration,
+    /// Size of files the process may write, the size of its `/tmp`, and the amount of its output on stdout and
+    /// stderr which is kept.
+    pub output_bytes: u64,
+    /// Processes and threads the user of the sandbox may run at once, counted across all running sandboxes.
+    pub processes: u64,
+}
+
+/// How a process run in the sandbox ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Termination {
+    Exited(i32),
+    Signaled(i32),
+    /// The process ran longer than the wall time of its limits.
+    TimedOut,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    pub termination: Termination,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Output {
+    pub fn success(&self) -> bool {
+        self.termination == Termination::Exited(0)
+    }
+}
+
+/// Whether the process may write to its workspace, e.g. to store the program it compiles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// Where the workspace is mounted in the sandbox, it is the working directory of the process.
+pub const WORKSPACE: &str = "/sandbox";
+
+/// The devices available in the sandbox.
+const DEVICES: [&str; 4] = ["/dev/null", "/dev/zero", "/dev/random", "/dev/urandom"];
+
+/// How often a running process is checked for having exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs processes isolated from the server.
+///
+/// Every process gets its own mount, PID, network, IPC and UTS namespace and is locked into a root file system which
+/// only contains the paths of `SANDBOX_MOUNTS` read-only, its workspace, a few devices, its own `/proc` and a private
+/// `/tmp`. It runs as `SANDBOX_UID` and `SANDBOX_GID` without privileges and may only make the system calls of
+/// `ALLOWED_SYSCALLS`. Setting this up requires the server to run as root, processes are not run at all otherwise.
+#[derive(Debug, Clone)]
+pub struct Sandbox {
+    uid: libc::uid_t,
+    gid: libc::gid_t,
+    mounts: Vec<PathBuf>,
+}
+
+/// A step of setting up the root file system of a sandbox. The paths are prepared before the process is forked,
+/// as it may not allocate memory afterwards.
+enum Step {
+    Directory(CString),
+    File(CString),
+    Symlink { target: CString, link: CString },
+    /// Binds `source` to `target` and remounts it with `flags`.
+    Bind { source: CString, target: CString, flags: libc::c_ulong },
+    Mount { fstype: CString, target: CString, flags: libc::c_ulong, data: CString },
+}
+
+fn c_path(path: &Path) -> io::Result<CString> {
+    CString::new(path.as_os_str().as_bytes()).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
+}
+
+fn check(result: libc::c_int) -> io::Result<()> {
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Collects the steps creating the root file system in `root`, the paths are the ones in the sandbox.
+struct RootBuilder<'a> {
+    root: &'a Path,
+    created: HashSet<PathBuf>,
+    steps: Vec<Step>,
+}
+
+impl RootBuilder<'_> {
+    fn inside(&self, path: &Path) -> PathBuf {
+        self.root.join(path.strip_prefix("/").unwrap_or(path))
+    }
+
+    /// Creates `path` and every missing parent.
+    fn directory(&mut self, path: &Path) -> io::Result<()> {
+        let mut ancestors: Vec<&Path> = path.ancestors().filter(|ancestor| ancestor.parent().is_some()).collect();
+        ancestors.reverse();
+
+        for ancestor in ancestors {
+            if self.created.insert(ancestor.to_path_buf()) {
+                self.steps.push(Step::Directory(c_path(&self.inside(ancestor))?));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parent(&mut self, path: &Path) -> io::Result<()> {
+        match path.parent() {
+            Some(parent) => self.directory(parent),
+            None => Ok(()),
+        }
+    }
+
+    /// Binds the file or directory `source` of the server to `target`. Symbolic links are copied instead, so they
+    /// resolve within the sandbox.
+    fn bind(&mut self, source: &Path, target: &Path, flags: libc::c_ulong) -> io::Result<()> {
+        let metadata = fs::symlink_metadata(source)?;
+
+        if metadata.file_type().is_symlink() {
+            self.parent(target)?;
+            self.steps.push(Step::Symlink { target: c_path(&fs::read_link(source)?)?, link: c_path(&self.inside(target))? });
+
+            return Ok(());
+        }
+
+        if metadata.is_dir() {
+            self.directory(target)?;
+        } else {
+            self.parent(target)?;
+            self.steps.push(Step::File(c_path(&self.inside(target))?));
+        }
+
+        self.steps.push(Step::Bind { source: c_path(source)?, target: c_path(&self.inside(target))?, flags });
+
+        Ok(())
+    }
+
+    /// Binds `source` like `bind`, skipping it if it does not exist, e.g. `/lib64` on some systems.
+    fn bind_if_exists(&mut self, source: &Path, flags: libc::c_ulong) -> io::Result<()> {
+        match self.bind(source, source, flags) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    fn mount(&mut self, fstype: &str, target: &Path, flags: libc::c_ulong, data: &str) -> io::Result<()> {
+        self.directory(target)?;
+        self.steps.push(Step::Mount {
+            fstype: CString::new(fstype).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?,
+            target: c_path(&self.inside(target))?,
+            flags,
+            data: CString::new(data).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?,
+        });
+
+        Ok(())
+    }
+}
+
+/// Everything the forked process needs to confine itself, prepared before it is forked.
+struct Confinement {
+    root: CString,
+    steps: Vec<Step>,
+    workspace: CString,
+    uid: libc::uid_t,
+    gid: libc::gid_t,
+    limits: Limits,
+    filter: Vec<libc::sock_filter>,
+}
+
+fn set_limit(resource: libc::__rlimit_resource_t, soft: u64, hard: u64) -> io::Result<()> {
+    let limit = libc::rlimit { rlim_cur: soft, rlim_max: hard };
+
+    check(unsafe { libc::setrlimit(resource, &limit) })
+}
+
+fn mount(source: *const libc::c_char, target: &CStr, fstype: *const libc::c_char, flags: libc::c_ulong, data: *const libc::c_char) -> io::Result<()> {
+    check(unsafe { libc::mount(source, target.as_ptr(), fstype, flags, data.cast()) })
+}
+
+impl Step {
+    fn apply(&self) -> io::Result<()> {
+        match self {
+            Step::Directory(path) => check(unsafe { libc::mkdir(path.as_ptr(), 0o755) }),
+            Step::File(path) => {
+                let fd = unsafe { libc::open(path.as_ptr(), libc::O_CREAT | libc::O_WRONLY | libc::O_CLOEXEC, 0o644) };
+                check(fd)?;
+
+                check(unsafe { libc::close(fd) })
+            },
+            Step::Symlink { target, link } => check(unsafe { libc::symlink(target.as_ptr(), link.as_ptr()) }),
+            Step::Bind { source, target, flags } => {
+                mount(source.as_ptr(), target, ptr::null(), libc::MS_BIND | libc::MS_REC, ptr::null())?;
+                mount(ptr::null(), target, ptr::null(), libc::MS_BIND | libc::MS_REMOUNT | flags, ptr::null())
+            },
+            Step::Mount { fstype, target, flags, data } => mount(fstype.as_ptr(), target, fstype.as_ptr(), *flags, data.as_ptr()),
+        }
+    }
+}
+
+impl Confinement {
+    /// Confines the process to its own session and namespaces. The process forks once more, as only its children
+    /// enter the new PID namespace. The child confines itself further and returns to run the command, the parent
+    /// waits for it and exits the way it did, so it stands in for the child towards the server.
+    /// Runs in the forked child before `exec`, so it only makes async-signal-safe system calls.
+    fn enter(&self) -> io::Result<()> {
+        check(unsafe { libc::setsid() })?;
+        check(unsafe { libc::unshare(libc::CLONE_NEWNS | libc::CLONE_NEWPID | libc::CLONE_NEWNET | libc::CLONE_NEWIPC | libc::CLONE_NEWUTS) })?;
+
+        match unsafe { libc::fork() } {
+            -1 => Err(io::Error::last_os_error()),
+            0 => self.confine(),
+            child => supervise(child),
+        }
+    }
+
+    /// Builds the root file system and locks the process into it as unprivileged user.
+    fn confine(&self) -> io::Result<()> {
+        mount(ptr::null(), c"/", ptr::null(), libc::MS_REC | libc::MS_PRIVATE, ptr::null())?;
+        mount(c"tmpfs".as_ptr(), &self.root, c"tmpfs".as_ptr(), libc::MS_NOSUID | libc::MS_NODEV, c"size=64k,mode=755".as_ptr())?;
+
+        for step in &self.steps {
+            step.apply()?;
+        }
+
+        mount(ptr::null(), &self.root, ptr::null(), libc::MS_REMOUNT | libc::MS_RDONLY | libc::MS_NOSUID | libc::MS_NODEV, ptr::null())?;
+
+        check(unsafe { libc::chdir(self.root.as_ptr()) })?;
+        check(unsafe { libc::chroot(c".".as_ptr()) })?;
+        check(unsafe { libc::chdir(self.workspace.as_ptr()) })?;
+
+        check(unsafe { libc::setgroups(0, ptr::null()) })?;
+        check(unsafe { libc::setresgid(self.gid, self.gid, self.gid) })?;
+        check(unsafe { libc::setresuid(self.uid, self.uid, self.uid) })?;
+        // changing the user clears the signal, it is only set afterwards
+        check(unsafe { libc::prctl(libc::PR_SET_PDEATHSIG, libc::SIGKILL as libc::c_ulong) })?;
+        check(unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1 as libc::c_ulong, 0 as libc::c_ulong, 0 as libc::c_ulong, 0 as libc::c_ulong) })?;
+
+        set_limit(libc::RLIMIT_CPU, self.limits.cpu_seconds, self.limits.cpu_seconds + 1)?;
+        set_limit(libc::RLIMIT_AS, self.limits.memory_bytes, self.limits.memory_bytes)?;
+        set_limit(libc::RLIMIT_FSIZE, self.limits.output_bytes, self.limits.output_bytes)?;
+        set_limit(libc::RLIMIT_NPROC, self.limits.processes, self.limits.processes)?;
+        set_limit(libc::RLIMIT_CORE, 0, 0)?;
+
+        let program = libc::sock_fprog { len: self.filter.len() as u16, filter: self.filter.as_ptr() as *mut libc::sock_filter };
+
+        check(unsafe { libc::prctl(libc::PR_SET_SECCOMP, libc::SECCOMP_MODE_FILTER as libc::c_ulong, &program as *const libc::sock_fprog) })
+    }
+}
+
+/// Waits for the confined child and exits the way it did. Closes every file first, so the server sees the end of the
+/// output and learns whether `exec` succeeded from the child alone.
+fn supervise(child: libc::pid_t) -> ! {
+    unsafe {
+        if libc::syscall(libc::SYS_close_range, 0, libc::c_uint::MAX, 0) != 0 {
+            for fd in 0..1024 {
+                libc::close(fd);
+            }
+        }
+
+        let mut status = 0;
+
+        while libc::waitpid(child, &mut status, 0) < 0 {
+            if io::Error::last_os_error().kind() != io::ErrorKind::Interrupted {
+                libc::_exit(127);
+            }
+        }
+
+        if libc::WIFSIGNALED(status) {
+            let signal = libc::WTERMSIG(status);
+            let _ = set_limit(libc::RLIMIT_CORE, 0, 0);
+
+            libc::signal(signal, libc::SIG_DFL);
+            libc::kill(libc::getpid(), signal);
+            libc::_exit(128 + signal);
+        }
+
+        libc::_exit(libc::WEXITSTATUS(status))
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+const AUDIT_ARCH: u32 = 0xc000_003e;
+
+#[cfg(target_arch = "aarch64")]
+const AUDIT_ARCH: u32 = 0xc000_00b7;
+
+/// The system calls of the interpreters, compilers and programs which do not reach beyond the sandbox. Everything
+/// else fails with `ENOSYS`, e.g. sockets, mounts, `ptrace` and namespaces.
+const ALLOWED_SYSCALLS: &[libc::c_long] = &[
+    libc::SYS_read, libc::SYS_write, libc::SYS_readv, libc::SYS_writev, libc::SYS_pread64, libc::SYS_pwrite64,
+    libc::SYS_preadv, libc::SYS_pwritev, libc::SYS_openat, libc::SYS_close, libc::SYS_close_range, libc::SYS_fstat,
+    libc::SYS_newfstatat, libc::SYS_statx, libc::SYS_statfs, libc::SYS_fstatfs, libc::SYS_lseek, libc::SYS_getdents64,
+    libc::SYS_faccessat, libc::SYS_faccessat2, libc::SYS_readlinkat, libc::SYS_mkdirat, libc::SYS_unlinkat,
+    libc::SYS_renameat, libc::SYS_renameat2, libc::SYS_linkat, libc::SYS_symlinkat, libc::SYS_fchmod,
+    libc::SYS_fchmodat, libc::SYS_truncate, libc::SYS_ftruncate, libc::SYS_fallocate, libc::SYS_fsync,
+    libc::SYS_fdatasync, libc::SYS_flock, libc::SYS_utimensat, libc::SYS_copy_file_range, libc::SYS_sendfile,
+    libc::SYS_getcwd, libc::SYS_chdir, libc::SYS_fchdir, libc::SYS_umask, libc::SYS_ioctl, libc::SYS_fcntl,
+    libc::SYS_dup, libc::SYS_dup3, libc::SYS_pipe2, libc::SYS_ppoll, libc::SYS_pselect6, libc::SYS_epoll_create1,
+    libc::SYS_epoll_ctl, libc::SYS_epoll_pwait, libc::SYS_eventfd2, libc::SYS_socketpair,
+    libc::SYS_sendto, libc::SYS_recvfrom, libc::SYS_sendmsg, libc::SYS_recvmsg,
+    libc::SYS_mmap, libc::SYS_mprotect, libc::SYS_munmap, libc::SYS_mremap, libc::SYS_madvise, libc::SYS_msync,
+    libc::SYS_brk, libc::SYS_membarrier,
+    libc::SYS_rt_sigaction, libc::SYS_rt_sigprocmask, libc::SYS_rt_sigreturn, libc::SYS_rt_sigsuspend,
+    libc::SYS_sigaltstack, libc::SYS_kill, libc::SYS_tgkill, libc::SYS_tkill, libc::SYS_restart_syscall,
+    libc::SYS_clone, libc::SYS_clone3, libc::SYS_execve, libc::SYS_wait4, libc::SYS_waitid, libc::SYS_exit,
+    libc::SYS_exit_group, libc::SYS_futex, libc::SYS_set_robust_list, libc::SYS_get_robust_list,
+    libc::SYS_set_tid_address, libc::SYS_rseq, libc::SYS_prctl, libc::SYS_sched_yield, libc::SYS_sched_getaffinity,
+    libc::SYS_sched_getparam, libc::SYS_sched_getscheduler, libc::SYS_getpriority,
+    libc::SYS_getpid, libc::SYS_getppid, libc::SYS_gettid, libc::SYS_getuid, libc::SYS_geteuid, libc::SYS_getgid,
+    libc::SYS_getegid, libc::SYS_getgroups, libc::SYS_getresuid, libc::SYS_getresgid, libc::SYS_getpgid,
+    libc::SYS_getsid, libc::SYS_getrlimit, libc::SYS_prlimit64, libc::SYS_getrusage, libc::SYS_sysinfo,
+    libc::SYS_times, libc::SYS_uname, libc::SYS_getrandom,
+    libc::SYS_clock_gettime, libc::SYS_clock_getres, libc::SYS_clock_nanosleep, libc::SYS_nanosleep,
+    libc::SYS_gettimeofday, libc::SYS_getitimer, libc::SYS_setitimer,
+    #[cfg(target_arch = "x86_64")] libc::SYS_open,
+    #[cfg(target_arch = "x86_64")] libc::SYS_stat,
+    #[cfg(target_arch = "x86_64")] libc::SYS_lstat,
+    #[cfg(target_arch = "x86_64")] libc::SYS_access,
+    #[cfg(target_arch = "x86_64")] libc::SYS_readlink,
+    #[cfg(target_arch = "x86_64")] libc::SYS_getdents,
+    #[cfg(target_arch = "x86_64")] libc::SYS_mkdir,
+    #[cfg(target_arch = "x86_64")] libc::SYS_rmdir,
+    #[cfg(target_arch = "x86_64")] libc::SYS_unlink,
+    #[cfg(target_arch = "x86_64")] libc::SYS_rename,
+    #[cfg(target_arch = "x86_64")] libc::SYS_link,
+    #[cfg(target_arch = "x86_64")] libc::SYS_symlink,
+    #[cfg(target_arch = "x86_64")] libc::SYS_chmod,
+    #[cfg(target_arch = "x86_64")] libc::SYS_creat,
+    #[cfg(target_arch = "x86_64")] libc::SYS_fadvise64,
+    #[cfg(target_arch = "x86_64")] libc::SYS_dup2,
+    #[cfg(target_arch = "x86_64")] libc::SYS_pipe,
+    #[cfg(target_arch = "x86_64")] libc::SYS_poll,
+    #[cfg(target_arch = "x86_64")] libc::SYS_select,
+    #[cfg(target_arch = "x86_64")] libc::SYS_epoll_wait,
+    #[cfg(target_arch = "x86_64")] libc::SYS_fork,
+    #[cfg(target_arch = "x86_64")] libc::SYS_vfork,
+    #[cfg(target_arch = "x86_64")] libc::SYS_arch_prctl,
+    #[cfg(target_arch = "x86_64")] libc::SYS_getpgrp,
+    #[cfg(target_arch = "x86_64")] libc::SYS_time,
+    #[cfg(target_arch = "x86_64")] libc::SYS_alarm,
+];
+
+fn statement(code: u32, k: u32) -> libc::sock_filter {
+    libc::sock_filter { code: code as u16, jt: 0, jf: 0, k }
+}
+
+fn jump(code: u32, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+    libc::sock_filter { code: code as u16, jt, jf, k }
+}
+
+/// Builds the seccomp filter allowing the `ALLOWED_SYSCALLS` of the architecture of the server. System calls of
+/// other architectures, e.g. the x32 ABI, kill the process.
+fn syscall_filter() -> Vec<libc::sock_filter> {
+    const LOAD: u32 = libc::BPF_LD | libc::BPF_W | libc::BPF_ABS;
+    const RETURN: u32 = libc::BPF_RET | libc::BPF_K;
+    const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+    let mut filter = vec![
+        statement(LOAD, 4),
+        jump(libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K, AUDIT_ARCH, 1, 0),
+        statement(RETURN, libc::SECCOMP_RET_KILL_PROCESS),
+        statement(LOAD, 0),
+        jump(libc::BPF_JMP | libc::BPF_JGE | libc::BPF_K, X32_SYSCALL_BIT, 0, 1),
+        statement(RETURN, libc::SECCOMP_RET_KILL_PROCESS),
+    ];
+
+    for syscall in ALLOWED_SYSCALLS {
+        filter.push(jump(libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K, *syscall as u32, 0, 1));
+        filter.push(statement(RETURN, libc::SECCOMP_RET_ALLOW));
+    }
+
+    filter.push(statement(RETURN, libc::SECCOMP_RET_ERRNO | libc::ENOSYS as u32));
+
+    filter
+}
+
+/// Reads up to `limit` bytes of a pipe and discards the rest, so the process does not block on a full pipe.
+fn collect(mut pipe: impl Read + Send + 'static, limit: u64) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut kept = vec![];
+        let _ = pipe.by_ref().take(limit).read_to_end(&mut kept);
+        let _ = io::copy(&mut pipe, &mut io::sink());
+
+        String::from_utf8_lossy(&kept).into_owned()
+    })
+}
+
+fn kill_group(child: &Child) {
+    unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL) };
+}
+
+impl Sandbox {
+    /// Creates the sandbox of `SANDBOX_UID`, `SANDBOX_GID` and `SANDBOX_MOUNTS`.
+    pub fn new(env: &Config) -> Self {
+        Sandbox {
+            uid: env.sandbox_uid,
+            gid: env.sandbox_gid,
+            mounts: env.sandbox_mounts.split(':').filter(|path| !path.is_empty()).map(PathBuf::from).collect(),
+        }
+    }
+
+    fn confinement(&self, root: &Path, directory: &Path, access: Access, limits: &Limits) -> io::Result<Confinement> {
+        let read_only = libc::MS_RDONLY | libc::MS_NOSUID | libc::MS_NODEV;
+        let mut builder = RootBuilder { root, created: HashSet::new(), steps: vec![] };
+
+        for path in &self.mounts {
+            builder.bind_if_exists(path, read_only)?;
+        }
+
+        for device in DEVICES {
+            builder.bind_if_exists(Path::new(device), libc::MS_NOSUID | libc::MS_NOEXEC)?;
+        }
+
+        let workspace_flags = match access {
+            Access::ReadOnly => read_only,
+            Access::ReadWrite => libc::MS_NOSUID | libc::MS_NODEV,
+        };
+
+        builder.bind(directory, Path::new(WORKSPACE), workspace_flags)?;
+        builder.mount("proc", Path::new("/proc"), libc::MS_NOSUID | libc::MS_NODEV | libc::MS_NOEXEC, "")?;
+        builder.mount("tmpfs", Path::new("/tmp"), libc::MS_NOSUID | libc::MS_NODEV, &format!("size={},nr_inodes=1024,mode=1777", limits.output_bytes))?;
+
+        Ok(Confinement {
+            root: c_path(root)?,
+            steps: builder.steps,
+            workspace: c_path(Path::new(WORKSPACE))?,
+            uid: self.uid,
+            gid: self.gid,
+            limits: *limits,
+            filter: syscall_filter(),
+        })
+    }
+
+    /// Runs `command` in the sandbox with `input` on stdin and waits until it ends or runs out of time.
+    ///
+    /// `directory` is the workspace of the process, paths of the command are resolved within the sandbox. Processes
+    /// it starts are killed with it. Fails if the process cannot be confined, e.g. if the server does not run as root.
+    pub fn run(&self, command: &mut Command, directory: &Path, access: Access, input: &str, limits: &Limits) -> io::Result<Output> {
+        let root = tempfile::tempdir()?;
+        chown(directory, Some(self.uid), Some(self.gid))?;
+
+        let confinement = self.confinement(root.path(), directory, access, limits)?;
+
+        let mut child = unsafe {
+            command
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .pre_exec(move || confinement.enter())
+                .spawn()?
+        };
+
+        let mut stdin = child.stdin.take().unwrap();
+        let input = input.as_bytes().to_vec();
+        let writer = thread::spawn(move || {
+            let _ = stdin.write_all(&input);
+        });
+
+        let stdout = collect(child.stdout.take().unwrap(), limits.output_bytes);
+        let stderr = collect(child.stderr.take().unwrap(), limits.output_bytes);
+
+        let deadline = Instant::now() + limits.wall_time;
+
+        let termination = loop {
+            if let Some(status) = child.try_wait()? {
+                kill_group(&child);
+
+                break match (status.code(), status.signal()) {
+                    (Some(code), _) => Termination::Exited(code),
+                    (None, Some(signal)) => Termination::Signaled(signal),
+                    (None, None) => Termination::Signaled(0),
+                };
+            }
+
+            if Instant::now() >= deadline {
+                kill_group(&child);
+                child.wait()?;
+
+                break Termination::TimedOut;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        let _ = writer.join();
+
+        Ok(Output {
+            termination,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+        })
+    }
+}
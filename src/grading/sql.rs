@@ -0,0 +1,374 @@
+use std::{
+    collections::BTreeMap,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use mongodb::bson::{Bson, Document};
+use rusqlite::{limits::Limit, types::ValueRef, Batch, Connection, ErrorCode};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `SQL` tasks by running the `query` of the answer and of the solution, each on a fresh in-memory SQLite
+/// database created from the `schema` and `data` of the task, and comparing their result sets.
+///
+/// The rows are compared in order if the task is `ordered` and as multisets otherwise. Queries have to be a single
+/// `SELECT` statement, which may start with `WITH` or be a `VALUES` list, and are interrupted after the `time_ms`
+/// of the `limits` of the task. Mismatches are reported with the rows which differ. Running both queries may take
+/// twice the time limit, so answers are graded in the background.
+pub struct SqlGrader;
+
+/// Time limit of a query if the task does not set it.
+const DEFAULT_TIME_MS: u64 = 1000;
+
+/// The longest time limit a task may set, matching the schema of the task type.
+const MAX_TIME_MS: u64 = 10000;
+
+/// The time the `schema` and `data` of a task may take to create its database.
+const SETUP_TIME_MS: u64 = MAX_TIME_MS;
+
+/// Results with more rows are rejected rather than compared, e.g. accidental cross joins.
+const MAX_ROWS: usize = 10000;
+
+/// Values built by queries may not be longer, so queries cannot exhaust the memory within their time limit.
+const MAX_VALUE_LENGTH: i32 = 1 << 20;
+
+/// The decimals reals are compared with.
+const REAL_DECIMALS: i32 = 9;
+
+/// The amount of differing rows listed in the feedback.
+const MAX_DIFFERENCES: usize = 10;
+
+/// The keywords read-only queries may start with.
+const QUERY_KEYWORDS: [&str; 3] = ["SELECT", "WITH", "VALUES"];
+
+struct ResultSet {
+    columns: usize,
+    rows: Vec<Row>,
+}
+
+/// A row of a result set, its values written as SQL literals so rows can be compared and shown.
+type Row = Vec<String>;
+
+enum QueryError {
+    /// The `schema` or `data` of the task could not be executed.
+    Setup(&'static str, String),
+    /// The query is not a single read-only query.
+    Statement(String),
+    Failed(String),
+    TimedOut,
+    TooManyRows,
+}
+
+impl QueryError {
+    fn message(&self) -> String {
+        match self {
+            QueryError::Setup(_, message) => format!("The database of the task could not be created: {}", message),
+            QueryError::Statement(message) => message.clone(),
+            QueryError::Failed(message) => format!("The query failed: {}", message),
+            QueryError::TimedOut => "The query exceeded the time limit".to_string(),
+            QueryError::TooManyRows => format!("The query returns more than {} rows", MAX_ROWS),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for QueryError {
+    fn from(err: rusqlite::Error) -> Self {
+        match err.sqlite_error_code() {
+            Some(ErrorCode::OperationInterrupted) => QueryError::TimedOut,
+            _ => QueryError::Failed(err.to_string()),
+        }
+    }
+}
+
+/// Writes a value as SQL literal. Reals are rounded to `REAL_DECIMALS` decimals, so rounding errors of different
+/// ways to compute them do not matter, and integral reals are written like integers, so `SUM` and `COUNT` compare equal.
+fn literal(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(value) => value.to_string(),
+        ValueRef::Real(value) => {
+            let scale = 10f64.powi(REAL_DECIMALS);
+            let value = (value * scale).round() / scale;
+
+            if value.fract() == 0.0 && value.abs() < 1e15 {
+                return (value as i64).to_string();
+            }
+
+            value.to_string()
+        },
+        ValueRef::Text(text) => format!("'{}'", String::from_utf8_lossy(text).replace('\'', "''")),
+        ValueRef::Blob(blob) => format!("X'{}'", blob.iter().map(|byte| format!("{:02X}", byte)).collect::<String>()),
+    }
+}
+
+fn display_row(row: &Row) -> String {
+    format!("({})", row.join(", "))
+}
+
+/// The first keyword of a query, skipping whitespace and comments.
+fn first_keyword(query: &str) -> String {
+    let mut rest = query.trim_start();
+
+    loop {
+        if let Some(comment) = rest.strip_prefix("--") {
+            rest = comment.split_once('\n').map(|(_, rest)| rest).unwrap_or_default().trim_start();
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map(|(_, rest)| rest).unwrap_or_default().trim_start();
+        } else {
+            break;
+        }
+    }
+
+    rest.chars().take_while(|char| char.is_ascii_alphabetic()).collect::<String>().to_uppercase()
+}
+
+/// The `time_ms` of the `limits` of the task, if it is set to an integer.
+fn time_ms(task: &Document) -> Option<i64> {
+    task.get_document("limits").ok()
+        .and_then(|limits| match limits.get("time_ms") {
+            Some(Bson::Int32(time)) => Some(i64::from(*time)),
+            Some(Bson::Int64(time)) => Some(*time),
+            _ => None,
+        })
+}
+
+/// The time limit of the queries of a task, capped at `MAX_TIME_MS` for tasks stored before it was checked.
+fn time_limit(task: &Document) -> Duration {
+    let time_ms = time_ms(task)
+        .and_then(|time| u64::try_from(time).ok())
+        .unwrap_or(DEFAULT_TIME_MS);
+
+    Duration::from_millis(time_ms.min(MAX_TIME_MS))
+}
+
+/// Interrupts the statements running on a connection once they run longer than a time limit, until it is dropped.
+struct Timer {
+    finished: Option<mpsc::Sender<()>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Timer {
+    fn start(connection: &Connection, time_limit: Duration) -> Self {
+        let interrupt = connection.get_interrupt_handle();
+        let (finished, wait_for_finish) = mpsc::channel::<()>();
+
+        let thread = thread::spawn(move || {
+            if let Err(RecvTimeoutError::Timeout) = wait_for_finish.recv_timeout(time_limit) {
+                interrupt.interrupt();
+            }
+        });
+
+        Timer { finished: Some(finished), thread: Some(thread) }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        drop(self.finished.take());
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Creates a fresh in-memory database with the schema and data of the task, which only allows reading afterwards.
+/// The schema and data are interrupted after `SETUP_TIME_MS`, e.g. if they insert the rows of an endless recursive query.
+fn create_database(task: &Document) -> Result<Connection, QueryError> {
+    let connection = Connection::open_in_memory().map_err(|err| QueryError::Setup("", err.to_string()))?;
+
+    connection.set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0);
+    connection.set_limit(Limit::SQLITE_LIMIT_LENGTH, MAX_VALUE_LENGTH);
+
+    let timer = Timer::start(&connection, Duration::from_millis(SETUP_TIME_MS));
+
+    for key in ["schema", "data"] {
+        connection.execute_batch(task.get_str(key).unwrap_or_default())
+            .map_err(|err| match err.sqlite_error_code() {
+                Some(ErrorCode::OperationInterrupted) => QueryError::Setup(key, format!("it took longer than {} ms", SETUP_TIME_MS)),
+                _ => QueryError::Setup(key, err.to_string()),
+            })?;
+    }
+
+    drop(timer);
+
+    connection.execute_batch("PRAGMA query_only = ON").map_err(|err| QueryError::Setup("", err.to_string()))?;
+
+    Ok(connection)
+}
+
+/// Runs `query` on a fresh database of the task, interrupting it once it runs longer than the time limit of the task.
+fn run_query(task: &Document, query: &str) -> Result<ResultSet, QueryError> {
+    if !QUERY_KEYWORDS.contains(&first_keyword(query).as_str()) {
+        return Err(QueryError::Statement("Only SELECT queries are allowed".to_string()));
+    }
+
+    let connection = create_database(task)?;
+
+    let timer = Timer::start(&connection, time_limit(task));
+    let result = read_result(&connection, query);
+    drop(timer);
+
+    result
+}
+
+fn read_result(connection: &Connection, query: &str) -> Result<ResultSet, QueryError> {
+    let mut batch = Batch::new(connection, query);
+
+    let mut statement = match batch.next()? {
+        Some(statement) => statement,
+        None => return Err(QueryError::Statement("The query is empty".to_string())),
+    };
+
+    if batch.next()?.is_some() {
+        return Err(QueryError::Statement("Only a single query is allowed".to_string()));
+    }
+
+    if !statement.readonly() {
+        return Err(QueryError::Statement("Only SELECT queries are allowed".to_string()));
+    }
+
+    let columns = statement.column_count();
+    let mut rows = vec![];
+    let mut result = statement.query([])?;
+
+    while let Some(row) = result.next()? {
+        if rows.len() == MAX_ROWS {
+            return Err(QueryError::TooManyRows);
+        }
+
+        rows.push((0..columns).map(|column| row.get_ref(column).map(literal)).collect::<Result<Row, _>>()?);
+    }
+
+    Ok(ResultSet { columns, rows })
+}
+
+/// Lists the rows at the same position which differ, and the rows only one of the results has.
+fn ordered_differences(expected: &[Row], actual: &[Row]) -> Vec<String> {
+    let mut differences = vec![];
+
+    for (index, (expected_row, actual_row)) in expected.iter().zip(actual).enumerate() {
+        if expected_row != actual_row {
+            differences.push(format!("Row {} is {} instead of {}", index + 1, display_row(actual_row), display_row(expected_row)));
+        }
+    }
+
+    for (index, row) in expected.iter().enumerate().skip(actual.len()) {
+        differences.push(format!("Row {} is missing: {}", index + 1, display_row(row)));
+    }
+
+    for (index, row) in actual.iter().enumerate().skip(expected.len()) {
+        differences.push(format!("Row {} is unexpected: {}", index + 1, display_row(row)));
+    }
+
+    differences
+}
+
+/// Lists the rows the results contain a different number of times.
+fn multiset_differences(expected: &[Row], actual: &[Row]) -> Vec<String> {
+    let mut counts: BTreeMap<&Row, i64> = BTreeMap::new();
+
+    for row in expected {
+        *counts.entry(row).or_default() += 1;
+    }
+
+    for row in actual {
+        *counts.entry(row).or_default() -= 1;
+    }
+
+    counts.into_iter().filter_map(|(row, count)| match count {
+        0 => None,
+        1 => Some(format!("Missing row {}", display_row(row))),
+        count if count > 1 => Some(format!("Missing row {} ({} times)", display_row(row), count)),
+        -1 => Some(format!("Unexpected row {}", display_row(row))),
+        count => Some(format!("Unexpected row {} ({} times)", display_row(row), -count)),
+    }).collect()
+}
+
+fn query_feedback(correct: bool, message: String) -> GradeFeedback {
+    GradeFeedback {
+        path: "/query".to_string(),
+        correct,
+        message,
+    }
+}
+
+fn sql_error(instance_path: &str, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path: instance_path.to_string(),
+        schema_path: String::new(),
+        keyword: "sql".to_string(),
+        message,
+    }
+}
+
+impl Grader for SqlGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let expected = match run_query(task, solution.get_str("query").unwrap_or_default()) {
+            Ok(expected) => expected,
+            Err(err) => return Grade::new(0.0, 1.0, vec![query_feedback(false, format!("The solution could not be run: {}", err.message()))]),
+        };
+
+        let actual = match run_query(task, answer.get_str("query").unwrap_or_default()) {
+            Ok(actual) => actual,
+            Err(err) => return Grade::new(0.0, 1.0, vec![query_feedback(false, err.message())]),
+        };
+
+        if actual.columns != expected.columns {
+            return Grade::new(0.0, 1.0, vec![query_feedback(false, format!(
+                "The result has {} columns instead of {}", actual.columns, expected.columns
+            ))]);
+        }
+
+        let mut differences = if task.get_bool("ordered").unwrap_or(false) {
+            ordered_differences(&expected.rows, &actual.rows)
+        } else {
+            multiset_differences(&expected.rows, &actual.rows)
+        };
+
+        if differences.is_empty() {
+            return Grade::new(1.0, 1.0, vec![query_feedback(true, "The result is correct".to_string())]);
+        }
+
+        let omitted = differences.len().saturating_sub(MAX_DIFFERENCES);
+        differences.truncate(MAX_DIFFERENCES);
+
+        if omitted > 0 {
+            differences.push(format!("{} more rows differ", omitted));
+        }
+
+        let mut feedback = vec![query_feedback(false, format!(
+            "The result has {} rows, the expected result has {}", actual.rows.len(), expected.rows.len()
+        ))];
+        feedback.extend(differences.into_iter().map(|difference| query_feedback(false, difference)));
+
+        Grade::new(0.0, 1.0, feedback)
+    }
+
+    fn validate_task(&self, task: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        if let Some(time) = time_ms(task).filter(|time| !(1..=MAX_TIME_MS as i64).contains(time)) {
+            return Err(vec![sql_error("/limits/time_ms", format!("The time limit has to be between 1 and {} ms, but is {} ms", MAX_TIME_MS, time))]);
+        }
+
+        match create_database(task) {
+            Err(QueryError::Setup(key, message)) => Err(vec![sql_error(&format!("/{}", key), message)]),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        match run_query(task, solution.get_str("query").unwrap_or_default()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(vec![sql_error("/query", err.message())]),
+        }
+    }
+
+    fn grades_in_background(&self) -> bool {
+        true
+    }
+}
+
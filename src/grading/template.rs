@@ -0,0 +1,534 @@
+use std::{collections::HashMap, sync::Arc};
+
+use mongodb::bson::{Bson, Document};
+use serde::Deserialize;
+
+use crate::models::{task::TaskDoc, validation::ValidationErrorEntry};
+
+use super::{grader::GraderRegistry, multiple_choice::MultipleChoiceGenerator};
+
+/// Random numbers for instances of task templates. Equal seeds give equal numbers, so an instance can be generated
+/// again from the seed stored with its answer.
+pub struct SeededRng(u64);
+
+impl SeededRng {
+    pub fn new(seed: i64) -> Self {
+        SeededRng(seed as u64)
+    }
+
+    /// SplitMix64, which is good enough for drawing task parameters and stable across versions.
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number between `min` and `max`, both included.
+    pub fn range_i64(&mut self, min: i64, max: i64) -> i64 {
+        let span = max.wrapping_sub(min) as u64;
+
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+
+        min.wrapping_add((self.next_u64() % (span + 1)) as i64)
+    }
+
+    /// A random order of the numbers from `0` to `length` excluded.
+    pub fn permutation(&mut self, length: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..length).collect();
+
+        for index in (1..length).rev() {
+            permutation.swap(index, self.range_i64(0, index as i64) as usize);
+        }
+
+        permutation
+    }
+
+    /// A number between `0` included and `1` excluded.
+    pub fn unit_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Definition of a parameter of a task template, stored by name in the `parameters` of the task.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ParameterDefinition {
+    Integer { min: i64, max: i64 },
+    Number {
+        min: f64,
+        max: f64,
+        /// The decimal places the number is rounded to.
+        #[serde(default = "default_decimals")]
+        decimals: u32,
+    },
+    /// A word of symbols of the alphabet.
+    Word {
+        #[serde(default)]
+        alphabet: Vec<String>,
+        min_length: usize,
+        max_length: usize,
+    },
+    Choice { values: Vec<Bson> },
+}
+
+fn default_decimals() -> u32 {
+    2
+}
+
+/// The largest magnitude of integer parameters, up to which placeholders compute with them exactly.
+const MAX_INTEGER: u64 = 1 << 53;
+
+/// The longest word a word parameter may draw.
+const MAX_WORD_LENGTH: usize = 1000;
+
+/// The most symbols of an alphabet and values of a choice.
+const MAX_VALUES: usize = 1000;
+
+/// How deeply parentheses and signs may be nested in a placeholder.
+const MAX_NESTING: usize = 64;
+
+fn parameter_error(instance_path: &str, message: String) -> ValidationErrorEntry {
+    ValidationErrorEntry {
+        instance_path: instance_path.to_string(),
+        schema_path: String::new(),
+        keyword: "parameters".to_string(),
+        message,
+    }
+}
+
+/// Reads the parameter definitions of a task template, in the order they are stored.
+pub fn parse_parameters(parameters: &Document) -> Result<Vec<(String, ParameterDefinition)>, Vec<ValidationErrorEntry>> {
+    let mut definitions = vec![];
+    let mut errors = vec![];
+
+    for (name, definition) in parameters {
+        let path = format!("/parameters/{}", name);
+
+        let definition = match definition.as_document().map(|definition| mongodb::bson::from_document(definition.clone())) {
+            Some(Ok(definition)) => definition,
+            Some(Err(err)) => {
+                errors.push(parameter_error(&path, err.to_string()));
+                continue;
+            },
+            None => {
+                errors.push(parameter_error(&path, "The definition of a parameter has to be an object".to_string()));
+                continue;
+            },
+        };
+
+        let empty_range = || format!("\"{}\" has an empty range of values", name);
+
+        let error = match &definition {
+            ParameterDefinition::Integer { min, max } if min.unsigned_abs().max(max.unsigned_abs()) > MAX_INTEGER =>
+                Some(format!("The values of \"{}\" have to be between -{} and {}", name, MAX_INTEGER, MAX_INTEGER)),
+            ParameterDefinition::Integer { min, max } => (min > max).then(empty_range),
+            ParameterDefinition::Number { min, max, .. } => (!(min.is_finite() && max.is_finite() && min <= max)).then(empty_range),
+            ParameterDefinition::Word { max_length, .. } if *max_length > MAX_WORD_LENGTH =>
+                Some(format!("The words of \"{}\" may not be longer than {} symbols", name, MAX_WORD_LENGTH)),
+            ParameterDefinition::Word { alphabet, .. } if alphabet.len() > MAX_VALUES =>
+                Some(format!("The alphabet of \"{}\" may not have more than {} symbols", name, MAX_VALUES)),
+            ParameterDefinition::Word { min_length, max_length, .. } => (min_length > max_length).then(empty_range),
+            ParameterDefinition::Choice { values } if values.len() > MAX_VALUES =>
+                Some(format!("\"{}\" may not have more than {} values", name, MAX_VALUES)),
+            ParameterDefinition::Choice { values } => values.is_empty().then(empty_range),
+        };
+
+        if let Some(error) = error {
+            errors.push(parameter_error(&path, error));
+        }
+
+        definitions.push((name.clone(), definition));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(definitions)
+}
+
+/// Draws a value for every parameter.
+pub fn draw_values(definitions: &[(String, ParameterDefinition)], rng: &mut SeededRng) -> Result<HashMap<String, Bson>, Vec<ValidationErrorEntry>> {
+    let mut values = HashMap::new();
+
+    for (name, definition) in definitions {
+        let value = match definition {
+            ParameterDefinition::Integer { min, max } => Bson::Int64(rng.range_i64(*min, *max)),
+            ParameterDefinition::Number { min, max, decimals } => {
+                let factor = 10f64.powi(*decimals as i32);
+                let number = min + rng.unit_f64() * (max - min);
+
+                Bson::Double(((number * factor).round() / factor).clamp(*min, *max))
+            },
+            ParameterDefinition::Word { alphabet, min_length, max_length } => {
+                if alphabet.is_empty() {
+                    return Err(vec![parameter_error(&format!("/parameters/{}", name), format!("\"{}\" has no alphabet", name))]);
+                }
+
+                let length = rng.range_i64(*min_length as i64, *max_length as i64) as usize;
+
+                Bson::String((0..length).map(|_| alphabet[rng.range_i64(0, alphabet.len() as i64 - 1) as usize].as_str()).collect())
+            },
+            ParameterDefinition::Choice { values } => values[rng.range_i64(0, values.len() as i64 - 1) as usize].clone(),
+        };
+
+        values.insert(name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+enum Token {
+    Number(f64),
+    Name(String),
+    Operator(char),
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                number.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Number(number.parse().map_err(|_| format!("\"{}\" is not a number", number))?));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                name.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Name(name));
+        } else if "+-*/%()".contains(c) {
+            tokens.push(Token::Operator(c));
+            chars.next();
+        } else {
+            return Err(format!("Unexpected \"{}\"", c));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Evaluates arithmetic placeholders like `{{a * b + 1}}` by recursive descent.
+struct Evaluator<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    /// The parentheses and signs the current factor is nested in.
+    depth: usize,
+    values: &'a HashMap<String, Bson>,
+}
+
+impl Evaluator<'_> {
+    fn next_operator(&self, operators: &str) -> Option<char> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(c)) if operators.contains(*c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    fn sum(&mut self) -> Result<f64, String> {
+        let mut value = self.product()?;
+
+        while let Some(operator) = self.next_operator("+-") {
+            self.position += 1;
+            let right = self.product()?;
+            value = if operator == '+' { value + right } else { value - right };
+        }
+
+        Ok(value)
+    }
+
+    fn product(&mut self) -> Result<f64, String> {
+        let mut value = self.factor()?;
+
+        while let Some(operator) = self.next_operator("*/%") {
+            self.position += 1;
+            let right = self.factor()?;
+
+            if operator != '*' && right == 0.0 {
+                return Err("Division by zero".to_string());
+            }
+
+            value = match operator {
+                '*' => value * right,
+                '/' => value / right,
+                _ => value % right,
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a nested part of the expression, limiting the nesting so the recursion cannot overflow the stack.
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<f64, String>) -> Result<f64, String> {
+        if self.depth == MAX_NESTING {
+            return Err("Expression is nested too deeply".to_string());
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+
+        value
+    }
+
+    fn factor(&mut self) -> Result<f64, String> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+
+        match token {
+            Some(Token::Number(number)) => Ok(*number),
+            Some(Token::Name(name)) => match self.values.get(name) {
+                Some(Bson::Int64(value)) => Ok(*value as f64),
+                Some(Bson::Int32(value)) => Ok(*value as f64),
+                Some(Bson::Double(value)) => Ok(*value),
+                Some(_) => Err(format!("\"{}\" is not a number", name)),
+                None => Err(format!("\"{}\" is no parameter", name)),
+            },
+            Some(Token::Operator('-')) => Ok(-self.nested(Self::factor)?),
+            Some(Token::Operator('(')) => {
+                let value = self.nested(Self::sum)?;
+
+                match self.tokens.get(self.position) {
+                    Some(Token::Operator(')')) => {
+                        self.position += 1;
+                        Ok(value)
+                    },
+                    _ => Err("Missing \")\"".to_string()),
+                }
+            },
+            _ => Err("Incomplete expression".to_string()),
+        }
+    }
+}
+
+/// Evaluates the content of a placeholder. A single parameter keeps its type, expressions give numbers,
+/// integers if they are whole.
+fn evaluate(expression: &str, values: &HashMap<String, Bson>) -> Result<Bson, String> {
+    if let Some(value) = values.get(expression.trim()) {
+        return Ok(value.clone());
+    }
+
+    let mut evaluator = Evaluator { tokens: tokenize(expression)?, position: 0, depth: 0, values };
+    let value = evaluator.sum()?;
+
+    if evaluator.position != evaluator.tokens.len() {
+        return Err(format!("\"{}\" is no valid expression", expression.trim()));
+    }
+
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        return Ok(Bson::Int64(value as i64));
+    }
+
+    Ok(Bson::Double(value))
+}
+
+fn display(value: &Bson) -> String {
+    match value {
+        Bson::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Replaces the placeholders `{{...}}` in the strings of `value`. A string consisting of a single placeholder is
+/// replaced by its value, keeping numbers numbers; placeholders inside longer strings are written out.
+fn substitute(value: &Bson, values: &HashMap<String, Bson>, path: &str) -> Result<Bson, ValidationErrorEntry> {
+    match value {
+        Bson::String(text) => {
+            let trimmed = text.trim();
+
+            if trimmed.starts_with("{{") && trimmed.ends_with("}}") && trimmed.matches("{{").count() == 1 {
+                return evaluate(&trimmed[2..trimmed.len() - 2], values).map_err(|err| parameter_error(path, err));
+            }
+
+            let mut result = String::new();
+            let mut rest = text.as_str();
+
+            while let Some(start) = rest.find("{{") {
+                let end = rest[start..].find("}}")
+                    .ok_or_else(|| parameter_error(path, "Missing \"}}\"".to_string()))?;
+
+                result.push_str(&rest[..start]);
+                result.push_str(&display(&evaluate(&rest[start + 2..start + end], values).map_err(|err| parameter_error(path, err))?));
+                rest = &rest[start + end + 2..];
+            }
+
+            result.push_str(rest);
+
+            Ok(Bson::String(result))
+        },
+        Bson::Array(items) => items.iter().enumerate()
+            .map(|(index, item)| substitute(item, values, &format!("{}/{}", path, index)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Bson::Array),
+        Bson::Document(document) => substitute_document(document, values, path).map(Bson::Document),
+        value => Ok(value.clone()),
+    }
+}
+
+fn substitute_document(document: &Document, values: &HashMap<String, Bson>, path: &str) -> Result<Document, ValidationErrorEntry> {
+    document.iter()
+        .map(|(key, value)| Ok((key.clone(), substitute(value, values, &format!("{}/{}", path, key))?)))
+        .collect()
+}
+
+/// Generates instances of task templates of one task type.
+pub trait Generator: Send + Sync {
+    /// Generates the task and solution of an instance from the task template, its solution template and the
+    /// parameter definitions.
+    fn generate(&self, task: &Document, solution: &Document, parameters: &Document, rng: &mut SeededRng) -> Result<(Document, Document), Vec<ValidationErrorEntry>>;
+
+    /// Draws the order the options of a task are shown in, listing the original index of every shown option.
+    /// Tasks without options to shuffle return `None`, which is the default.
+    fn permutation(&self, _task: &Document, _rng: &mut SeededRng) -> Option<Vec<usize>> {
+        None
+    }
+
+    /// Shows the options of a task in the order of `permutation`.
+    fn shuffle(&self, task: &Document, _permutation: &[usize]) -> Document {
+        task.clone()
+    }
+
+    /// Maps an answer to the task shown in the order of `permutation` back to the original options.
+    fn unshuffle_answer(&self, answer: &Document, _permutation: &[usize]) -> Document {
+        answer.clone()
+    }
+}
+
+/// Draws the parameters and fills them into the placeholders of the task and solution templates.
+pub struct PlaceholderGenerator;
+
+impl Generator for PlaceholderGenerator {
+    fn generate(&self, task: &Document, solution: &Document, parameters: &Document, rng: &mut SeededRng) -> Result<(Document, Document), Vec<ValidationErrorEntry>> {
+        let values = draw_values(&parse_parameters(parameters)?, rng)?;
+
+        let task = substitute_document(task, &values, "/task").map_err(|err| vec![err])?;
+        let solution = substitute_document(solution, &values, "/solution").map_err(|err| vec![err])?;
+
+        Ok((task, solution))
+    }
+}
+
+/// Generates CYK tasks, drawing words without an alphabet of their own from the terminals of the grammar.
+pub struct CykGenerator;
+
+impl Generator for CykGenerator {
+    fn generate(&self, task: &Document, solution: &Document, parameters: &Document, rng: &mut SeededRng) -> Result<(Document, Document), Vec<ValidationErrorEntry>> {
+        let terminals: Vec<Bson> = task.get_array("T").cloned().unwrap_or_default();
+
+        let parameters: Document = parameters.iter().map(|(name, definition)| {
+            let mut definition = definition.clone();
+
+            if let Bson::Document(definition) = &mut definition {
+                if definition.get_str("type") == Ok("word") && !definition.contains_key("alphabet") {
+                    definition.insert("alphabet", terminals.clone());
+                }
+            }
+
+            (name.clone(), definition)
+        }).collect();
+
+        PlaceholderGenerator.generate(task, solution, &parameters, rng)
+    }
+}
+
+/// Maps task types to their generator. Task types without a registered generator fill in placeholders.
+#[derive(Clone)]
+pub struct GeneratorRegistry {
+    generators: HashMap<String, Arc<dyn Generator>>,
+    default_generator: Arc<dyn Generator>,
+}
+
+impl Default for GeneratorRegistry {
+    /// Creates the registry with the generators of the built-in task types.
+    fn default() -> Self {
+        let mut registry = GeneratorRegistry {
+            generators: HashMap::new(),
+            default_generator: Arc::new(PlaceholderGenerator),
+        };
+
+        registry.register("CYK-Algorithm", Arc::new(CykGenerator));
+        registry.register("Multiple-Choice", Arc::new(MultipleChoiceGenerator));
+
+        registry
+    }
+}
+
+impl GeneratorRegistry {
+    /// Creates the registry with the generators of the built-in task types.
+    pub fn new() -> Self {
+        GeneratorRegistry::default()
+    }
+
+    /// Registers `generator` for `task_type`, replacing the generator registered before.
+    pub fn register(&mut self, task_type: &str, generator: Arc<dyn Generator>) {
+        self.generators.insert(task_type.to_string(), generator);
+    }
+
+    pub fn get(&self, task_type: &str) -> &dyn Generator {
+        self.generators.get(task_type).unwrap_or(&self.default_generator).as_ref()
+    }
+
+    /// Generates the instance of a task for `seed`, with the reference solution computed by the grader of its task
+    /// type or else filled in from the solution template. Tasks without parameters are returned as they are.
+    pub fn instantiate(&self, task: &TaskDoc, seed: i64, graders: &GraderRegistry) -> Result<TaskDoc, Vec<ValidationErrorEntry>> {
+        let parameters = match &task.parameters {
+            Some(parameters) => parameters,
+            None => return Ok(task.clone()),
+        };
+
+        let (instance, solution) = self.get(&task.task_type)
+            .generate(&task.task, &task.solution, parameters, &mut SeededRng::new(seed))?;
+
+        let solution = graders.get(&task.task_type).solve(&instance).unwrap_or(solution);
+
+        Ok(TaskDoc {
+            task: instance,
+            solution,
+            parameters: None,
+            ..task.clone()
+        })
+    }
+
+    /// Draws the order the options of a task are shown in, `None` for tasks whose options are not shuffled.
+    pub fn permutation(&self, task: &TaskDoc, rng: &mut SeededRng) -> Option<Vec<i32>> {
+        self.get(&task.task_type)
+            .permutation(&task.task, rng)
+            .map(|permutation| permutation.into_iter().map(|index| index as i32).collect())
+    }
+
+    /// Shows the options of a task in the order stored with an answer.
+    pub fn shuffle(&self, task: TaskDoc, permutation: &Option<Vec<i32>>) -> TaskDoc {
+        match permutation {
+            Some(permutation) => TaskDoc {
+                task: self.get(&task.task_type).shuffle(&task.task, &to_indices(permutation)),
+                ..task
+            },
+            None => task,
+        }
+    }
+
+    /// Maps an answer to a task shown in the order stored with the answer back to the original options.
+    pub fn unshuffle_answer(&self, task_type: &str, answer: &Document, permutation: &Option<Vec<i32>>) -> Document {
+        match permutation {
+            Some(permutation) => self.get(task_type).unshuffle_answer(answer, &to_indices(permutation)),
+            None => answer.clone(),
+        }
+    }
+}
+
+fn to_indices(permutation: &[i32]) -> Vec<usize> {
+    permutation.iter().map(|index| *index as usize).collect()
+}
@@ -0,0 +1,99 @@
+use mongodb::bson::{Bson, Document};
+use regex::{Regex, RegexBuilder};
+
+use crate::models::validation::ValidationErrorEntry;
+
+use super::grader::{Grade, GradeFeedback, Grader};
+
+/// Grades `Short-Text` tasks by comparing the normalised answer with the solution and its accepted `alternatives`,
+/// or by matching the whole answer with the regular expression `pattern` of the solution.
+///
+/// Answers are compared ignoring the case unless the task is `case_sensitive`, and with leading, trailing and repeated
+/// whitespace removed unless the task sets `normalize_whitespace` to `false`.
+pub struct TextGrader;
+
+struct Normalisation {
+    case_sensitive: bool,
+    normalize_whitespace: bool,
+}
+
+impl Normalisation {
+    fn of(task: &Document) -> Self {
+        Normalisation {
+            case_sensitive: task.get_bool("case_sensitive").unwrap_or(false),
+            normalize_whitespace: task.get_bool("normalize_whitespace").unwrap_or(true),
+        }
+    }
+
+    fn whitespace(&self, text: &str) -> String {
+        if self.normalize_whitespace {
+            return text.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+
+        text.to_string()
+    }
+
+    fn apply(&self, text: &str) -> String {
+        let text = self.whitespace(text);
+
+        if self.case_sensitive {
+            return text;
+        }
+
+        text.to_lowercase()
+    }
+
+    /// Compiles `pattern` so that it has to match the whole answer.
+    fn pattern(&self, pattern: &str) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(&format!("^(?:{})$", pattern))
+            .case_insensitive(!self.case_sensitive)
+            .build()
+    }
+}
+
+impl Grader for TextGrader {
+    fn grade(&self, task: &Document, solution: &Document, answer: &Document) -> Grade {
+        let normalisation = Normalisation::of(task);
+
+        let correct = match answer.get_str("solution") {
+            Ok(answer) => {
+                let normalised_answer = normalisation.apply(answer);
+
+                let accepted = solution.get_str("solution").into_iter()
+                    .chain(solution.get_array("alternatives").map(|alternatives| alternatives.as_slice()).unwrap_or_default().iter().filter_map(Bson::as_str))
+                    .any(|accepted| normalisation.apply(accepted) == normalised_answer);
+
+                let matched = solution.get_str("pattern").ok()
+                    .and_then(|pattern| normalisation.pattern(pattern).ok())
+                    .is_some_and(|pattern| pattern.is_match(&normalisation.whitespace(answer)));
+
+                accepted || matched
+            },
+            Err(_) => false,
+        };
+
+        Grade::new(if correct { 1.0 } else { 0.0 }, 1.0, vec![GradeFeedback {
+            path: "/solution".to_string(),
+            correct,
+            message: if correct { "The answer is correct" } else { "The answer is not accepted" }.to_string(),
+        }])
+    }
+
+    fn validate_solution(&self, task: &Document, solution: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+        let pattern = match solution.get_str("pattern") {
+            Ok(pattern) => pattern,
+            Err(_) => return Ok(()),
+        };
+
+        if let Err(err) = Normalisation::of(task).pattern(pattern) {
+            return Err(vec![ValidationErrorEntry {
+                instance_path: "/pattern".to_string(),
+                schema_path: String::new(),
+                keyword: "pattern".to_string(),
+                message: err.to_string(),
+            }]);
+        }
+
+        Ok(())
+    }
+}
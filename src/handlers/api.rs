@@ -13,10 +13,10 @@ pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
             .app_data(Data::<UsersRepo>::new(user_db.clone()))
             .app_data(Data::<PermissionsRepo>::new(permission_db.clone()))
             .configure(|cfg| user::config(cfg, pgdb.clone()))
-            .configure(users::config)
+            .configure(|cfg| users::config(cfg, pgdb.clone()))
             .configure(|cfg| groups::config(cfg, pgdb.clone()))
-            .configure(tasks::config)
-            .configure(auth::config)
+            .configure(|cfg| tasks::config(cfg, pgdb.clone()))
+            .configure(|cfg| auth::config(cfg, pgdb.clone()))
             .configure(ressources::config)
     );
 }
\ No newline at end of file
@@ -6,12 +6,13 @@ use chrono::{Duration, Utc};
 use rand_core::OsRng;
 use serde_json::json;
 
-use crate::{models::{auth::{RegisterUserSchema, LoginUserSchema, LoginResponse}, users::{NewUser, UserResponse}, jwt::TokenClaims}, repository::users::UsersRepo, AppState, jwt};
+use crate::{models::{auth::{RegisterUserSchema, LoginUserSchema, LoginResponse}, users::{NewUser, UserResponse}, jwt::TokenClaims}, repository::{users::UsersRepo, group::GroupRepo, postgres::PgRepo}, AppState, jwt};
 use jsonwebtoken::{encode, EncodingKey, Header};
 
 /// # User Registration Endpoint
 ///
 /// This endpoint allows users to register by providing necessary information.
+/// Open group invitations for the email of the new user are redeemed automatically.
 #[utoipa::path(
     post,
     path = "/api/auth/register",
@@ -26,6 +27,7 @@ use jsonwebtoken::{encode, EncodingKey, Header};
 pub async fn register(
     body: Json<RegisterUserSchema>,
     db: Data<UsersRepo>,
+    group_repo: Data<GroupRepo>,
 ) -> HttpResponse {
     let exists = db.exist_user_with_email(body.email.to_lowercase()).unwrap();
 
@@ -47,6 +49,15 @@ pub async fn register(
         email: &body.email.to_lowercase(),
     });
 
+    if let Ok(user) = &user {
+        if let Ok(invitations) = group_repo.fetch_open_invitations_for_email(&user.email) {
+            for invitation in invitations {
+                // A failed invitation must not fail the registration
+                let _ = group_repo.redeem_invitation(&invitation, &user.id);
+            }
+        }
+    }
+
     match user {
         Ok(user) => HttpResponse::Created().json(UserResponse {
             id: user.id,
@@ -167,9 +178,11 @@ pub async fn validate(_: jwt::JwtMiddleware) -> HttpResponse {
         .finish()
 }
 
-pub fn config(cfg: &mut ServiceConfig) {
+pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
+    let group_repo = pgdb.new_group_repo();
     cfg.service(
         web::scope("/auth")
+            .app_data(Data::<GroupRepo>::new(group_repo.clone()))
             .service(register)
             .service(login)
             .service(logout)
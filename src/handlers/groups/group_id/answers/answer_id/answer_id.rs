@@ -2,16 +2,14 @@
 use std::io::{self, Write};
 
 use actix_web::{web::{ServiceConfig, self, Data, Path, Json}, HttpResponse, get, patch};
-use jsonschema::JSONSchema;
-use serde_json::Value;
 use uuid::Uuid;
 
-use crate::{models::{answer::{AnswerPath, UpdatedAnswerResponse, UpdateAnswerSchema, AnswerResponse}, util::{AnswerState, AccessType}}, repository::{group::GroupRepo, postgres::PgRepo}, jwt, permission, AppState};
+use crate::{models::{validation::{compile_schema, validate_document, ValidationErrorSchema}, answer::{AnswerPath, UpdatedAnswerResponse, UpdateAnswerSchema, AnswerResponse}, task::TaskResponse, util::{AnswerState, AccessType}}, repository::{group::GroupRepo, postgres::PgRepo}, jwt, permission, AppState};
 
 /// # Fetch Answer
 ///
 /// This route is used for fetching details about a specific answer to a task. 
-/// It returns information such as the answer's state, correctness, answer_doc, and timestamps. 
+/// It returns information such as the answer's state, correctness, answer_doc, the feedback of the grader, and timestamps. 
 /// Users can only access details about their own answers unless they have the specified permission.
 #[utoipa::path(
     get,
@@ -54,12 +52,16 @@ pub async fn fetch_answer(
         Ok(None) => return HttpResponse::Ok().json(AnswerResponse {
             id: answer.id,
             correct: answer.correct,
+            score: answer.score,
+            max_score: answer.max_score,
             state: answer.state,
             task_id: answer.task_id,
             answer_doc_id: answer.answer_doc_id,
             answer_doc: None,
             updated_at: None,
             created_from: answer.created_from,
+            task_revision: answer.task_revision,
+            feedback: vec![],
         }),
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
@@ -70,19 +72,97 @@ pub async fn fetch_answer(
         id: answer.id,
         state: answer.state,
         correct: answer.correct,
+        score: answer.score,
+        max_score: answer.max_score,
         task_id: answer.task_id,
         answer_doc_id: answer.answer_doc_id,
         answer_doc: Some(answer_doc.solution),
         updated_at: Some(answer_doc.updated_at.and_utc()),
         created_from: answer.created_from,
+        task_revision: answer.task_revision,
+        feedback: answer_doc.feedback,
     })
 }
 
+/// # Fetch Answer Task
+///
+/// This route is used for fetching the task an answer belongs to, in the revision the answer is pinned to. 
+/// Answers to task templates get the instance generated from their seed, so every student sees their own values. 
+/// Shuffled options are returned in the order stored with the answer, which is the order the answer refers to. 
+/// The solution of the task is never returned.
+/// Users can only access the task of their own answers unless they have the specified permission.
+#[utoipa::path(
+    get,
+    path="/api/groups/{group_id}/answers/{answer_id}/task",
+    tag="answer",
+    params(
+        ("answer_id" = Uuid, Path, description = "The unique identifier for the answer whose task is fetched."),
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group."),
+    ),
+    responses(
+        (status = 200, description = "The request was successful, and the task of the answer is provided.", body = TaskResponse),
+        (status = 403, description = "The user is not allowed to access the answer", body = ErrorSchema),
+        (status = 404, description = "Answer could not be found", body = ErrorSchema),
+    )
+)]
+#[get("/task")]
+pub async fn fetch_answer_task(
+    path: Path<AnswerPath>,
+    data: Data<GroupRepo>,
+    app: Data<AppState>,
+    jwt: jwt::JwtMiddleware,
+    permission: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let answer = match data.fetch_answer(&path.answer_id, &path.group_id) {
+        Ok(solution) => solution,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Not Found"})
+        )
+    };
+
+    if answer.created_from != jwt.user_id
+        && !permission.permission_addons.contains(&AccessType::Other) {
+        return HttpResponse::Forbidden().json(
+            serde_json::json!({"message": "Not allowed"})
+        )
+    }
+
+    let task = match data.fetch_task(&answer.task_id) {
+        Ok(task) => task,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let task_doc = match app.mongodb.fetch_task_revision(task.task_doc_id, answer.task_revision).await {
+        Ok(Some(task_doc)) => task_doc,
+        Ok(None) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Task could not be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let task_doc = match answer.seed {
+        Some(seed) => match app.generators.instantiate(&task_doc, seed, &app.graders) {
+            Ok(instance) => instance,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        },
+        None => task_doc,
+    };
+
+    HttpResponse::Ok().json(TaskResponse::from(app.generators.shuffle(task_doc, &answer.permutation)))
+}
+
 /// # Update Answer Endpoint
 ///
 /// This route is used for updating an answer to a task. 
 /// The answer can only be updated by the user who created it. 
-/// The updated answer must adhere to the schema defined for the corresponding task.
+/// The updated answer must adhere to the schema version the corresponding task revision was validated against
+/// and pass the checks of the grader of the task type, e.g. automata may only use the states they define.
 #[utoipa::path(
     patch,
     path="/api/groups/{group_id}/answers/{answer_id}/",
@@ -94,7 +174,7 @@ pub async fn fetch_answer(
     ),
     responses(
         (status = 200, description = "JSON object containing the updated answer details", body = UpdatedAnswerResponse),
-        (status = 400, description = "The updated answer has the wrong format", body = ErrorSchema),
+        (status = 400, description = "The updated answer has the wrong format, listing every validation error", body = ValidationErrorSchema),
         (status = 401, description = "The user is not allowed to update the answer", body = ErrorSchema),
         (status = 403, description = "The answer is not in the 'Active' state", body = ErrorSchema),
         (status = 404, description = "The solution schema could not be found", body = ErrorSchema),
@@ -128,14 +208,27 @@ pub async fn update_answer(
         );
     }
 
-    let task_type = match data.fetch_task_type(&answer.task_id) {
-        Ok(task_type) => task_type,
+    let task = match data.fetch_task(&answer.task_id) {
+        Ok(task) => task,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let task_doc = match app.mongodb.fetch_task_revision(task.task_doc_id, answer.task_revision).await {
+        Ok(task_doc) => task_doc,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
         )
     };
 
-    let schema_doc = match app.mongodb.fetch_schema(task_type.to_string()).await {
+    // the answer has to match the schema version the task revision it is pinned to was validated against
+    let schema_doc = match &task_doc {
+        Some(task_doc) => app.mongodb.fetch_schema_version(task.task_type.clone(), task_doc.schema_version).await,
+        None => app.mongodb.fetch_schema(task.task_type.clone()).await,
+    };
+
+    let schema_doc = match schema_doc {
         Ok(Some(schema_doc)) => schema_doc,
         Ok(None) => return HttpResponse::NotFound().json(
             serde_json::json!({"message": "Schema could not be found"})
@@ -145,26 +238,35 @@ pub async fn update_answer(
         )
     };
 
-    let solution_schema = match JSONSchema::compile(&serde_json::from_str(&serde_json::to_string(&schema_doc.solution_schema).unwrap()).unwrap()) {
+    let solution_schema = match compile_schema(&schema_doc.solution_schema) {
         Ok(v) => v,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Schema could not be parsed"})
         )
     };
 
-    let solution_string = body.solution.to_string();
-    let solution_obj: Value = match serde_json::from_str(&solution_string) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::InternalServerError().json(
-            serde_json::json!({"message": "solution could not be parsed"})
+    if let Err(errors) = validate_document(&solution_schema, &body.solution) {
+        return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("solution has wrong format", errors)
         )
-    };
+    }
 
-    match solution_schema.validate(&solution_obj) {
-        Ok(_) => (),
-        Err(_) => return HttpResponse::BadRequest().json(
-            serde_json::json!({"message": "solution has wrong format"})
-        )
+    if let Some(task_doc) = task_doc {
+        let task_doc = match answer.seed {
+            Some(seed) => match app.generators.instantiate(&task_doc, seed, &app.graders) {
+                Ok(instance) => instance,
+                Err(_) => return HttpResponse::InternalServerError().json(
+                    serde_json::json!({"message": "Something went wrong"})
+                )
+            },
+            None => task_doc,
+        };
+
+        if let Err(errors) = app.graders.get(&task.task_type).validate_answer(&task_doc.task, &body.solution) {
+            return HttpResponse::BadRequest().json(
+                ValidationErrorSchema::new("solution has wrong format", errors)
+            )
+        }
     }
 
     let updated_answer_doc; 
@@ -211,5 +313,6 @@ pub fn config(cfg: &mut ServiceConfig) {
         web::scope("/{answer_id}")
             .service(update_answer)
             .service(fetch_answer)
+            .service(fetch_answer_task)
     );
 }
\ No newline at end of file
@@ -1,9 +1,13 @@
 // Documentation was created by ChatGPT
-use actix_web::{web::{ServiceConfig, self, Path, Data}, HttpResponse, delete, get};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::{repository::{postgres::PgRepo, group::GroupRepo}, permission, jwt, models::groups::{GroupPath, GroupInfoResponse, GroupMetaDataResponse}};
+use actix_web::{web::{ServiceConfig, self, Path, Data, Json, Query}, HttpResponse, delete, get, patch, post};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
 
-use super::{users::users, members::members, task_packages::task_packages, answers::anwers, solution_attempts::solution_attempts, user::user};
+use crate::{repository::{postgres::PgRepo, group::{GroupRepo, READ_GROUP_INFO, GROUP_RESTORE_RETENTION_DAYS}, permissions::PermissionsRepo}, permission, jwt, models::{groups::{GroupPath, GroupInfoResponse, GroupMetaDataResponse, UpdateGroupSchema, UpdateGroup, GroupInfo, GroupTreeQuery, GroupTreeResponse, GroupDescendantsQuery, GroupDescendantsResponse, GroupDescendantResponse, CloneGroupSchema, CloneGroupResponse, CloneGroup}, util::AccessType}};
+
+use super::{users::users, members::members, invitations::invitations, join_requests::join_requests, settings::settings, task_packages::task_packages, answers::anwers, solution_attempts::solution_attempts, user::user};
 
 
 /// # Get Group Information Endpoint
@@ -42,6 +46,193 @@ pub async fn get_group(
     })
 }
 
+/// # Update Group Endpoint
+///
+/// This endpoint renames a group, moves it to another parent and/or changes its join policy.
+/// The whole subtree of the group is moved along with it.
+/// Moving a group below another group requires the `Write` access type on the new parent,
+/// moving it to the top level requires the global `Create` access type.
+#[utoipa::path(
+    patch,
+    path = "/api/groups/{group_id}/",
+    tag = "group",
+    request_body = UpdateGroupSchema,
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group to be updated."),
+    ),
+    responses(
+        (status = 200, description = "The group was successfully updated.", body = GroupInfoResponse),
+        (status = 400, description = "The new name is empty or too long", body = ErrorSchema),
+        (status = 403, description = "The user is not allowed to move the group to the new parent", body = ErrorSchema),
+        (status = 404, description = "Group or new parent could not be found", body = ErrorSchema),
+        (status = 409, description = "The new parent is the group itself or one of its subgroups", body = ErrorSchema),
+    )
+)]
+#[patch("/")]
+pub async fn update_group(
+    body: Json<UpdateGroupSchema>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if let Some(name) = &body.name {
+        if name.trim().is_empty() || name.chars().count() > 100 {
+            return HttpResponse::BadRequest().json(
+                serde_json::json!({"message": "Name must contain between 1 and 100 characters"})
+            )
+        }
+    }
+
+    if group_repo.fetch_active_group_info(path.group_id).is_err() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    if let Some(parent) = body.parent {
+        if let Some(parent_id) = parent {
+            if group_repo.fetch_active_group_info(parent_id).is_err() {
+                return HttpResponse::NotFound().json(
+                    serde_json::json!({"message": "Parent group couldnt be found"})
+                )
+            }
+
+            let parent_ancestors = match group_repo.fetch_group_ancestors(&parent_id) {
+                Ok(v) => v,
+                Err(_) => return HttpResponse::InternalServerError().json(
+                    serde_json::json!({"message": "Something went wrong"})
+                )
+            };
+
+            if parent_id == path.group_id || parent_ancestors.contains(&path.group_id) {
+                return HttpResponse::Conflict().json(
+                    serde_json::json!({"message": "Group cannot be moved into its own subtree"})
+                )
+            }
+        }
+
+        match may_place_group(&permission_repo, &jwt.user_id, &parent) {
+            Ok(true) => (),
+            Ok(false) => return HttpResponse::Forbidden().json(
+                serde_json::json!({"message": "Not allowed to move the group to the new parent"})
+            ),
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            ),
+        }
+    }
+
+    let updated_group = match group_repo.update_group(&path.group_id, &UpdateGroup {
+        name: body.name.as_ref().map(|name| name.trim().to_string()),
+        parent: body.parent,
+        join_policy: body.join_policy,
+        updated_from: jwt.user_id,
+    }) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    HttpResponse::Ok().json(GroupInfoResponse {
+        id: updated_group.id,
+        name: updated_group.name,
+        parent: updated_group.parent,
+    })
+}
+
+/// # Clone Group Endpoint
+///
+/// This endpoint copies a group, e.g. to prepare the course of the next semester.
+/// The copy keeps the join policy and the settings of the group. Optionally the active subgroups
+/// and the active task packages with their tasks are copied as well, the copied tasks link to the
+/// same task documents. Members and solution attempts are not copied.
+/// Everything is copied in a single transaction, the response maps the ids of the originals to the
+/// ids of the copies.
+/// Copying a group requires the `Write` access type on it. Placing the copy below a group requires the
+/// `Write` access type on that group, placing it on the top level requires the global `Create` access type.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/clone",
+    tag = "group",
+    request_body = CloneGroupSchema,
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group to be copied."),
+    ),
+    responses(
+        (status = 201, description = "The group was successfully copied.", body = CloneGroupResponse),
+        (status = 400, description = "The new name is empty or too long", body = ErrorSchema),
+        (status = 403, description = "The user is not allowed to create the copy below the parent", body = ErrorSchema),
+        (status = 404, description = "Group or parent could not be found", body = ErrorSchema),
+    )
+)]
+#[post("/clone")]
+pub async fn clone_group(
+    body: Json<CloneGroupSchema>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if let Some(name) = &body.name {
+        if name.trim().is_empty() || name.chars().count() > 100 {
+            return HttpResponse::BadRequest().json(
+                serde_json::json!({"message": "Name must contain between 1 and 100 characters"})
+            )
+        }
+    }
+
+    let group_info = match group_repo.fetch_active_group_info(path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    };
+
+    let parent = body.parent.unwrap_or(group_info.parent);
+
+    if let Some(parent_id) = parent {
+        if group_repo.fetch_active_group_info(parent_id).is_err() {
+            return HttpResponse::NotFound().json(
+                serde_json::json!({"message": "Parent group couldnt be found"})
+            )
+        }
+    }
+
+    match may_place_group(&permission_repo, &jwt.user_id, &parent) {
+        Ok(true) => (),
+        Ok(false) => return HttpResponse::Forbidden().json(
+            serde_json::json!({"message": "Not allowed to create the copy below the parent"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+
+    let cloned_group = match group_repo.clone_group(&path.group_id, &CloneGroup {
+        name: body.name.as_ref().map(|name| name.trim().to_string()).unwrap_or(group_info.name),
+        parent,
+        include_subgroups: body.include_subgroups.unwrap_or(false),
+        include_task_packages: body.include_task_packages.unwrap_or(true),
+        created_from: jwt.user_id,
+    }) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    HttpResponse::Created().json(CloneGroupResponse {
+        id: cloned_group.id,
+        groups: cloned_group.groups,
+        task_packages: cloned_group.task_packages,
+        tasks: cloned_group.tasks,
+    })
+}
+
 /// # Get Group Metadata Endpoint
 ///
 /// This endpoint retrieves metadata information about a specific group.
@@ -77,12 +268,16 @@ pub async fn get_group_meta_data(
         created_at: group_meta_data.created_at.and_utc(),
         updated_from: group_meta_data.updated_from,
         updated_at: group_meta_data.updated_at.and_utc(),
+        join_policy: group_meta_data.join_policy,
     })
 }
 
 /// # Delete Group Endpoint
 ///
-/// This endpoint deletes a specific group.
+/// This endpoint deletes a specific group together with all of its subgroups.
+/// The task packages of the deleted groups are deleted as well and all group scoped permissions
+/// of the deleted groups are revoked. The deletion can be undone with the restore endpoint
+/// within the retention window.
 #[utoipa::path(
     delete,
     path = "/api/groups/{group_id}",
@@ -92,6 +287,7 @@ pub async fn get_group_meta_data(
     ),
     responses(
         (status = 204, description = "The group was successfully deleted."),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
     )
 )]
 #[delete("/")]
@@ -102,18 +298,337 @@ pub async fn delete_group(
     _: permission::PermissionMiddleware
 ) -> HttpResponse {
     match group_repo.delete_group(&path.group_id, &token_payload.user_id) {
-        Ok(v) => {
-            if v == 1 {
-                return HttpResponse::NoContent().finish()
+        Ok(0) => HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        ),
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+}
+
+/// # Restore Group Endpoint
+///
+/// This endpoint restores a deleted group together with the subgroups, task packages and
+/// group scoped permissions that were deleted along with it.
+/// Only the group the deletion was started on can be restored and only within
+/// `GROUP_RESTORE_RETENTION_DAYS` days after the deletion. The parent of the group has to be active.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/restore",
+    tag = "group",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group to be restored."),
+    ),
+    responses(
+        (status = 200, description = "The group was successfully restored.", body = GroupInfoResponse),
+        (status = 404, description = "Deleted group could not be found", body = ErrorSchema),
+        (status = 409, description = "The group was deleted together with its parent or the parent is deleted", body = ErrorSchema),
+        (status = 410, description = "The retention window of the deleted group is over", body = ErrorSchema),
+    )
+)]
+#[post("/restore")]
+pub async fn restore_group(
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let deleted_group = match group_repo.fetch_deleted_group_info(&path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Deleted group couldnt be found"})
+        )
+    };
+
+    match deleted_group.deleted_with {
+        Some(deleted_with) if deleted_with == path.group_id => (),
+        Some(deleted_with) => return HttpResponse::Conflict().json(
+            serde_json::json!({"message": format!("Group was deleted together with group {}, restore that group instead", deleted_with)})
+        ),
+        None => return HttpResponse::Gone().json(
+            serde_json::json!({"message": "Group cannot be restored anymore"})
+        ),
+    }
+
+    let retention_start = Utc::now().naive_utc() - Duration::days(GROUP_RESTORE_RETENTION_DAYS);
+    if deleted_group.deleted_at.is_none_or(|deleted_at| deleted_at < retention_start) {
+        return HttpResponse::Gone().json(
+            serde_json::json!({"message": "Group cannot be restored anymore"})
+        )
+    }
+
+    if let Some(parent) = deleted_group.parent {
+        if group_repo.fetch_active_group_info(parent).is_err() {
+            return HttpResponse::Conflict().json(
+                serde_json::json!({"message": "Parent group is deleted, restore the parent group first"})
+            )
+        }
+    }
+
+    if group_repo.restore_group(&path.group_id, &jwt.user_id).is_err() {
+        return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+
+    let group_info = match group_repo.fetch_active_group_info(path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    HttpResponse::Ok().json(GroupInfoResponse {
+        id: group_info.id,
+        name: group_info.name,
+        parent: group_info.parent,
+    })
+}
+
+/// Walks the subtree below `root_id` breadth first and returns every group together with its
+/// depth relative to the root. Groups the user cannot read are skipped including their subgroups,
+/// groups deeper than `max_depth` are not visited.
+fn collect_visible_groups(
+    subtree: Vec<GroupInfo>,
+    root_id: &Uuid,
+    max_depth: Option<i32>,
+    readable_group_ids: &Option<HashSet<Uuid>>,
+) -> Vec<(GroupInfo, i32)> {
+    let mut root = None;
+    let mut children: HashMap<Uuid, Vec<GroupInfo>> = HashMap::new();
+
+    for group in subtree {
+        if &group.id == root_id {
+            root = Some(group);
+        } else if let Some(parent) = group.parent {
+            children.entry(parent).or_default().push(group);
+        }
+    }
+
+    let mut visible_groups = vec![];
+    let mut queue = VecDeque::new();
+    if let Some(root) = root {
+        queue.push_back((root, 0));
+    }
+
+    while let Some((group, depth)) = queue.pop_front() {
+        if max_depth.is_none_or(|max_depth| depth < max_depth) {
+            for child in children.remove(&group.id).unwrap_or_default() {
+                let is_readable = readable_group_ids.as_ref()
+                    .is_none_or(|readable_group_ids| readable_group_ids.contains(&child.id));
+
+                if is_readable {
+                    queue.push_back((child, depth + 1));
+                }
             }
-            return HttpResponse::InternalServerError().json(
+        }
+        visible_groups.push((group, depth));
+    }
+
+    visible_groups
+}
+
+/// Loads the subtree below `root_id` and filters it down to the groups the user can read.
+/// Users with the global `Read` access type on groups see the whole subtree.
+fn fetch_visible_groups(
+    group_repo: &GroupRepo,
+    permission_repo: &PermissionsRepo,
+    user_id: &Uuid,
+    root_id: &Uuid,
+    max_depth: Option<i32>,
+) -> Result<Vec<(GroupInfo, i32)>, diesel::result::Error> {
+    let subtree = group_repo.fetch_active_subtree(root_id)?;
+
+    let has_global_read = permission_repo
+        .user_has_permission(user_id, &READ_GROUP_INFO.to_string(), &None)?
+        .contains(&AccessType::Read);
+
+    let readable_group_ids = if has_global_read {
+        None
+    } else {
+        let group_ids = subtree.iter().map(|group| group.id).collect();
+        Some(group_repo.fetch_readable_group_ids(user_id, &group_ids)?.into_iter().collect())
+    };
+
+    Ok(collect_visible_groups(subtree, root_id, max_depth, &readable_group_ids))
+}
+
+/// # Get Group Tree Endpoint
+///
+/// This endpoint retrieves a group together with its subgroups as a nested tree.
+/// Subgroups the user has no read rights on are left out including their branches.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/tree",
+    tag = "group",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the root group of the tree."),
+        ("depth" = Option<i32>, Query, description = "The maximum number of levels below the root group. Default is unlimited."),
+        ("with_counts" = Option<bool>, Query, description = "Whether the amount of members and active task packages is included for every group. Default is false."),
+    ),
+    responses(
+        (status = 200, description = "The request was successful, and the tree of the group is provided.", body = GroupTreeResponse),
+        (status = 400, description = "The depth is negative", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    )
+)]
+#[get("/tree")]
+pub async fn get_group_tree(
+    query: Query<GroupTreeQuery>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if query.depth.is_some_and(|depth| depth < 0) {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Depth must not be negative"})
+        )
+    }
+
+    let visible_groups = match fetch_visible_groups(&group_repo, &permission_repo, &jwt.user_id, &path.group_id, query.depth) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    if visible_groups.is_empty() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    let (member_counts, task_package_counts) = if query.with_counts.unwrap_or(false) {
+        let group_ids = visible_groups.iter().map(|(group, _)| group.id).collect();
+
+        match (group_repo.fetch_member_counts(&group_ids), group_repo.fetch_task_package_counts(&group_ids)) {
+            (Ok(member_counts), Ok(task_package_counts)) => (Some(member_counts), Some(task_package_counts)),
+            _ => return HttpResponse::InternalServerError().json(
                 serde_json::json!({"message": "Something went wrong"})
             )
         }
+    } else {
+        (None, None)
+    };
+
+    // Groups are visited breadth first, so going backwards every child is built before its parent.
+    let mut child_nodes: HashMap<Uuid, Vec<GroupTreeResponse>> = HashMap::new();
+    let mut root_node = None;
+
+    for (group, depth) in visible_groups.into_iter().rev() {
+        let mut children = child_nodes.remove(&group.id).unwrap_or_default();
+        children.reverse();
+
+        let node = GroupTreeResponse {
+            id: group.id,
+            name: group.name,
+            parent: group.parent,
+            depth,
+            member_count: member_counts.as_ref().map(|counts| counts.get(&group.id).copied().unwrap_or(0)),
+            task_package_count: task_package_counts.as_ref().map(|counts| counts.get(&group.id).copied().unwrap_or(0)),
+            children,
+        };
+
+        match group.parent {
+            Some(parent) if depth > 0 => child_nodes.entry(parent).or_default().push(node),
+            _ => root_node = Some(node),
+        }
+    }
+
+    HttpResponse::Ok().json(root_node)
+}
+
+/// # Get Group Descendants Endpoint
+///
+/// This endpoint retrieves all subgroups of a group as a flat, paginated list ordered by depth and name.
+/// Subgroups the user has no read rights on are left out including their branches.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/descendants",
+    tag = "group",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose descendants are fetched."),
+        ("depth" = Option<i32>, Query, description = "The maximum number of levels below the group. Default is unlimited."),
+        ("page" = Option<i32>, Query, description = "The page number for paginated results (default: 0)."),
+        ("limit" = Option<i32>, Query, description = "The number of groups to retrieve per page (default: 200)."),
+    ),
+    responses(
+        (status = 200, description = "The request was successful, and the descendants of the group are provided.", body = GroupDescendantsResponse),
+        (status = 400, description = "The depth or the paging parameters are negative", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    )
+)]
+#[get("/descendants")]
+pub async fn get_group_descendants(
+    query: Query<GroupDescendantsQuery>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let page = query.page.unwrap_or(0);
+    let limit = query.limit.unwrap_or(200);
+
+    if query.depth.is_some_and(|depth| depth < 0) || page < 0 || limit < 0 {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Depth, page and limit must not be negative"})
+        )
+    }
+
+    let mut visible_groups = match fetch_visible_groups(&group_repo, &permission_repo, &jwt.user_id, &path.group_id, query.depth) {
+        Ok(v) => v,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
         )
+    };
+
+    if visible_groups.is_empty() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
     }
+
+    visible_groups.retain(|(_, depth)| *depth > 0);
+    visible_groups.sort_by(|(group_a, depth_a), (group_b, depth_b)|
+        depth_a.cmp(depth_b).then_with(|| group_a.name.cmp(&group_b.name))
+    );
+
+    let total_count = visible_groups.len() as i64;
+
+    HttpResponse::Ok().json(GroupDescendantsResponse {
+        groups: visible_groups.into_iter()
+            .skip((page as usize) * (limit as usize))
+            .take(limit as usize)
+            .map(|(group, depth)| GroupDescendantResponse {
+                id: group.id,
+                name: group.name,
+                parent: group.parent,
+                depth,
+            }).collect(),
+        total_count,
+    })
+}
+
+/// Checks whether a user may place a group below `parent`. This requires the `Write` access type on the
+/// parent, or the global `Create` access type for top level groups.
+fn may_place_group(
+    permission_repo: &PermissionsRepo,
+    user_id: &Uuid,
+    parent: &Option<Uuid>,
+) -> Result<bool, diesel::result::Error> {
+    let required_access_type = match parent {
+        Some(_) => AccessType::Write,
+        None => AccessType::Create,
+    };
+
+    Ok(permission_repo
+        .user_has_permission(user_id, &READ_GROUP_INFO.to_string(), parent)?
+        .contains(&required_access_type))
 }
 
 pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
@@ -121,12 +636,20 @@ pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
         web::scope("/{group_id}")
             .service(get_group)
             .service(get_group_meta_data)
+            .service(update_group)
+            .service(get_group_tree)
+            .service(get_group_descendants)
             .service(delete_group)
+            .service(restore_group)
+            .service(clone_group)
             .configure(task_packages::config)
             .configure(anwers::config)
             .configure(solution_attempts::config)
             .configure(user::config)
             .configure(users::config)
             .configure(|cfg| members::config(cfg, pgdb.clone()))
+            .configure(invitations::config)
+            .configure(join_requests::config)
+            .configure(settings::config)
     );
 }
\ No newline at end of file
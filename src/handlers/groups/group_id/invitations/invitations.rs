@@ -0,0 +1,198 @@
+// Documentation was created by ChatGPT
+use actix_web::{web::{ServiceConfig, self, Data, Json, Path}, post, get, delete, HttpResponse};
+use chrono::Utc;
+use diesel::result::{Error, DatabaseErrorKind};
+
+use crate::{jwt, permission, repository::{group::{GroupRepo, GROUP_MEMBER_INFO, ADD_MEMBER_ROLE_KEY}, permissions::PermissionsRepo}, models::{groups::GroupPath, invitations::{CreateInvitationSchema, NewGroupInvitation, InvitationResponse, InvitationListResponse, InvitationPath}, util::AccessType}};
+
+/// # Create Group Invitation
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes the created invitation together with its join code.
+///
+/// ## Notes
+///
+/// - Anyone holding the join code can redeem it via `POST /api/user/invitations/redeem` until it expires,
+///   is used up or gets revoked.
+/// - `email` restricts the invitation to one user. If no user with that email exists yet,
+///   the invitation is redeemed automatically when the user registers.
+/// - `role` is the role whose permissions the new member receives for the group. Without a role the
+///   default member role is used. Choosing another role requires the global `Write` access type on
+///   group members.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/invitations/",
+    tag = "invitation",
+    request_body = CreateInvitationSchema,
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the invitation is for."),
+    ),
+    responses(
+        (status = 201, description = "The invitation was created.", body = InvitationResponse),
+        (status = 400, description = "The email, role, maximum uses or expiry date is invalid.", body = ErrorSchema),
+        (status = 403, description = "The user is not allowed to invite with the given role.", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    ),
+)]
+#[post("/")]
+pub async fn create_invitation(
+    body: Json<CreateInvitationSchema>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if body.max_uses.is_some_and(|max_uses| max_uses < 1) {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "max_uses must be at least 1"})
+        )
+    }
+
+    if body.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "expires_at must be in the future"})
+        )
+    }
+
+    let email = body.email.as_ref().map(|email| email.trim().to_lowercase());
+    if email.as_ref().is_some_and(|email| !email.contains('@') || email.chars().count() > 100) {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Invalid email"})
+        )
+    }
+
+    if body.role.as_ref().is_some_and(|role| role != ADD_MEMBER_ROLE_KEY) {
+        let permission_addons = match permission_repo.user_has_permission(
+            &jwt.user_id,
+            &GROUP_MEMBER_INFO.to_string(),
+            &None,
+        ) {
+            Ok(v) => v,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        if !permission_addons.contains(&AccessType::Write) {
+            return HttpResponse::Forbidden().json(
+                serde_json::json!({"message": "Not allowed to invite with this role"})
+            )
+        }
+    }
+
+    if group_repo.fetch_active_group_info(path.group_id).is_err() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    match group_repo.create_invitation(&NewGroupInvitation {
+        group_id: path.group_id,
+        email,
+        role: body.role.clone(),
+        max_uses: body.max_uses,
+        expires_at: body.expires_at.map(|expires_at| expires_at.naive_utc()),
+        created_from: jwt.user_id,
+    }) {
+        Ok(invitation) => HttpResponse::Created().json(InvitationResponse::from(invitation)),
+        Err(Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _)) => HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Role doesnt exist"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Get Group Invitations
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes all invitations of the group, including revoked ones, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/invitations/",
+    tag = "invitation",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose invitations are retrieved."),
+    ),
+    responses(
+        (status = 200, description = "Invitations were successfully retrieved.", body = InvitationListResponse),
+    ),
+)]
+#[get("/")]
+pub async fn get_invitations(
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match group_repo.fetch_invitations(&path.group_id) {
+        Ok(invitations) => HttpResponse::Ok().json(InvitationListResponse {
+            invitations: invitations.into_iter().map(InvitationResponse::from).collect(),
+        }),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Revoke Group Invitation
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes the revoked invitation. Revoked invitations can no longer be redeemed,
+/// memberships created through them are kept.
+#[utoipa::path(
+    delete,
+    path = "/api/groups/{group_id}/invitations/{invitation_id}/",
+    tag = "invitation",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the invitation belongs to."),
+        ("invitation_id" = Uuid, Path, description = "The unique identifier of the invitation to revoke."),
+    ),
+    responses(
+        (status = 200, description = "The invitation was revoked.", body = InvitationResponse),
+        (status = 404, description = "No active invitation with this id exists for the group.", body = ErrorSchema),
+    ),
+)]
+#[delete("/{invitation_id}/")]
+pub async fn revoke_invitation(
+    path: Path<InvitationPath>,
+    group_repo: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match group_repo.revoke_invitation(&path.group_id, &path.invitation_id) {
+        Ok(invitation) => HttpResponse::Ok().json(InvitationResponse::from(invitation)),
+        Err(Error::NotFound) => HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Invitation couldnt be found"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        web::scope("/invitations")
+            .service(create_invitation)
+            .service(get_invitations)
+            .service(revoke_invitation)
+    );
+}
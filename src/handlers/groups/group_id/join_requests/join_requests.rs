@@ -0,0 +1,287 @@
+// Documentation was created by ChatGPT
+use actix_web::{web::{ServiceConfig, self, Data, Json, Path, Query}, post, get, HttpResponse};
+use chrono::Utc;
+use diesel::result::{Error, DatabaseErrorKind};
+
+use crate::{jwt, permission, repository::group::GroupRepo, models::{groups::{GroupPath, NewGroupMember}, join_requests::{CreateJoinRequestSchema, NewGroupJoinRequest, JoinRequestResponse, JoinRequestListResponse, JoinRequestPath, JoinRequestQuery}, util::{JoinPolicy, JoinRequestState}}};
+
+/// # Request To Join Group
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token. No group permission is needed.
+///
+/// ## Response
+///
+/// The response includes the created join request.
+///
+/// ## Notes
+///
+/// - Groups with the `Request` join policy get a `Pending` request which a group manager approves or rejects.
+/// - Groups with the `Open` join policy add the user right away, the request is returned as `Approved`.
+/// - Groups with the `Closed` join policy cannot be joined this way.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/join_requests/",
+    tag = "join_request",
+    request_body = CreateJoinRequestSchema,
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the user wants to join."),
+    ),
+    responses(
+        (status = 201, description = "The join request was created.", body = JoinRequestResponse),
+        (status = 400, description = "The message is too long.", body = ErrorSchema),
+        (status = 403, description = "The group does not accept join requests.", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+        (status = 409, description = "The user is already a member or already has a pending request.", body = ErrorSchema),
+    ),
+)]
+#[post("/")]
+pub async fn create_join_request(
+    body: Json<CreateJoinRequestSchema>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let message = body.message.as_ref().map(|message| message.trim().to_string()).filter(|message| !message.is_empty());
+    if message.as_ref().is_some_and(|message| message.chars().count() > 500) {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Message must not be longer than 500 characters"})
+        )
+    }
+
+    let group_meta_data = match group_repo.fetch_active_group_meta_data(path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    };
+
+    if group_meta_data.join_policy == JoinPolicy::Closed {
+        return HttpResponse::Forbidden().json(
+            serde_json::json!({"message": "Group doesnt accept join requests"})
+        )
+    }
+
+    match group_repo.is_member_of_group(&path.group_id, &jwt.user_id) {
+        Ok(false) => (),
+        Ok(true) => return HttpResponse::Conflict().json(
+            serde_json::json!({"message": "User is already a member of the group"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+
+    let (state, decided_at) = if group_meta_data.join_policy == JoinPolicy::Open {
+        match group_repo.add_user_to_group(&NewGroupMember {
+            group_id: &path.group_id,
+            user_id: &jwt.user_id,
+        }) {
+            Ok(_) => (),
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => return HttpResponse::Conflict().json(
+                serde_json::json!({"message": "User is already a member of the group"})
+            ),
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        }
+
+        (JoinRequestState::Approved, Some(Utc::now().naive_utc()))
+    } else {
+        (JoinRequestState::Pending, None)
+    };
+
+    match group_repo.create_join_request(&NewGroupJoinRequest {
+        group_id: path.group_id,
+        user_id: jwt.user_id,
+        message,
+        state,
+        decided_at,
+    }) {
+        Ok(join_request) => HttpResponse::Created().json(JoinRequestResponse::from(join_request)),
+        Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => HttpResponse::Conflict().json(
+            serde_json::json!({"message": "User already has a pending join request for the group"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Get Group Join Requests
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes the join requests of the group together with the requesting users, newest first.
+/// Without `state` all requests are returned.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/join_requests/",
+    tag = "join_request",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose join requests are retrieved."),
+        ("state" = Option<JoinRequestState>, Query, description = "Only return join requests in this state."),
+    ),
+    responses(
+        (status = 200, description = "Join requests were successfully retrieved.", body = JoinRequestListResponse),
+    ),
+)]
+#[get("/")]
+pub async fn get_join_requests(
+    path: Path<GroupPath>,
+    query: Query<JoinRequestQuery>,
+    group_repo: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match group_repo.fetch_join_requests(&path.group_id, query.state) {
+        Ok(join_requests) => HttpResponse::Ok().json(JoinRequestListResponse {
+            join_requests: join_requests.into_iter().map(JoinRequestResponse::from).collect(),
+        }),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Approve Join Request
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes the approved join request. The user is added to the group with the
+/// permissions of the default member role.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/join_requests/{join_request_id}/approve",
+    tag = "join_request",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the join request belongs to."),
+        ("join_request_id" = Uuid, Path, description = "The unique identifier of the join request to approve."),
+    ),
+    responses(
+        (status = 200, description = "The join request was approved.", body = JoinRequestResponse),
+        (status = 404, description = "Group or join request could not be found", body = ErrorSchema),
+        (status = 409, description = "The join request was already decided.", body = ErrorSchema),
+    ),
+)]
+#[post("/{join_request_id}/approve")]
+pub async fn approve_join_request(
+    path: Path<JoinRequestPath>,
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if group_repo.fetch_active_group_info(path.group_id).is_err() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    let join_request = match group_repo.fetch_join_request(&path.group_id, &path.join_request_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Join request couldnt be found"})
+        )
+    };
+
+    if join_request.state != JoinRequestState::Pending {
+        return HttpResponse::Conflict().json(
+            serde_json::json!({"message": "Join request was already decided"})
+        )
+    }
+
+    match group_repo.approve_join_request(&path.group_id, &path.join_request_id, &jwt.user_id) {
+        Ok(join_request) => HttpResponse::Ok().json(JoinRequestResponse::from(join_request)),
+        // Another manager decided the request in the meantime
+        Err(Error::NotFound) => HttpResponse::Conflict().json(
+            serde_json::json!({"message": "Join request was already decided"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Reject Join Request
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes the rejected join request. The user can send a new request afterwards.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/join_requests/{join_request_id}/reject",
+    tag = "join_request",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the join request belongs to."),
+        ("join_request_id" = Uuid, Path, description = "The unique identifier of the join request to reject."),
+    ),
+    responses(
+        (status = 200, description = "The join request was rejected.", body = JoinRequestResponse),
+        (status = 404, description = "Join request could not be found", body = ErrorSchema),
+        (status = 409, description = "The join request was already decided.", body = ErrorSchema),
+    ),
+)]
+#[post("/{join_request_id}/reject")]
+pub async fn reject_join_request(
+    path: Path<JoinRequestPath>,
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let join_request = match group_repo.fetch_join_request(&path.group_id, &path.join_request_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Join request couldnt be found"})
+        )
+    };
+
+    if join_request.state != JoinRequestState::Pending {
+        return HttpResponse::Conflict().json(
+            serde_json::json!({"message": "Join request was already decided"})
+        )
+    }
+
+    decide_join_request(&group_repo, &path, JoinRequestState::Rejected, &jwt)
+}
+
+fn decide_join_request(
+    group_repo: &GroupRepo,
+    path: &JoinRequestPath,
+    state: JoinRequestState,
+    jwt: &jwt::JwtMiddleware,
+) -> HttpResponse {
+    match group_repo.decide_join_request(&path.group_id, &path.join_request_id, state, &jwt.user_id) {
+        Ok(join_request) => HttpResponse::Ok().json(JoinRequestResponse::from(join_request)),
+        // Another manager decided the request in the meantime
+        Err(Error::NotFound) => HttpResponse::Conflict().json(
+            serde_json::json!({"message": "Join request was already decided"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        web::scope("/join_requests")
+            .service(create_join_request)
+            .service(get_join_requests)
+            .service(approve_join_request)
+            .service(reject_join_request)
+    );
+}
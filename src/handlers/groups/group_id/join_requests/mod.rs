@@ -0,0 +1 @@
+pub mod join_requests;
\ No newline at end of file
@@ -1,8 +1,14 @@
 // Documentation was created by ChatGPT
+use std::collections::HashSet;
+
 use actix_web::{web::{ServiceConfig, self, Data, Json, Query, Path}, post, get, delete, HttpResponse};
+use diesel::result::{Error, DatabaseErrorKind};
 use uuid::Uuid;
 
-use crate::{jwt, permission, repository::{group::GroupRepo, users::UsersRepo, postgres::PgRepo}, models::{groups::{GroupAddMemberSchema, NewGroupMember, GroupPath, GroupRemoveMemberSchema}, members::{MembersPagingSchema, MemberInfoResponse, MemberListResponse, MemberListWithCount, MemberListWithCountResponse}, util::{PagingSchema, OrderDir}, users::UserResponse}};
+use super::roster;
+
+use crate::{jwt, permission, repository::{group::GroupRepo, users::UsersRepo, postgres::PgRepo}, models::{groups::{GroupAddMemberSchema, NewGroupMember, GroupPath, GroupRemoveMemberSchema}, members::{MembersPagingSchema, MemberInfoResponse, MemberFilter, MemberListWithCountResponse, GroupMemberResponse, MemberPermissionResponse, MemberBatchQuery, MemberBatchResponse, MemberEntryResult, MemberEntryStatus, MemberInfo, OnError}, util::{PagingSchema, OrderDir, AccessType}, users::UserResponse}};
+use itertools::Itertools;
 
 /// # Add Member to Group
 ///
@@ -12,15 +18,18 @@ use crate::{jwt, permission, repository::{group::GroupRepo, users::UsersRepo, po
 ///
 /// ## Response
 ///
-/// The response includes a list of added members.
+/// The response includes a list of added members and a result for every entry of `new_members`.
 ///
 /// ## Notes
 ///
 /// - This route adds one or more members to the specified group.
 /// - The `new_members` parameter contains a list of email addresses of users to be added to the group.
-/// - The response includes details about the added members, including member ID, user ID, user name, and user email.
+/// - Failed entries carry one of the reasons `unknown_email`, `inactive_user`, `already_member`,
+///   `duplicate_entry` or `internal_error`.
+/// - With `on_error=continue` (default) all valid entries are added. With `on_error=abort` no member is
+///   added if one entry fails, the response status is then "Bad Request" (400) and valid entries are
+///   reported as `Skipped`.
 /// - Users with the appropriate permissions can add members to a group.
-/// - The added members are returned in the response.
 #[utoipa::path(
     post,
     path = "/api/groups/{group_id}/members/",
@@ -28,51 +37,81 @@ use crate::{jwt, permission, repository::{group::GroupRepo, users::UsersRepo, po
     request_body = GroupAddMemberSchema,
     params(
         ("group_id" = String, Path, description = "The unique identifier of the group to which members are being added."),
+        ("on_error" = Option<OnError>, Query, description = "Whether the valid entries are added if an entry fails. Default is continue."),
     ),
     responses(
         (
             status = 201, 
-            description = "Members were successfully added to the group.", 
-            body = CreateGroupResponse
+            description = "The entries were processed.", 
+            body = MemberBatchResponse
         ),
+        (status = 400, description = "An entry failed and the batch was aborted.", body = MemberBatchResponse),
     ),
 )]
 #[post("/")]
 pub async fn add_member_to_group(
     body: Json<GroupAddMemberSchema>,
+    query: Query<MemberBatchQuery>,
     path: Path<GroupPath>,
     data: Data<GroupRepo>,
     users_repo: Data<UsersRepo>,
     _: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
-    let mut added_members: Vec<MemberInfoResponse> = vec![];
-
-    for user_to_add in &body.new_members {
-        let user_id = users_repo.fetch_active_user_id_by_email(user_to_add).unwrap_or(Uuid::default());
-
-        if user_id != Uuid::default() {
-            let add_result = data.add_user_to_group(&NewGroupMember {
-                user_id: &user_id,
-                group_id: &path.group_id,
-            });
-
-            if add_result.is_ok() {
-                let added_member = add_result.unwrap();
-                added_members.push(MemberInfoResponse { 
-                    id: added_member.member_id, 
-                    user: UserResponse { 
-                        id: added_member.user_id,
-                        name: added_member.name, 
-                        email: added_member.email, 
-                    }
-                });
-            }
+    let mut seen_users: HashSet<Uuid> = HashSet::new();
+
+    let entries: Vec<Result<Uuid, &str>> = body.new_members.iter().map(|user_to_add| {
+        let user_id = match users_repo.fetch_active_user_id_by_email(user_to_add) {
+            Ok(v) => v,
+            Err(Error::NotFound) => return match users_repo.exist_user_with_email(user_to_add.clone()) {
+                Ok(true) => Err("inactive_user"),
+                Ok(false) => Err("unknown_email"),
+                Err(_) => Err("internal_error"),
+            },
+            Err(_) => return Err("internal_error"),
+        };
+
+        if !seen_users.insert(user_id) {
+            return Err("duplicate_entry")
         }
-    };
-    HttpResponse::Created().json(MemberListResponse {
-        members: added_members,
-    })
+
+        match data.is_member_of_group(&path.group_id, &user_id) {
+            Ok(true) => Err("already_member"),
+            Ok(false) => Ok(user_id),
+            Err(_) => Err("internal_error"),
+        }
+    }).collect();
+
+    if query.on_error == Some(OnError::Abort) {
+        if entries.iter().any(|entry| entry.is_err()) {
+            return HttpResponse::BadRequest().json(aborted_batch(&body.new_members, &entries))
+        }
+
+        let user_ids: Vec<Uuid> = entries.into_iter().flatten().collect();
+
+        let added_members = match data.add_users_to_group(&path.group_id, &user_ids) {
+            Ok(v) => v,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        return HttpResponse::Created().json(applied_batch(
+            body.new_members.iter().cloned().zip(added_members.into_iter().map(Ok)),
+            MemberEntryStatus::Added,
+        ))
+    }
+
+    let results = body.new_members.iter().cloned().zip(entries).map(|(entry, user_id)| {
+        let result = user_id.and_then(|user_id| data.add_user_to_group(&NewGroupMember {
+            user_id: &user_id,
+            group_id: &path.group_id,
+        }).map_err(|err| failure_reason(&err)));
+
+        (entry, result)
+    });
+
+    HttpResponse::Created().json(applied_batch(results, MemberEntryStatus::Added))
 }
 
 /// # Remove Member from Group
@@ -83,15 +122,17 @@ pub async fn add_member_to_group(
 ///
 /// ## Response
 ///
-/// The response includes a list of removed members.
+/// The response includes a list of removed members and a result for every entry of `remove_members`.
 ///
 /// ## Notes
 ///
 /// - This route removes one or more members from the specified group.
-/// - The `remove_members` parameter contains a list of user IDs to be removed from the group.
-/// - The response includes details about the removed members, including member ID, user ID, user name, and user email.
+/// - The `remove_members` parameter contains a list of member IDs to be removed from the group.
+/// - Failed entries carry one of the reasons `member_not_found`, `duplicate_entry` or `internal_error`.
+/// - With `on_error=continue` (default) all valid entries are removed. With `on_error=abort` no member is
+///   removed if one entry fails, the response status is then "Bad Request" (400) and valid entries are
+///   reported as `Skipped`.
 /// - Users with the appropriate permissions can remove members from a group.
-/// - The removed members are returned in the response.
 #[utoipa::path(
     delete,
     path = "/api/groups/{group_id}/members/",
@@ -99,43 +140,133 @@ pub async fn add_member_to_group(
     request_body = GroupRemoveMemberSchema,
     params(
         ("group_id" = String, Path, description = "The unique identifier of the group from which members are being removed."),
+        ("on_error" = Option<OnError>, Query, description = "Whether the valid entries are removed if an entry fails. Default is continue."),
     ),
     responses(
         (
             status = 200, 
-            description = "Members were successfully removed from the group.", 
-            body = MemberListResponse
-        )
+            description = "The entries were processed.", 
+            body = MemberBatchResponse
+        ),
+        (status = 400, description = "An entry failed and the batch was aborted.", body = MemberBatchResponse),
     )
 )]
 #[delete("/")]
 pub async fn remove_member_from_group(
     body: Json<GroupRemoveMemberSchema>,
+    query: Query<MemberBatchQuery>,
     path: Path<GroupPath>,
     data: Data<GroupRepo>,
     _: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
-    let mut removed_members: Vec<MemberInfoResponse> = vec![];
-
-    for user_to_remove in &body.remove_members {
-        let remove_result = data.remove_user_from_group(&user_to_remove, &path.group_id);
-
-        if remove_result.is_ok() {
-            let removed_member = remove_result.unwrap();
-            removed_members.push(MemberInfoResponse { 
-                id: removed_member.member_id, 
-                user: UserResponse { 
-                    id: removed_member.user_id,
-                    name: removed_member.name, 
-                    email: removed_member.email, 
-                }
-            });
+    let member_ids: Vec<String> = body.remove_members.iter().map(|member_id| member_id.to_string()).collect();
+
+    if query.on_error == Some(OnError::Abort) {
+        let existing_members: HashSet<Uuid> = match data.fetch_all_member_of_group_in(&path.group_id, &body.remove_members) {
+            Ok(v) => v.into_iter().map(|member| member.member_id).collect(),
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        let mut seen_members: HashSet<Uuid> = HashSet::new();
+        let entries: Vec<Result<Uuid, &str>> = body.remove_members.iter().map(|member_id| {
+            if !seen_members.insert(*member_id) {
+                Err("duplicate_entry")
+            } else if !existing_members.contains(member_id) {
+                Err("member_not_found")
+            } else {
+                Ok(*member_id)
+            }
+        }).collect();
+
+        if entries.iter().any(|entry| entry.is_err()) {
+            return HttpResponse::BadRequest().json(aborted_batch(&member_ids, &entries))
         }
-    };
-    HttpResponse::Ok().json(MemberListResponse {
-        members: removed_members,
-    })
+
+        let removed_members = match data.remove_users_from_group(&body.remove_members, &path.group_id) {
+            Ok(v) => v,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        return HttpResponse::Ok().json(applied_batch(
+            member_ids.into_iter().zip(removed_members.into_iter().map(Ok)),
+            MemberEntryStatus::Removed,
+        ))
+    }
+
+    let mut seen_members: HashSet<Uuid> = HashSet::new();
+    let results = member_ids.into_iter().zip(&body.remove_members).map(|(entry, member_id)| {
+        if !seen_members.insert(*member_id) {
+            return (entry, Err("duplicate_entry"))
+        }
+
+        let result = data.remove_user_from_group(member_id, &path.group_id).map_err(|err| match err {
+            Error::NotFound => "member_not_found",
+            err => failure_reason(&err),
+        });
+
+        (entry, result)
+    });
+
+    HttpResponse::Ok().json(applied_batch(results, MemberEntryStatus::Removed))
+}
+
+/// Maps the error of adding or removing a single member to its reason code.
+fn failure_reason(err: &Error) -> &'static str {
+    match err {
+        Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => "already_member",
+        Error::DatabaseError(_, info) if info.message() == "user is not active" => "inactive_user",
+        _ => "internal_error",
+    }
+}
+
+/// Builds the report of a batch whose entries were applied one by one.
+fn applied_batch<'a>(
+    results: impl Iterator<Item = (String, Result<MemberInfo, &'a str>)>,
+    status: MemberEntryStatus,
+) -> MemberBatchResponse {
+    let mut members: Vec<MemberInfoResponse> = vec![];
+
+    let results: Vec<MemberEntryResult> = results.map(|(entry, result)| match result {
+        Ok(member) => {
+            let member = MemberInfoResponse::from(member);
+            members.push(member.clone());
+
+            MemberEntryResult { entry, status, reason: None, member: Some(member) }
+        },
+        Err(reason) => MemberEntryResult {
+            entry,
+            status: MemberEntryStatus::Failed,
+            reason: Some(reason.to_string()),
+            member: None,
+        },
+    }).collect();
+
+    MemberBatchResponse {
+        members,
+        failed_count: results.iter().filter(|result| result.status == MemberEntryStatus::Failed).count(),
+        results,
+    }
+}
+
+/// Builds the report of an aborted batch, no entry was applied.
+fn aborted_batch<T>(entries: &[String], results: &[Result<T, &str>]) -> MemberBatchResponse {
+    let results: Vec<MemberEntryResult> = entries.iter().zip(results).map(|(entry, result)| MemberEntryResult {
+        entry: entry.clone(),
+        status: if result.is_ok() { MemberEntryStatus::Skipped } else { MemberEntryStatus::Failed },
+        reason: result.as_ref().err().map(|reason| reason.to_string()),
+        member: None,
+    }).collect();
+
+    MemberBatchResponse {
+        members: vec![],
+        failed_count: results.iter().filter(|result| result.status == MemberEntryStatus::Failed).count(),
+        results,
+    }
 }
 
 /// # Get Group Members
@@ -151,10 +282,13 @@ pub async fn remove_member_from_group(
 /// ## Notes
 ///
 /// - This route retrieves information about the members of a specified group.
-/// - The response includes details such as member ID, user ID, user name, and user email.
-/// - Members can be filtered based on specific member IDs.
-/// - Pagination parameters (`limit`, `page`, and `order`) allow control over the number and order of retrieved members.
+/// - The response includes details such as member ID, user ID, user name, user email, the join date,
+///   the role the member joined with and the granted group scoped permissions of the member.
+/// - Members can be filtered based on specific member IDs, their role and a ressource (and access type) they have a permission for.
+/// - Pagination parameters (`limit`, `page`, `sort` and `order`) allow control over the number and order of retrieved members.
 /// - Users with the appropriate permissions can view the members of a group.
+/// - If the group settings hide the members from each other (`members_visible` is `false`), only users with
+///   the `Write` access type on group members, e.g. the teachers of the group, can view them.
 #[utoipa::path(
     get,
     path = "/api/groups/{group_id}/members/",
@@ -162,16 +296,21 @@ pub async fn remove_member_from_group(
     params(
         ("group_id" = String, Path, description = "The unique identifier of the group for which members are being retrieved."),
         ("member_ids[]" = Option<String>, Query, description = "A list of member IDs to retrieve only specific members."),
+        ("role" = Option<String>, Query, description = "Only members who joined the group with this role."),
+        ("ressource" = Option<String>, Query, description = "Only members with a permission for this ressource in the group."),
+        ("access_type" = Option<AccessType>, Query, description = "Together with `ressource`, only members granted this access type."),
         ("page" = Option<i32>, Query, description = "The page number of results if the request is paginated. Default is set to page 0."),
         ("limit" = Option<i32>, Query, description = "The maximum number of members to be returned in a single request. Default is set to 200."),
-        ("order" = Option<OrderDir>, Query, description = "The order of results (ascending or descending). Default is set to descending (DESC).")
+        ("sort" = Option<MemberSort>, Query, description = "The column the members are sorted by. Default is `joined_at`."),
+        ("order" = Option<OrderDir>, Query, description = "The order of results (ascending or descending). Default is set to ascending (ASC).")
     ),
     responses(
         (
             status = 200, 
             description = "Members were successfully retrieved.", 
-            body = GroupPagingResponse
+            body = MemberListWithCountResponse
         ),
+        (status = 403, description = "The members of the group are not visible to the user.", body = ErrorSchema),
     ),
 )]
 #[get("/")]
@@ -180,41 +319,62 @@ pub async fn get_group_members(
     group_repo: Data<GroupRepo>,
     path: Path<GroupPath>,
     _: jwt::JwtMiddleware,
-    _: permission::PermissionMiddleware,
+    permission: permission::PermissionMiddleware,
 ) -> HttpResponse {
-    //TODO: compine paging and member_ids
+    if !permission.permission_addons.contains(&AccessType::Write) {
+        match group_repo.fetch_group_settings(&path.group_id) {
+            Ok(settings) if !settings.members_visible => return HttpResponse::Forbidden().json(
+                serde_json::json!({"message": "The members of this group are not visible"})
+            ),
+            Ok(_) => (),
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            ),
+        }
+    }
+
     let pagination = &PagingSchema{
             limit: query.limit.unwrap_or(200),
             page: query.page.unwrap_or(0),
-            order: query.order.unwrap_or(OrderDir::DESC),
+            order: query.order.unwrap_or(OrderDir::ASC),
         };
 
-    let member_list_with_count: MemberListWithCount;
+    let filter = MemberFilter {
+        member_ids: query.member_ids.clone(),
+        role: query.role.clone(),
+        ressource: query.ressource.clone(),
+        access_type: query.access_type,
+        sort: query.sort.unwrap_or_default(),
+    };
 
-    if query.member_ids.is_none() {
-        member_list_with_count = match group_repo.fetch_all_member_of_group(&path.group_id, pagination) {
-            Ok(v) => v,
-            Err(_) => return HttpResponse::InternalServerError().json(
-                serde_json::json!({"message": "Something went wrong"})
-            )
-        };
-    } else {
-        member_list_with_count = match group_repo.fetch_all_member_of_group_in(&path.group_id, &query.member_ids.as_ref().unwrap()) {
-            Ok(v) => v,
-            Err(_) => return HttpResponse::InternalServerError().json(
-                serde_json::json!({"message": "Something went wrong"})
-            )
-        }
-    }
+    let mut member_list_with_count = match group_repo.fetch_all_member_of_group(&path.group_id, &filter, pagination) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
 
     HttpResponse::Ok().json(MemberListWithCountResponse {
-        members: member_list_with_count.member_list.into_iter().map(|member| MemberInfoResponse { 
+        members: member_list_with_count.member_list.into_iter().map(|member| GroupMemberResponse { 
             id: member.member_id,
+            joined_at: member.joined_at.and_utc(),
+            role: member.role,
+            permissions: member_list_with_count.permissions
+                .remove(&member.user_id)
+                .unwrap_or_default()
+                .into_iter()
+                .group_by(|(ressource, _)| ressource.clone())
+                .into_iter()
+                .map(|(ressource, permissions)| MemberPermissionResponse {
+                    ressource,
+                    access_types: permissions.map(|(_, access_type)| access_type).collect(),
+                })
+                .collect(),
             user: UserResponse { 
                 id: member.user_id,
                 name: member.name,
                 email: member.email,
-            }
+            },
         }).collect(),
         total_count: member_list_with_count.total_count,
     })
@@ -228,5 +388,7 @@ pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
             .service(add_member_to_group)
             .service(remove_member_from_group)
             .service(get_group_members)
+            .service(roster::import_members)
+            .service(roster::export_members)
     );
 }
\ No newline at end of file
@@ -1 +1,2 @@
-pub mod members;
\ No newline at end of file
+pub mod members;
+pub mod roster;
\ No newline at end of file
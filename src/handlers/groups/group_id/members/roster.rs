@@ -0,0 +1,345 @@
+// Documentation was created by ChatGPT
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::MultipartForm;
+use actix_web::{web::{self, Data, Path}, post, get, HttpResponse, http::header::{ContentDisposition, DispositionParam, DispositionType}};
+use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
+use rand_core::{OsRng, RngCore};
+
+use crate::{jwt, permission, repository::{group::{GroupRepo, GROUP_MEMBER_INFO, ADD_MEMBER_ROLE_KEY}, users::UsersRepo, permissions::PermissionsRepo}, models::{groups::{GroupPath, NewGroupMember}, invitations::NewGroupInvitation, roster::{ImportMembersForm, ImportMembersResponse, ImportRowResult, ImportRowStatus, MissingUserStrategy, RosterRow}, users::NewUser, util::AccessType}};
+
+const GENERATED_PASSWORD_LENGTH: usize = 16;
+
+/// # Import Group Members from CSV
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes a result for every row of the file. Rows fail independently of each other,
+/// failed rows carry one of the reasons `invalid_row`, `invalid_email`, `duplicate_email`, `unknown_role`,
+/// `role_not_allowed`, `unknown_user`, `missing_name`, `inactive_user` or `internal_error`.
+///
+/// ## Notes
+///
+/// - The file is uploaded as the `file` field of a `multipart/form-data` body. It needs a header row with
+///   the column `email`, the columns `name` and `role` are optional.
+/// - Members get the permissions of the role of their row, or of the default member role of the group. Other
+///   roles than the `add_member` role require the global `Write` access type on group members.
+/// - `missing_users` is an optional field of the form and decides what happens to emails without an account: `Skip` (default), `Invite` creates
+///   an invitation restricted to the email which is redeemed when the user registers, `Password` creates
+///   the account with a generated password which is returned once in the report.
+/// - With the form field `dry_run` set to `true` nothing is changed, the report shows what the import would do.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/members/import",
+    tag = "member",
+    request_body(content = ImportMembersForm, content_type = "multipart/form-data"),
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group to which members are imported."),
+    ),
+    responses(
+        (status = 200, description = "The file was processed.", body = ImportMembersResponse),
+        (status = 400, description = "The file is not a CSV file with an `email` column.", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    ),
+)]
+#[post("/import")]
+pub async fn import_members(
+    MultipartForm(form): MultipartForm<ImportMembersForm>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    users_repo: Data<UsersRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let dry_run = form.dry_run.map(|dry_run| dry_run.into_inner()).unwrap_or(false);
+    let missing_users = form.missing_users.map(|missing_users| missing_users.into_inner()).unwrap_or(MissingUserStrategy::Skip);
+
+    if group_repo.fetch_active_group_info(path.group_id).is_err() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    let default_role = match group_repo.fetch_default_member_role(&path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(form.file.data.as_ref());
+
+    let headers = match reader.headers() {
+        Ok(headers) => headers.iter().map(|header| header.to_lowercase()).collect::<csv::StringRecord>(),
+        Err(_) => return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "File is not a valid CSV file"})
+        )
+    };
+
+    if !headers.iter().any(|header| header == "email") {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "CSV file needs an email column"})
+        )
+    }
+
+    let can_assign_roles = match permission_repo.user_has_permission(&jwt.user_id, &GROUP_MEMBER_INFO.to_string(), &None) {
+        Ok(permission_addons) => permission_addons.contains(&AccessType::Write),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let mut known_roles: HashMap<String, bool> = HashMap::new();
+    let mut seen_emails: HashSet<String> = HashSet::new();
+    let mut rows: Vec<ImportRowResult> = vec![];
+
+    for (index, record) in reader.records().enumerate() {
+        let row = index + 2;
+
+        let roster_row: Option<RosterRow> = record.ok().and_then(|record| record.deserialize(Some(&headers)).ok());
+        let roster_row = match roster_row {
+            Some(v) => v,
+            None => {
+                rows.push(failed_row(row, String::new(), "invalid_row"));
+                continue;
+            }
+        };
+
+        let email = roster_row.email.to_lowercase();
+        let name = roster_row.name.filter(|name| !name.is_empty());
+        let role = roster_row.role.filter(|role| !role.is_empty());
+
+        if !email.contains('@') || email.chars().count() > 100 {
+            rows.push(failed_row(row, email, "invalid_email"));
+            continue;
+        }
+
+        if !seen_emails.insert(email.clone()) {
+            rows.push(failed_row(row, email, "duplicate_email"));
+            continue;
+        }
+
+        if let Some(role) = &role {
+            let role_exists = match known_roles.get(role) {
+                Some(v) => *v,
+                None => {
+                    let role_exists = permission_repo.role_exists(role).unwrap_or(false);
+                    known_roles.insert(role.clone(), role_exists);
+                    role_exists
+                }
+            };
+
+            if !role_exists {
+                rows.push(failed_row(row, email, "unknown_role"));
+                continue;
+            }
+
+            if role != ADD_MEMBER_ROLE_KEY && !can_assign_roles {
+                rows.push(failed_row(row, email, "role_not_allowed"));
+                continue;
+            }
+        }
+
+        let role_key = role.as_deref().unwrap_or(&default_role);
+
+        rows.push(match users_repo.fetch_active_user_id_by_email(&email) {
+            Ok(user_id) => {
+                match group_repo.is_member_of_group(&path.group_id, &user_id) {
+                    Ok(true) => ImportRowResult {
+                        user_id: Some(user_id),
+                        ..row_result(row, email, ImportRowStatus::AlreadyMember)
+                    },
+                    Ok(false) if dry_run => ImportRowResult {
+                        user_id: Some(user_id),
+                        ..row_result(row, email, ImportRowStatus::Added)
+                    },
+                    Ok(false) => match group_repo.add_user_to_group_with_role(&NewGroupMember {
+                        group_id: &path.group_id,
+                        user_id: &user_id,
+                    }, role_key) {
+                        Ok(_) => ImportRowResult {
+                            user_id: Some(user_id),
+                            ..row_result(row, email, ImportRowStatus::Added)
+                        },
+                        Err(_) => failed_row(row, email, "internal_error"),
+                    },
+                    Err(_) => failed_row(row, email, "internal_error"),
+                }
+            },
+            Err(_) => match users_repo.exist_user_with_email(email.clone()) {
+                Ok(true) => failed_row(row, email, "inactive_user"),
+                Ok(false) => match missing_users {
+                    MissingUserStrategy::Skip => failed_row(row, email, "unknown_user"),
+                    MissingUserStrategy::Invite if dry_run => row_result(row, email, ImportRowStatus::Invited),
+                    MissingUserStrategy::Invite => match group_repo.create_invitation(&NewGroupInvitation {
+                        group_id: path.group_id,
+                        email: Some(email.clone()),
+                        role: role.clone(),
+                        max_uses: Some(1),
+                        expires_at: None,
+                        created_from: jwt.user_id,
+                    }) {
+                        Ok(invitation) => ImportRowResult {
+                            invitation_code: Some(invitation.code),
+                            ..row_result(row, email, ImportRowStatus::Invited)
+                        },
+                        Err(_) => failed_row(row, email, "internal_error"),
+                    },
+                    MissingUserStrategy::Password => match name {
+                        None => failed_row(row, email, "missing_name"),
+                        Some(_) if dry_run => row_result(row, email, ImportRowStatus::Created),
+                        Some(name) => create_member_with_password(&group_repo, &path.group_id, row, email, &name, role_key).await,
+                    },
+                },
+                Err(_) => failed_row(row, email, "internal_error"),
+            },
+        });
+    }
+
+    HttpResponse::Ok().json(ImportMembersResponse {
+        dry_run,
+        failed_count: rows.iter().filter(|row| row.status == ImportRowStatus::Failed).count(),
+        rows,
+    })
+}
+
+/// # Export Group Members as CSV
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response is a CSV file with the columns `email`, `name` and `role` and one row per member,
+/// ordered by name. The file can be imported into another group. The role is empty for members who
+/// joined before roles were recorded.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/members/export",
+    tag = "member",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose members are exported."),
+    ),
+    responses(
+        (status = 200, description = "The members of the group as CSV file.", content_type = "text/csv", body = String),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    ),
+)]
+#[get("/export")]
+pub async fn export_members(
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if group_repo.fetch_active_group_info(path.group_id).is_err() {
+        return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        )
+    }
+
+    let members = match group_repo.fetch_roster(&path.group_id) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    let written = writer.write_record(["email", "name", "role"]).and_then(|_| {
+        members.iter().try_for_each(|member| writer.write_record([
+            member.email.as_str(),
+            member.name.as_str(),
+            member.role.as_deref().unwrap_or_default(),
+        ]))
+    });
+
+    let csv_file = match written.ok().and_then(|_| writer.into_inner().ok()) {
+        Some(v) => v,
+        None => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("members-{}.csv", path.group_id))],
+        })
+        .body(csv_file)
+}
+
+async fn create_member_with_password(
+    group_repo: &GroupRepo,
+    group_id: &uuid::Uuid,
+    row: usize,
+    email: String,
+    name: &str,
+    role_key: &str,
+) -> ImportRowResult {
+    if name.chars().count() > 255 {
+        return failed_row(row, email, "invalid_row")
+    }
+
+    let password = generate_password();
+    let hashed_password = match web::block({
+        let password = password.clone();
+        move || {
+            let salt = SaltString::generate(&mut OsRng);
+            Argon2::default().hash_password(password.as_bytes(), &salt).map(|hash| hash.to_string())
+        }
+    }).await {
+        Ok(Ok(v)) => v,
+        _ => return failed_row(row, email, "internal_error"),
+    };
+
+    match group_repo.create_user_as_member(&NewUser {
+        password: &hashed_password,
+        name,
+        email: &email,
+    }, group_id, role_key) {
+        Ok(member) => ImportRowResult {
+            user_id: Some(member.user_id),
+            password: Some(password),
+            ..row_result(row, email, ImportRowStatus::Created)
+        },
+        Err(_) => failed_row(row, email, "internal_error"),
+    }
+}
+
+fn row_result(row: usize, email: String, status: ImportRowStatus) -> ImportRowResult {
+    ImportRowResult {
+        row,
+        email,
+        status,
+        reason: None,
+        user_id: None,
+        invitation_code: None,
+        password: None,
+    }
+}
+
+fn failed_row(row: usize, email: String, reason: &str) -> ImportRowResult {
+    ImportRowResult {
+        reason: Some(reason.to_string()),
+        ..row_result(row, email, ImportRowStatus::Failed)
+    }
+}
+
+fn generate_password() -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz23456789";
+
+    (0..GENERATED_PASSWORD_LENGTH)
+        .map(|_| ALPHABET[OsRng.next_u32() as usize % ALPHABET.len()] as char)
+        .collect()
+}
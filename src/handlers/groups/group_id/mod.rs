@@ -4,4 +4,7 @@ pub mod answers;
 pub mod task_packages;
 pub mod group_id;
 pub mod user;
-pub mod users;
\ No newline at end of file
+pub mod users;
+pub mod invitations;
+pub mod join_requests;
+pub mod settings;
\ No newline at end of file
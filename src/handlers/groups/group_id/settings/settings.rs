@@ -0,0 +1,191 @@
+// Documentation was created by ChatGPT
+use actix_web::{web::{ServiceConfig, Path, Data, Json}, get, patch, HttpResponse};
+use jsonschema::JSONSchema;
+
+use crate::{jwt, permission, repository::{group::{GroupRepo, GROUP_MEMBER_INFO, ADD_MEMBER_ROLE_KEY}, permissions::PermissionsRepo}, models::{groups::GroupPath, group_settings::GroupSettings, util::AccessType}};
+
+const GROUP_SETTINGS_SCHEMA: &str = include_str!("../../../../assets/group_settings.schema.json");
+
+/// # Get Group Settings
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes all settings of the group, settings that were never set have their default value.
+#[utoipa::path(
+    get,
+    path = "/api/groups/{group_id}/settings",
+    tag = "group",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose settings are fetched."),
+    ),
+    responses(
+        (status = 200, description = "The settings of the group.", body = GroupSettings),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    )
+)]
+#[get("/settings")]
+pub async fn get_group_settings(
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match group_repo.fetch_group_settings(&path.group_id) {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(diesel::result::Error::NotFound) => HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+/// # Update Group Settings
+///
+/// ## Authentication
+///
+/// This route requires a valid JWT authentication token.
+///
+/// ## Response
+///
+/// The response includes all settings of the group after the update.
+///
+/// ## Notes
+///
+/// - The body is merged into the current settings: fields in the body replace the current value,
+///   `null` resets a field to its default and missing fields are kept.
+/// - The merged settings are validated against the settings schema, unknown fields are rejected.
+///   `term_end` must not be before `term_start`.
+/// - `default_member_role` must be an existing role. Other roles than the `add_member` role require the
+///   global `Write` access type on group members.
+#[utoipa::path(
+    patch,
+    path = "/api/groups/{group_id}/settings",
+    tag = "group",
+    request_body = GroupSettings,
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group whose settings are updated."),
+    ),
+    responses(
+        (status = 200, description = "The settings were successfully updated.", body = GroupSettings),
+        (status = 400, description = "The settings are not valid", body = ErrorSchema),
+        (status = 403, description = "The user is not allowed to set the default member role", body = ErrorSchema),
+        (status = 404, description = "Group could not be found", body = ErrorSchema),
+    )
+)]
+#[patch("/settings")]
+pub async fn update_group_settings(
+    body: Json<serde_json::Value>,
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let changes = match body.as_object() {
+        Some(v) => v,
+        None => return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Settings must be a JSON object"})
+        )
+    };
+
+    let current_settings = match group_repo.fetch_group_settings(&path.group_id) {
+        Ok(v) => v,
+        Err(diesel::result::Error::NotFound) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    };
+
+    let mut merged_settings = match serde_json::to_value(&current_settings) {
+        Ok(serde_json::Value::Object(v)) => v,
+        _ => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    };
+
+    for (key, value) in changes {
+        if value.is_null() && merged_settings.contains_key(key) {
+            merged_settings.remove(key);
+        } else {
+            merged_settings.insert(key.clone(), value.clone());
+        }
+    }
+
+    let merged_settings = serde_json::Value::Object(merged_settings);
+
+    let schema = match JSONSchema::compile(&serde_json::from_str(GROUP_SETTINGS_SCHEMA).unwrap()) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    };
+
+    if let Err(mut errors) = schema.validate(&merged_settings) {
+        let message = errors.next().map(|error| error.to_string()).unwrap_or_default();
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": format!("Settings are not valid: {}", message)})
+        )
+    }
+
+    let settings: GroupSettings = match serde_json::from_value(merged_settings) {
+        Ok(v) => v,
+        Err(err) => return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": format!("Settings are not valid: {}", err)})
+        ),
+    };
+
+    if let (Some(term_start), Some(term_end)) = (settings.term_start, settings.term_end) {
+        if term_end < term_start {
+            return HttpResponse::BadRequest().json(
+                serde_json::json!({"message": "term_end must not be before term_start"})
+            )
+        }
+    }
+
+    if let Some(role) = &settings.default_member_role {
+        if current_settings.default_member_role.as_ref() != Some(role) {
+            match permission_repo.role_exists(role) {
+                Ok(true) => (),
+                Ok(false) => return HttpResponse::BadRequest().json(
+                    serde_json::json!({"message": "Default member role does not exist"})
+                ),
+                Err(_) => return HttpResponse::InternalServerError().json(
+                    serde_json::json!({"message": "Something went wrong"})
+                ),
+            }
+
+            if role != ADD_MEMBER_ROLE_KEY {
+                match permission_repo.user_has_permission(&jwt.user_id, &GROUP_MEMBER_INFO.to_string(), &None) {
+                    Ok(permission_addons) if permission_addons.contains(&AccessType::Write) => (),
+                    Ok(_) => return HttpResponse::Forbidden().json(
+                        serde_json::json!({"message": "Not allowed to set the default member role"})
+                    ),
+                    Err(_) => return HttpResponse::InternalServerError().json(
+                        serde_json::json!({"message": "Something went wrong"})
+                    ),
+                }
+            }
+        }
+    }
+
+    match group_repo.update_group_settings(&path.group_id, &settings, &jwt.user_id) {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg
+        .service(get_group_settings)
+        .service(update_group_settings);
+}
@@ -1,19 +1,19 @@
 // Documentation was created by ChatGPT
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use actix_web::{web::{ServiceConfig, self, Path, Data, Query}, post, get, HttpResponse};
+use actix_web::{web::{ServiceConfig, self, Path, Data, Query}, post, get, HttpResponse, error::BlockingError};
+use mongodb::bson::Document;
 use uuid::Uuid;
 
-use crate::{models::{solution_attempts::SolutionAttemptGroupPath, util}, AppState};
-use crate::models::util::AccessType;
+use crate::{grading::grader::{Grade, GradeFeedback}, models::{solution_attempts::{AnswerEntry, SolutionAttemptGroupPath}, task::TaskDoc}, AppState};
+use crate::models::util::{AccessType, AnswerState};
 use crate::{models::{solution_attempts::{SolutionAttemptPath, SolutionAttemptWithAnswerListResponse}, util::Visibility, users::{UserPath, UserQuery}}, permission, jwt, repository::{group::GroupRepo, postgres::PgRepo}};
 
 /// # Finish Solution Attempt
 /// 
 /// This route is used to finish a solution attempt for a specific group. 
-/// It validates and compares the submitted solutions with the expected solutions for each task in the attempt and updates the state accordingly. 
-/// Only users with the necessary permissions or the solution attempt owner can finish the attempt.
-/// 
+/// It grades every answer with the grader of its task type, against the instance and the order of options it was given to, and stores the feedback of the grader. 
+/// Answers of slow task types, e.g. programming tasks, are graded in the background by at most `GRADING_WORKERS` workers and stay in the `Grading` state until then. 
 /// Only users with the necessary permissions or the solution attempt owner can finish the attempt.
 #[utoipa::path(
     post,
@@ -52,7 +52,16 @@ pub async fn finish_solution_attempt(
         return  HttpResponse::Forbidden().json(serde_json::json!({"message": "Forbidden access to Solution group"}));
     }
 
-    let mut answer_accurate_state: HashMap<Uuid, bool> = HashMap::new();
+    if solution_attempt.state != AnswerState::Active {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Solution Group already finished"})
+        );
+    }
+
+    let mut answer_scores: HashMap<Uuid, f64> = HashMap::new();
+    let mut answer_feedback: HashMap<Uuid, Vec<GradeFeedback>> = HashMap::new();
+    let mut background_grading: Vec<BackgroundGrading> = vec![];
+    let mut grading_answers: HashSet<Uuid> = HashSet::new();
 
     for answer in solution_attempt.solution_list {
         let answer_doc = match app.mongodb.fetch_answer_doc(answer.answer_doc_id).await {
@@ -62,24 +71,50 @@ pub async fn finish_solution_attempt(
             )
         };
 
-        if answer_doc.is_none() {
-            answer_accurate_state.insert(answer.answer_id, false);
+        let answer_doc = match answer_doc {
+            Some(answer_doc) => answer_doc,
+            None => {
+                answer_scores.insert(answer.answer_id, 0.0);
+                continue;
+            }
+        };
+
+        let (task_doc, answer_solution) = match answered_task(&app, &answer, &answer_doc.solution).await {
+            Some(answered_task) => answered_task,
+            None => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        if app.graders.get(&task_doc.task_type).grades_in_background() {
+            answer_scores.insert(answer.answer_id, 0.0);
+            grading_answers.insert(answer.answer_id);
+            answer_feedback.insert(answer_doc.id, vec![GradeFeedback {
+                path: String::new(),
+                correct: false,
+                message: "The answer is being graded".to_string(),
+            }]);
+            background_grading.push(BackgroundGrading {
+                answer_id: answer.answer_id,
+                answer_doc_id: answer_doc.id,
+                task_doc,
+                answer: answer_solution,
+            });
             continue;
         }
-        let task_doc = match app.mongodb.fetch_task(answer.task_doc_id, false).await {
-            Ok(task_doc) => task_doc.unwrap(),
+
+        let grade = match grade_blocking(&app, task_doc, answer_solution).await {
+            Ok(grade) => grade,
             Err(_) => return HttpResponse::InternalServerError().json(
                 serde_json::json!({"message": "Something went wrong"})
             )
         };
 
-        answer_accurate_state.insert(answer.answer_id, util::compare_json_objects_ignore_order(
-            &serde_json::from_str(&answer_doc.unwrap().solution.to_string()).unwrap(), 
-            &serde_json::from_str(&task_doc.solution.to_string()).unwrap()
-        ));
+        answer_scores.insert(answer.answer_id, grade.share());
+        answer_feedback.insert(answer_doc.id, grade.feedback);
     }
 
-    let amount_updated_solutions = match data.finish_solution_attempt(&path.solution_attempt_id, &answer_accurate_state) {
+    let amount_updated_solutions = match data.finish_solution_attempt(&path.solution_attempt_id, &answer_scores, &grading_answers) {
         Ok(amount_updated_solutions) => amount_updated_solutions,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
@@ -92,9 +127,115 @@ pub async fn finish_solution_attempt(
         );
     }
 
+    // The attempt is finished, so the answers have to be graded even if some feedback could not be stored
+    let mut feedback_stored = true;
+
+    for (answer_doc_id, feedback) in answer_feedback {
+        feedback_stored &= app.mongodb.update_answer_feedback(&answer_doc_id, &feedback).await.is_ok();
+    }
+
+    for grading in background_grading {
+        actix_web::rt::spawn(grade_in_background(data.clone(), app.clone(), grading));
+    }
+
+    if !feedback_stored {
+        return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+
     HttpResponse::NoContent().finish()
 }
 
+/// An answer of a finished solution attempt which is graded after the attempt is finished.
+struct BackgroundGrading {
+    answer_id: Uuid,
+    answer_doc_id: Uuid,
+    task_doc: TaskDoc,
+    answer: Document,
+}
+
+/// Grades an answer on the blocking thread pool, so large answers do not stall the other requests.
+async fn grade_blocking(app: &AppState, task_doc: TaskDoc, answer: Document) -> Result<Grade, BlockingError> {
+    let graders = app.graders.clone();
+
+    web::block(move || graders.get(&task_doc.task_type).grade(&task_doc.task, &task_doc.solution, &answer)).await
+}
+
+/// Fetches the revision of the task an answer was given to, instantiates it with the seed of the answer
+/// and maps the answer back to the original order of the options of the task.
+async fn answered_task(app: &AppState, answer: &AnswerEntry, solution: &Document) -> Option<(TaskDoc, Document)> {
+    let task_doc = app.mongodb.fetch_task_revision(answer.task_doc_id, answer.task_revision).await.ok()??;
+
+    let task_doc = match answer.seed {
+        Some(seed) => app.generators.instantiate(&task_doc, seed, &app.graders).ok()?,
+        None => task_doc,
+    };
+
+    let solution = app.generators.unshuffle_answer(&task_doc.task_type, solution, &answer.permutation);
+
+    Some((task_doc, solution))
+}
+
+/// The grade of an answer which could not be graded in the background.
+fn ungraded() -> Grade {
+    Grade::new(0.0, 1.0, vec![GradeFeedback {
+        path: String::new(),
+        correct: false,
+        message: "The answer could not be graded".to_string(),
+    }])
+}
+
+/// Grades an answer on the blocking thread pool once one of the `GRADING_WORKERS` is free and stores its score and feedback.
+async fn grade_in_background(data: Data<GroupRepo>, app: Data<AppState>, grading: BackgroundGrading) {
+    let grade = {
+        let _worker = app.grading_workers.acquire().await;
+
+        grade_blocking(&app, grading.task_doc, grading.answer).await.unwrap_or_else(|_| ungraded())
+    };
+
+    store_background_grade(&data, &app, grading.answer_id, grading.answer_doc_id, grade).await;
+}
+
+async fn store_background_grade(data: &GroupRepo, app: &AppState, answer_id: Uuid, answer_doc_id: Uuid, grade: Grade) {
+    if data.update_answer_score(&answer_id, grade.share()).is_ok() {
+        let _ = app.mongodb.update_answer_feedback(&answer_doc_id, &grade.feedback).await;
+    }
+}
+
+/// Grades the answers which are still in the `Grading` state in the background, e.g. because the server was stopped while grading them.
+/// Answers whose document or task cannot be found anymore score no points,
+/// answers whose document cannot be fetched stay in the `Grading` state until the next start.
+pub async fn resume_background_grading(data: Data<GroupRepo>, app: Data<AppState>) {
+    let answers = match data.fetch_grading_answers() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: the answers being graded could not be fetched, they are graded when the server starts again: {err}");
+            return;
+        }
+    };
+
+    for answer in answers {
+        let answered_task = match app.mongodb.fetch_answer_doc(answer.answer_doc_id).await {
+            Ok(Some(answer_doc)) => answered_task(&app, &answer, &answer_doc.solution).await,
+            Ok(None) => None,
+            Err(_) => continue,
+        };
+
+        match answered_task {
+            Some((task_doc, solution)) => {
+                actix_web::rt::spawn(grade_in_background(data.clone(), app.clone(), BackgroundGrading {
+                    answer_id: answer.answer_id,
+                    answer_doc_id: answer.answer_doc_id,
+                    task_doc,
+                    answer: solution,
+                }));
+            },
+            None => store_background_grade(&data, &app, answer.answer_id, answer.answer_doc_id, ungraded()).await,
+        }
+    }
+}
+
 
 /// # Fetch Solution Attempt
 /// 
@@ -149,6 +290,8 @@ pub async fn fetch_solution_attempt(
         created_at: solution_attempt.solution_attempt.created_at.and_utc(),
         answer_list: solution_attempt.solution_list,
         state: solution_attempt.state,
+        score: solution_attempt.solution_attempt.score,
+        max_score: solution_attempt.solution_attempt.max_score,
     })
 }
 
@@ -1,9 +1,13 @@
 // Documentation was created by ChatGPT
+use std::collections::HashMap;
+
 use actix_web::{
     post,
     web::{self, Data, Json, Path, ServiceConfig},
     HttpResponse,
 };
+use uuid::Uuid;
+use rand_core::{OsRng, RngCore};
 
 use crate::{
     jwt,
@@ -13,6 +17,8 @@ use crate::{
     },
     permission,
     repository::group::GroupRepo,
+    AppState,
+    grading::template::SeededRng,
 };
 
 /// # Create Solution Attempt
@@ -21,6 +27,10 @@ use crate::{
 /// It allows users to initiate a solution attempt for a specific task package. 
 /// The created solution attempt includes metadata such as the user, task package, visibility, and initial state. 
 /// Access is restricted based on user permissions.
+/// No solution attempts can be started for deleted task packages or task packages of deleted groups.
+/// Without a visibility in the request, the default visibility of the group settings is used.
+/// Every task template of the package gets a random seed, stored with its answer, from which the instance of the student is generated.
+/// Tasks shuffling their options get a random order of the options, stored with their answer as well.
 #[utoipa::path(
     post,
     path="/api/groups/{group_id}/task_packages/{task_package_id}/solution_attempts/",
@@ -32,6 +42,7 @@ use crate::{
     request_body = CreateSolutionAttemptSchema,
     responses(
         (status = 201, description = "The solution attempt is successfully created", body = CreatedSolutionAttemptResponse),
+        (status = 404, description = "The task package does not exist or is deleted", body = ErrorSchema),
     )
 )]
 #[post("/")]
@@ -39,11 +50,71 @@ pub async fn create_solution_attempt(
     body: Json<CreateSolutionAttemptSchema>,
     path: Path<TaskPackagePath>,
     data: Data<GroupRepo>,
+    app: Data<AppState>,
     jwt: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
-    //TODO Not Found if wrong task_package_id or group_id
-    match data.create_solution_attempt(&jwt.user_id, &path.task_package_id, &path.group_id, &body.visibility) {
+    match data.is_task_package_active(&path.task_package_id, &path.group_id) {
+        Ok(true) => (),
+        Ok(false) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Task package couldnt be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+
+    let visibility = match &body.visibility {
+        Some(v) => Some(v.clone()),
+        None => match data.fetch_group_settings(&path.group_id) {
+            Ok(settings) => settings.default_visibility,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            ),
+        },
+    };
+
+    let tasks = match data.fetch_tasks_from_package(&path.task_package_id, &path.group_id, &None) {
+        Ok(tasks) => tasks,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    };
+
+    let mut seeds: HashMap<Uuid, i64> = HashMap::new();
+    let mut permutations: HashMap<Uuid, Vec<i32>> = HashMap::new();
+
+    for task in tasks {
+        let task_doc = match app.mongodb.fetch_task_revision(task.task_doc_id, task.task_revision).await {
+            Ok(Some(task_doc)) => task_doc,
+            _ => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            ),
+        };
+
+        let task_doc = match task_doc.parameters {
+            Some(_) => {
+                let seed = OsRng.next_u64() as i64;
+
+                let instance = match app.generators.instantiate(&task_doc, seed, &app.graders) {
+                    Ok(instance) => instance,
+                    Err(_) => return HttpResponse::InternalServerError().json(
+                        serde_json::json!({"message": "Something went wrong"})
+                    ),
+                };
+
+                seeds.insert(task.task_doc_id, seed);
+                instance
+            },
+            None => task_doc,
+        };
+
+        if let Some(permutation) = app.generators.permutation(&task_doc, &mut SeededRng::new(OsRng.next_u64() as i64)) {
+            permutations.insert(task.task_doc_id, permutation);
+        }
+    }
+
+    match data.create_solution_attempt(&jwt.user_id, &path.task_package_id, &path.group_id, &visibility, &seeds, &permutations) {
         Ok(created_soltuion_group) => {
             HttpResponse::Created().json(CreatedSolutionAttemptResponse {
                 id: created_soltuion_group.solution_attempt.id,
@@ -53,6 +124,8 @@ pub async fn create_solution_attempt(
                 created_at: created_soltuion_group.solution_attempt.created_at.and_utc(),
                 answer_list: created_soltuion_group.solution_list,
                 state: created_soltuion_group.state,
+                score: created_soltuion_group.solution_attempt.score,
+                max_score: created_soltuion_group.solution_attempt.max_score,
             })
         }
         Err(_) => HttpResponse::InternalServerError()
@@ -1,6 +1,6 @@
 // Documentation was created by ChatGPT
 use actix_web::{
-    delete, get, post,
+    delete, get, patch, post,
     web::{self, Data, Json, Path, Query, ServiceConfig},
     HttpResponse,
 };
@@ -12,7 +12,7 @@ use crate::{
         task::{
             AddTasksToPackageSchema, AddedTasksToPackageResponse, TasksFromPackageResponse,
             NewTempTask, RemoveTasksFromPackageSchema, RemoveTasksFromPackageResponse,
-            TaskTypeFilter, TasksResponse, TaskResponse,
+            TaskTypeFilter, TasksResponse, TaskResponse, UpdateTaskPointsSchema,
         },
         task_package::TaskPackagePath,
         util::{OrderDir, PagingSchema},
@@ -168,12 +168,54 @@ pub async fn remove_tasks_from_package(
     }
 }
 
+/// # Update Task Points in Task Package
+///
+/// This route is used to set the points answers to tasks of a task package are weighted with.
+/// Answers which are already finished keep the points they were scored with.
+/// Access is restricted based on user permissions.
+#[utoipa::path(
+    patch,
+    path="/api/groups/{group_id}/task_packages/{task_package_id}/tasks/",
+    tag="task",
+    params(
+        ("task_package_id" = Uuid, Path, description = "The unique identifier for the task package of the tasks."),
+        ("group_id" = Uuid, Path, description = "The unique identifier for the group associated with the task package."),
+    ),
+    responses(
+        (status = 200, description = "The request was successful, and the updated tasks are returned", body = TasksFromPackageResponse),
+        (status = 400, description = "The points are negative or not a number", body = ErrorSchema),
+    ),
+    request_body = UpdateTaskPointsSchema
+)]
+#[patch("/")]
+pub async fn update_task_points(
+    body: Json<UpdateTaskPointsSchema>,
+    path: Path<TaskPackagePath>,
+    data: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    if body.tasks.iter().any(|task| !task.points.is_finite() || task.points < 0.0) {
+        return HttpResponse::BadRequest()
+            .json(serde_json::json!({"message": "Points must be a positive number"}));
+    }
+
+    match data.update_task_points(&path.task_package_id, &path.group_id, &body.tasks) {
+        Ok(tasks) => HttpResponse::Ok().json(TasksFromPackageResponse {
+            tasks,
+        }),
+        Err(_) => HttpResponse::InternalServerError()
+            .json(serde_json::json!({"message": "Something went wrong"})),
+    }
+}
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(
         web::scope("/tasks")
             //.service(get_tasks_from_package)
             .service(remove_tasks_from_package)
             .service(add_tasks_to_package)
+            .service(update_task_points)
             .service(get_tasks_infos_from_package),
     );
 }
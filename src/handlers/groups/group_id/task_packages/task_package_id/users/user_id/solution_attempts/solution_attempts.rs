@@ -71,6 +71,8 @@ pub async fn fetch_user_solution_attempts(
                 visibility: solution_group.0.visibility,
                 created_at: solution_group.0.created_at.and_utc(),
                 state: solution_group.1,
+                score: solution_group.0.score,
+                max_score: solution_group.0.max_score,
             })
             .collect(),
     })
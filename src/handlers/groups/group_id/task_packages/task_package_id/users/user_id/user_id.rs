@@ -8,6 +8,7 @@ use super::solution_attempts::solution_attempts;
 /// # Fetch Task Package Statistic Endpoint
 ///
 /// This endpoint retrieves statistics for a specific task package and user within a group, allowing users to analyze their performance and completion rates across different task types.
+/// Each solution attempt reports the amount of correct answers and the points scored out of the points of its tasks.
 ///
 #[utoipa::path(
     get,
@@ -1,10 +1,10 @@
 // Documentation was created by ChatGPT
 use std::io::{self, Write};
 
-use actix_web::{web::{self, ServiceConfig, Data, Json}, HttpResponse, post};
+use actix_web::{web::{self, ServiceConfig, Data, Json, Query}, HttpResponse, post, get};
 use uuid::Uuid;
 
-use crate::{repository::{group::GroupRepo, postgres::PgRepo}, jwt, permission, models::groups::{CreateGroupSchema, NewGroup, CreateGroupResponse}};
+use crate::{repository::{group::{GroupRepo, READ_GROUP_INFO}, postgres::PgRepo, permissions::PermissionsRepo}, jwt, permission, models::{groups::{CreateGroupSchema, NewGroup, CreateGroupResponse, GroupSearchSchema, GroupSearchFilter, GroupSearchResponse, GroupSearchResult}, group_settings::GroupSettings, util::{PagingSchema, OrderDir, AccessType}}};
 
 use super::group_id::group_id;
 
@@ -53,12 +53,88 @@ pub async fn create_group(
     })
 }
 
+/// # Search Groups Endpoint
+///
+/// This endpoint searches the active groups by name and description. Users without the global
+/// `Read` permission for groups only find the groups they can read and the groups listed in the course catalogue.
+#[utoipa::path(
+    get,
+    path = "/api/groups/search",
+    tag = "group",
+    params(
+        ("q" = Option<String>, Query, description = "Search text, every word has to occur in the name or description of a group."),
+        ("ancestor" = Option<Uuid>, Query, description = "Only groups below this group."),
+        ("member" = Option<bool>, Query, description = "Only groups the user is (`true`) or is not (`false`) a member of."),
+        ("catalogue" = Option<bool>, Query, description = "Only groups that are (`true`) or are not (`false`) listed in the course catalogue."),
+        ("page" = Option<i32>, Query, description = "The page number for paginated results (default: 0)."),
+        ("limit" = Option<i32>, Query, description = "The number of groups to retrieve per page (default: 50, max: 200)."),
+    ),
+    responses(
+        (status = 200, description = "The found groups ordered by relevance.", body = GroupSearchResponse),
+        (status = 400, description = "The page or limit is negative.", body = ErrorSchema),
+    ),
+)]
+#[get("/search")]
+pub async fn search_groups(
+    query: Query<GroupSearchSchema>,
+    group_repo: Data<GroupRepo>,
+    permission_repo: Data<PermissionsRepo>,
+    jwt: jwt::JwtMiddleware,
+) -> HttpResponse {
+    let pagination = PagingSchema {
+        limit: query.limit.unwrap_or(50).min(200),
+        page: query.page.unwrap_or(0),
+        order: OrderDir::ASC,
+    };
+
+    if pagination.limit < 0 || pagination.page < 0 {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Page and limit must not be negative"})
+        )
+    }
+
+    let all_groups = permission_repo
+        .user_has_permission(&jwt.user_id, &READ_GROUP_INFO.to_string(), &None)
+        .unwrap_or(vec![])
+        .contains(&AccessType::Read);
+
+    let found_groups = match group_repo.search_groups(&jwt.user_id, &GroupSearchFilter {
+        text: query.q.clone(),
+        ancestor: query.ancestor,
+        member: query.member,
+        catalogue: query.catalogue,
+        all_groups,
+    }, &pagination) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    HttpResponse::Ok().json(GroupSearchResponse {
+        groups: found_groups.group_info_list.into_iter().map(|group| {
+            let settings: GroupSettings = serde_json::from_value(group.settings).unwrap_or_default();
+
+            GroupSearchResult {
+                id: group.id,
+                name: group.name,
+                parent: group.parent,
+                description: settings.description,
+                catalogue_visible: settings.catalogue_visible,
+                member: found_groups.member_of.contains(&group.id),
+            }
+        }).collect(),
+        total_count: found_groups.total_count,
+    })
+}
+
 pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
     let group_repo = pgdb.new_group_repo();
     cfg.service(
         web::scope("/groups")
             .app_data(Data::<GroupRepo>::new(group_repo.clone()))
             .service(create_group)
+            .service(search_groups)
             .configure(|cfg| group_id::config(cfg, pgdb.clone()))
     );
 }
\ No newline at end of file
@@ -1,11 +1,9 @@
 // Documentation was created by ChatGPT
 use std::io::{self, Write};
 
-use actix_web::{web::{ServiceConfig, self, Data, Json, Path}, HttpResponse, post, get, delete};
-use jsonschema::{JSONSchema, Draft};
-use serde_json::Value;
+use actix_web::{web::{ServiceConfig, self, Data, Json, Path, Query}, HttpResponse, get, delete, patch};
 
-use crate::{jwt, permission, AppState, models::task::{CreateTaskRequest, CreateTaskResponse, TaskPath, TaskResponse, TaskSolution}};
+use crate::{jwt, permission, AppState, models::{task::{CreateTaskResponse, TaskPath, TaskResponse, TaskSolution, TaskRevisionQuery, UpdateTaskRequest}}, repository::group::GroupRepo, handlers::tasks::tasks::validate_task_document};
 
 // # Delete Task Endpoint
 ///
@@ -45,21 +43,28 @@ pub async fn delete_task(
     tag = "task_doc",
     params(
         ("task_id" = Uuid, Path, description = "The unique identifier of the task to be fetched."),
+        ("revision" = Option<i32>, Query, description = "The revision of the task to be fetched, defaults to the current revision."),
     ),
     responses(
         (status = 200, description = "The request was successful, and task details are provided.", body = TaskResponse),
-        (status = 404, description = "The specified task ID does not exist.")
+        (status = 404, description = "The specified task ID or revision does not exist.")
     ),
 )]
 #[get("/")]
 pub async fn fetch_task(
     path: Path<TaskPath>,
+    query: Query<TaskRevisionQuery>,
     app: Data<AppState>,
     _: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
 
-    let task = match app.mongodb.fetch_task(path.task_id, false).await {
+    let task = match query.revision {
+        Some(revision) => app.mongodb.fetch_task_revision(path.task_id, revision).await,
+        None => app.mongodb.fetch_task(path.task_id, false).await,
+    };
+
+    let task = match task {
         Ok(v) => v,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
@@ -67,12 +72,7 @@ pub async fn fetch_task(
     };
 
     if let Some(task) = task {
-        return HttpResponse::Ok().json(TaskResponse {
-            id: task.id,
-            state: task.state,
-            task: task.task,
-            task_type: task.task_type,
-        });
+        return HttpResponse::Ok().json(TaskResponse::from(task));
     } else {
         return HttpResponse::NotFound().json(
             serde_json::json!({"message": "Task not found"})
@@ -80,6 +80,83 @@ pub async fn fetch_task(
     }
 }
 
+/// # Update Task Endpoint
+///
+/// This endpoint stores a new revision of an existing task. The task and solution are validated against the latest
+/// schema version and by the grader of the task type, like on creation. Earlier revisions are kept, answers stay pinned to the revision
+/// they were given. Templates are checked through a sample instance, like on creation.
+#[utoipa::path(
+    patch,
+    path = "/api/tasks/{task_id}/",
+    tag = "task_doc",
+    request_body = UpdateTaskRequest,
+    params(
+        ("task_id" = Uuid, Path, description = "The unique identifier of the task to be updated."),
+    ),
+    responses(
+        (status = 200, description = "The new revision was stored, and information about the updated task is provided.", body = CreateTaskResponse),
+        (status = 400, description = "The provided task or solution has an incorrect format or is rejected by the grader, listing every validation error.", body = ValidationErrorSchema),
+        (status = 404, description = "The task does not exist or was deleted.", body = ErrorSchema),
+        (status = 409, description = "Another revision of the task was stored in the meantime.", body = ErrorSchema),
+    ),
+)]
+#[patch("/")]
+pub async fn update_task(
+    path: Path<TaskPath>,
+    body: Json<UpdateTaskRequest>,
+    app: Data<AppState>,
+    data: Data<GroupRepo>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let current_task = match app.mongodb.fetch_task(path.task_id, true).await {
+        Ok(Some(task)) => task,
+        Ok(None) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Task not found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let schema_doc = match app.mongodb.fetch_schema(current_task.task_type.clone()).await {
+        Ok(Some(schema_doc)) => schema_doc,
+        Ok(None) => return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Schema could not be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let solution = match validate_task_document(&app, &schema_doc, &body.task, &body.solution, &body.parameters).await {
+        Ok(solution) => solution,
+        Err(response) => return response,
+    };
+
+    let updated_task = match app.mongodb.update_task(&current_task, body.task.clone(), solution, body.parameters.clone(), schema_doc.version, body.metadata.clone()).await {
+        Ok(Some(updated_task)) => updated_task,
+        Ok(None) => return HttpResponse::Conflict().json(
+            serde_json::json!({"message": "Task was changed in the meantime"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    if data.update_task_revision(&updated_task.id, &updated_task.revision).is_err() {
+        // keep both stores at the same revision, the archived revision is written again by the next update
+        let _ = app.mongodb.restore_task_revision(&current_task, updated_task.revision).await;
+        return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+
+    HttpResponse::Ok().json(CreateTaskResponse {
+        task: updated_task
+    })
+}
+
 /// # Fetch Task Solution Endpoint
 ///
 /// This endpoint retrieves the solution details of a specific task based on the provided task ID.
@@ -89,21 +166,28 @@ pub async fn fetch_task(
     tag = "task_doc",
     params(
         ("task_id" = Uuid, Path, description = "The unique identifier of the task for which the solution is to be fetched."),
+        ("revision" = Option<i32>, Query, description = "The revision of the task, defaults to the current revision."),
     ),
     responses(
         (status = 200, description = "The request was successful, and task details are provided.", body = TaskSolution),
-        (status = 404, description = "The task with the specified identifier was not found.")
+        (status = 404, description = "The task with the specified identifier or revision was not found.")
     ),
 )]
 #[get("/solution")]
 pub async fn fetch_task_solution(
     path: Path<TaskPath>,
+    query: Query<TaskRevisionQuery>,
     app: Data<AppState>,
     _: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
 
-    let task = match app.mongodb.fetch_task(path.task_id, false).await {
+    let task = match query.revision {
+        Some(revision) => app.mongodb.fetch_task_revision(path.task_id, revision).await,
+        None => app.mongodb.fetch_task(path.task_id, false).await,
+    };
+
+    let task = match task {
         Ok(v) => v,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
@@ -126,6 +210,7 @@ pub fn config(cfg: &mut ServiceConfig) {
         web::scope("/{task_id}")
             .service(fetch_task)
             .service(delete_task)
+            .service(update_task)
             .service(fetch_task_solution)
     );
 }
\ No newline at end of file
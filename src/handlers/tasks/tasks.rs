@@ -1,23 +1,31 @@
 // Documentation was created by ChatGPT
-use std::io::{self, Write};
+use std::{collections::HashSet, io::{self, Write}};
 
-use actix_web::{web::{ServiceConfig, Data, Query, self, Json}, HttpResponse, get, post};
-use jsonschema::JSONSchema;
-use serde_json::Value;
-
-use crate::{AppState, jwt, permission, models::{task::{TaskPagingSchema, TaskPagingResponse, TaskResponse, CreateSchemaRequest, SchemaPagingSchema, SchemaPagingResponse, CreateTaskRequest, CreateTaskResponse}, util::{PagingSchema, OrderDir}}};
+use actix_web::{web::{ServiceConfig, Data, Query, self, Json, Path}, HttpResponse, get, post};
+use crate::{grading::{grader::GraderRegistry, template::{GeneratorRegistry, SeededRng}}, AppState, jwt, permission, models::{validation::{compile_schema, validate_document, ValidationErrorEntry, ValidationErrorSchema}, task::{TaskPagingSchema, TaskPagingResponse, TaskResponse, TaskFilter, TaskSort, CreateSchemaRequest, SchemaPagingSchema, SchemaPagingResponse, CreateTaskRequest, CreateTaskResponse, SchemaPath, SchemaVersionRequest, SchemaCheckResponse, SchemaCheckTaskEntry, SchemaCheckAnswerEntry}, util::{PagingSchema, OrderDir}}, repository::{group::GroupRepo, postgres::PgRepo, mongodb::SchemaDoc}};
+use mongodb::bson::Document;
 
 use super::task_id::task_id;
 
 /// # Fetch Tasks Endpoint
 ///
 /// This endpoint retrieves a paginated list of tasks based on the provided query parameters.
+/// Tasks can be filtered by their type and metadata and searched by text. The response counts the matching tasks
+/// by task type and metadata, so further filters can be offered.
 #[utoipa::path(
     get,
     path = "/api/tasks/",
     tag = "task_doc",
     params(
         ("task_ids[]" = Option<String>, Query, description = "A list of specific task identifiers to filter the results."),
+        ("task_types[]" = Option<String>, Query, description = "A list of task types to filter the results."),
+        ("tags[]" = Option<String>, Query, description = "A list of tags the tasks all have to be tagged with."),
+        ("topic" = Option<String>, Query, description = "The topic of the tasks."),
+        ("difficulty" = Option<Difficulty>, Query, description = "The difficulty of the tasks."),
+        ("language" = Option<String>, Query, description = "The language the tasks are written in."),
+        ("author" = Option<Uuid>, Query, description = "The user who created the tasks."),
+        ("search" = Option<String>, Query, description = "Words searched for in the questions, options and metadata of the tasks. Solutions are not searched."),
+        ("sort" = Option<TaskSort>, Query, description = "The field the tasks are sorted by, task_type or created_at. Default is task_type."),
         ("page" = Option<i32>, Query, description = "The page number of results if the request is paginated. Default is set to page 0."),
         ("limit" = Option<i32>, Query, description = "The maximum number of tasks to be returned per page."),
         ("order" = Option<OrderDir>, Query, description = "The order of results (ascending or descending). Default is set to descending (DESC)."),
@@ -39,7 +47,7 @@ pub async fn fetch_tasks(
         order: query.order.unwrap_or(OrderDir::DESC),
     };
 
-    let tasks = match app.mongodb.fetch_all_tasks(pagination, query.task_ids.clone(), false).await {
+    let (tasks, match_count, facets) = match app.mongodb.search_tasks(pagination, &TaskFilter::from(&query.0), query.sort.unwrap_or(TaskSort::TaskType)).await {
         Ok(v) => v,
         Err(err) => {
             eprintln!("error: {err}");
@@ -58,13 +66,10 @@ pub async fn fetch_tasks(
     };
 
     HttpResponse::Ok().json(TaskPagingResponse {
-        tasks: tasks.into_iter().map(|task| TaskResponse {
-            id: task.id,
-            task_type: task.task_type,
-            state: task.state,
-            task: task.task,
-        }).collect(),
-        total_count
+        tasks: tasks.into_iter().map(TaskResponse::from).collect(),
+        total_count,
+        match_count,
+        facets,
     })
 }
 
@@ -79,6 +84,7 @@ pub async fn fetch_tasks(
     request_body = CreateSchemaRequest,
     responses(
         (status = 204, description = "The schema was successfully created."),
+        (status = 400, description = "The task or solution schema could not be parsed, listing every error.", body = ValidationErrorSchema),
         (status = 409, description = "A schema for the specified task type already exists."),
     ),
 )]
@@ -100,19 +106,17 @@ pub async fn create_schema(
         )
     };
 
-    match JSONSchema::compile(&serde_json::from_str(&serde_json::to_string(&body.task_schema.clone()).unwrap()).unwrap()) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::BadRequest().json(
-            serde_json::json!({"message": "Task schema could not be parsed"})
+    if let Err(errors) = compile_schema(&body.task_schema) {
+        return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Task schema could not be parsed", errors)
         )
-    };
+    }
 
-    match JSONSchema::compile(&serde_json::from_str(&serde_json::to_string(&body.solution_schema.clone()).unwrap()).unwrap()) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::BadRequest().json(
-            serde_json::json!({"message": "Solution schema could not be parsed"})
+    if let Err(errors) = compile_schema(&body.solution_schema) {
+        return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Solution schema could not be parsed", errors)
         )
-    };
+    }
 
     match app.mongodb.create_schema_doc(body.task_schema.clone(), body.solution_schema.clone(), body.task_type.to_string()).await {
         Ok(_) => HttpResponse::NoContent().finish(),
@@ -122,9 +126,212 @@ pub async fn create_schema(
     }    
 }
 
+/// # Create Schema Version Endpoint
+///
+/// This endpoint stores a new version of the schema of an existing task type.
+/// New tasks are validated against the new version, existing tasks keep the version they were validated against.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/schemas/{task_type}/versions",
+    tag = "task_doc",
+    request_body = SchemaVersionRequest,
+    params(
+        ("task_type" = String, Path, description = "The task type the schema version is created for."),
+    ),
+    responses(
+        (status = 200, description = "The schema version was successfully created.", body = SchemaDoc),
+        (status = 400, description = "The schemas could not be parsed, listing every error, or the task type is deprecated.", body = ValidationErrorSchema),
+        (status = 404, description = "No schema exists for the task type.", body = ErrorSchema),
+    ),
+)]
+#[post("/schemas/{task_type}/versions")]
+pub async fn create_schema_version(
+    path: Path<SchemaPath>,
+    app: Data<AppState>,
+    body: Json<SchemaVersionRequest>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let current_schema = match app.mongodb.fetch_schema(path.task_type.to_string()).await {
+        Ok(Some(schema_doc)) => schema_doc,
+        Ok(None) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Schema could not be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    if current_schema.deprecated {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Schema is deprecated"})
+        )
+    }
+
+    if let Err(errors) = compile_schema(&body.task_schema) {
+        return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Task schema could not be parsed", errors)
+        )
+    }
+
+    if let Err(errors) = compile_schema(&body.solution_schema) {
+        return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Solution schema could not be parsed", errors)
+        )
+    }
+
+    match app.mongodb.create_schema_version(&current_schema, body.task_schema.clone(), body.solution_schema.clone()).await {
+        Ok(schema_doc) => HttpResponse::Ok().json(schema_doc),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+}
+
+/// # Deprecate Schema Endpoint
+///
+/// This endpoint deprecates all versions of the schema of a task type.
+/// No new tasks can be created for a deprecated task type, existing tasks and their answers stay readable.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/schemas/{task_type}/deprecate",
+    tag = "task_doc",
+    params(
+        ("task_type" = String, Path, description = "The task type to deprecate."),
+    ),
+    responses(
+        (status = 204, description = "The task type was successfully deprecated."),
+        (status = 404, description = "No schema exists for the task type.", body = ErrorSchema),
+    ),
+)]
+#[post("/schemas/{task_type}/deprecate")]
+pub async fn deprecate_schema(
+    path: Path<SchemaPath>,
+    app: Data<AppState>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match app.mongodb.deprecate_schema(&path.task_type).await {
+        Ok(0) => HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Schema could not be found"})
+        ),
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    }
+}
+
+/// # Check Schema Endpoint
+///
+/// This endpoint checks a proposed schema of a task type against the existing data before a new version is created.
+/// It lists the tasks whose current revision and the answers whose submitted solution would fail the proposed schema.
+/// Templates are checked through their sample instance, like when they are created. Deleted tasks and answers are not checked.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/schemas/{task_type}/check",
+    tag = "task_doc",
+    request_body = SchemaVersionRequest,
+    params(
+        ("task_type" = String, Path, description = "The task type the proposed schema is checked for."),
+    ),
+    responses(
+        (status = 200, description = "The tasks and answers failing the proposed schema.", body = SchemaCheckResponse),
+        (status = 400, description = "The proposed schemas could not be parsed, listing every error.", body = ValidationErrorSchema),
+        (status = 404, description = "No schema exists for the task type.", body = ErrorSchema),
+    ),
+)]
+#[post("/schemas/{task_type}/check")]
+pub async fn check_schema(
+    path: Path<SchemaPath>,
+    app: Data<AppState>,
+    data: Data<GroupRepo>,
+    body: Json<SchemaVersionRequest>,
+    _: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match app.mongodb.fetch_schema(path.task_type.to_string()).await {
+        Ok(Some(_)) => (),
+        Ok(None) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Schema could not be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let task_schema = match compile_schema(&body.task_schema) {
+        Ok(v) => v,
+        Err(errors) => return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Task schema could not be parsed", errors)
+        )
+    };
+
+    let solution_schema = match compile_schema(&body.solution_schema) {
+        Ok(v) => v,
+        Err(errors) => return HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new("Solution schema could not be parsed", errors)
+        )
+    };
+
+    let tasks = match app.mongodb.fetch_tasks_of_type(&path.task_type).await {
+        Ok(tasks) => tasks,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let answers = match data.fetch_answers_of_task_type(&path.task_type) {
+        Ok(answers) => answers,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let answer_docs = match app.mongodb.fetch_answer_docs(&answers.iter().map(|answer| answer.answer_doc_id).collect::<Vec<_>>()).await {
+        Ok(answer_docs) => answer_docs,
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )
+    };
+
+    let failing_answer_doc_ids: HashSet<_> = answer_docs.into_iter()
+        .filter(|answer_doc| validate_document(&solution_schema, &answer_doc.solution).is_err())
+        .map(|answer_doc| answer_doc.id)
+        .collect();
+
+    HttpResponse::Ok().json(SchemaCheckResponse {
+        tasks: tasks.into_iter().filter_map(|task| {
+            // placeholders of templates may not fit the schema, so templates are checked through the sample instance they are created with
+            let (task_valid, solution_valid) = match app.generators.instantiate(&task, 0, &app.graders) {
+                Ok(instance) => (
+                    validate_document(&task_schema, &instance.task).is_ok(),
+                    validate_document(&solution_schema, &instance.solution).is_ok(),
+                ),
+                Err(_) => (false, false),
+            };
+
+            (!task_valid || !solution_valid).then_some(SchemaCheckTaskEntry {
+                id: task.id,
+                revision: task.revision,
+                task_valid,
+                solution_valid,
+            })
+        }).collect(),
+        answers: answers.into_iter()
+            .filter(|answer| failing_answer_doc_ids.contains(&answer.answer_doc_id))
+            .map(|answer| SchemaCheckAnswerEntry {
+                id: answer.id,
+                task_id: answer.task_id,
+                answer_doc_id: answer.answer_doc_id,
+            }).collect(),
+    })
+}
+
 /// # Fetch Schemas Endpoint
 ///
 /// This endpoint allows fetching a paginated list of schemas based on specified criteria.
+/// Every version of a schema is listed separately, ordered by task type and version.
 #[utoipa::path(
     get,
     path = "/api/tasks/schemas",
@@ -176,6 +383,11 @@ pub async fn fetch_schemas(
 /// # Create Task Endpoint
 ///
 /// This endpoint creates a new task with the provided task type, task, and solution.
+/// The task is validated against the latest schema version of its task type, which must not be deprecated,
+/// and by the grader of its task type. Graders computing the solution from the task, like the one for CYK tasks,
+/// replace the provided solution.
+/// With parameter definitions the task is a template whose task and solution may contain `{{expression}}` placeholders.
+/// Templates are checked through a sample instance, every student gets an own instance when starting a solution attempt.
 #[utoipa::path(
     post,
     path = "/api/tasks/",
@@ -183,7 +395,7 @@ pub async fn fetch_schemas(
     request_body = CreateTaskRequest,
     responses(
         (status = 200, description = "The task was successfully created, and information about the new task is provided.", body = CreateTaskResponse),
-        (status = 400, description = "The provided task or solution has an incorrect format.", body = ErrorSchema),
+        (status = 400, description = "The provided task or solution has an incorrect format or is rejected by the grader, the parameters are invalid, listing every validation error, or the task type is deprecated.", body = ValidationErrorSchema),
         (status = 401, description = "The user is not authorized to create tasks.", body = ErrorSchema),
     ),
 )]
@@ -191,7 +403,7 @@ pub async fn fetch_schemas(
 pub async fn create_task(
     app: Data<AppState>,
     body: Json<CreateTaskRequest>,
-    _: jwt::JwtMiddleware,
+    jwt: jwt::JwtMiddleware,
     _: permission::PermissionMiddleware,
 ) -> HttpResponse {
 
@@ -206,51 +418,18 @@ pub async fn create_task(
         )
     };
 
-    let task_schema = match JSONSchema::compile(&serde_json::from_str(&serde_json::to_string(&schema_doc.task_schema).unwrap()).unwrap()) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::InternalServerError().json(
-            serde_json::json!({"message": "Schema could not be parsed"})
-        )
-    };
-
-    let solution_schema = match JSONSchema::compile(&serde_json::from_str(&serde_json::to_string(&schema_doc.solution_schema).unwrap()).unwrap()) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::InternalServerError().json(
-            serde_json::json!({"message": "Schema could not be parsed"})
-        )
-    };
-
-    let task_string = body.task.to_string();
-    let task_obj: Value = match serde_json::from_str(&task_string) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::InternalServerError().json(
-            serde_json::json!({"message": "task could not be parsed"})
-        )
-    };
-
-    match task_schema.validate(&task_obj) {
-        Ok(_) => (),
-        Err(_) => return HttpResponse::BadRequest().json(
-            serde_json::json!({"message": "task has wrong format"})
+    if schema_doc.deprecated {
+        return HttpResponse::BadRequest().json(
+            serde_json::json!({"message": "Schema is deprecated"})
         )
     }
 
-    let solution_string = body.solution.to_string();
-    let solution_obj: Value = match serde_json::from_str(&solution_string) {
-        Ok(v) => v,
-        Err(_) => return HttpResponse::InternalServerError().json(
-            serde_json::json!({"message": "solution could not be parsed"})
-        )
+    let solution = match validate_task_document(&app, &schema_doc, &body.task, &body.solution, &body.parameters).await {
+        Ok(solution) => solution,
+        Err(response) => return response,
     };
 
-    match solution_schema.validate(&solution_obj) {
-        Ok(_) => (),
-        Err(_) => return HttpResponse::BadRequest().json(
-            serde_json::json!({"message": "solution has wrong format"})
-        )
-    }
-
-    let created_task = match app.mongodb.create_task(body.task_type.to_string(), body.task.clone(), body.solution.clone()).await {
+    let created_task = match app.mongodb.create_task(body.task_type.to_string(), body.task.clone(), solution, body.parameters.clone(), schema_doc.version, body.metadata.clone(), Some(jwt.user_id)).await {
         Ok(created_task) => created_task,
         Err(_) => return HttpResponse::InternalServerError().json(
             serde_json::json!({"message": "Something went wrong"})
@@ -263,12 +442,82 @@ pub async fn create_task(
 }
 
 
-pub fn config(cfg: &mut ServiceConfig) {
+/// Validates a task and its solution before they are stored, for new tasks and new revisions alike.
+///
+/// The task and the solution are checked against the schema of the task type and by its grader. Templates are
+/// checked through a sample instance, as their placeholders may not fit the schema. Graders may run the task,
+/// e.g. the queries of SQL tasks, so the checks run on the blocking thread pool.
+///
+/// Returns the solution to store: the solution computed by the grader, or the solution template for templates.
+/// If the task is invalid, the error response is returned instead.
+pub async fn validate_task_document(app: &AppState, schema_doc: &SchemaDoc, task: &Document, solution: &Document, parameters: &Option<Document>) -> Result<Document, HttpResponse> {
+    let (graders, generators) = (app.graders.clone(), app.generators.clone());
+    let (schema_doc, task, solution, parameters) = (schema_doc.clone(), task.clone(), solution.clone(), parameters.clone());
+
+    match web::block(move || check_task_document(&graders, &generators, &schema_doc, &task, &solution, &parameters)).await {
+        Ok(Ok(solution)) => Ok(solution),
+        Ok(Err(TaskRejection::Invalid(message, errors))) => Err(HttpResponse::BadRequest().json(
+            ValidationErrorSchema::new(message, errors)
+        )),
+        Ok(Err(TaskRejection::SchemaUnparsable)) => Err(HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Schema could not be parsed"})
+        )),
+        Err(_) => Err(HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        )),
+    }
+}
+
+/// Why `check_task_document` rejects a task.
+enum TaskRejection {
+    /// The stored schema of the task type could not be compiled.
+    SchemaUnparsable,
+    /// The task or its solution is invalid, with the message and the errors of the response.
+    Invalid(&'static str, Vec<ValidationErrorEntry>),
+}
+
+fn check_task_document(graders: &GraderRegistry, generators: &GeneratorRegistry, schema_doc: &SchemaDoc, task: &Document, solution: &Document, parameters: &Option<Document>) -> Result<Document, TaskRejection> {
+    let task_schema = compile_schema(&schema_doc.task_schema).map_err(|_| TaskRejection::SchemaUnparsable)?;
+    let solution_schema = compile_schema(&schema_doc.solution_schema).map_err(|_| TaskRejection::SchemaUnparsable)?;
+
+    // placeholders of templates may not fit the schema, so templates are checked through a sample instance
+    let (instance_task, instance_solution) = match parameters {
+        Some(parameters) => generators.get(&schema_doc.task_type).generate(task, solution, parameters, &mut SeededRng::new(0))
+            .map_err(|errors| TaskRejection::Invalid("parameters are invalid", errors))?,
+        None => (task.clone(), solution.clone()),
+    };
+
+    validate_document(&task_schema, &instance_task)
+        .map_err(|errors| TaskRejection::Invalid("task has wrong format", errors))?;
+
+    let grader = graders.get(&schema_doc.task_type);
+
+    grader.validate_task(&instance_task)
+        .map_err(|errors| TaskRejection::Invalid("task is invalid", errors))?;
+
+    let instance_solution = grader.solve(&instance_task).unwrap_or(instance_solution);
+
+    validate_document(&solution_schema, &instance_solution)
+        .map_err(|errors| TaskRejection::Invalid("solution has wrong format", errors))?;
+
+    grader.validate_solution(&instance_task, &instance_solution)
+        .map_err(|errors| TaskRejection::Invalid("solution is invalid", errors))?;
+
+    // templates keep their solution template, the solution of an instance is computed when it is generated
+    Ok(if parameters.is_some() { solution.clone() } else { instance_solution })
+}
+
+pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
+    let group_repo = pgdb.new_group_repo();
     cfg.service(
         web::scope("/tasks")
+            .app_data(Data::<GroupRepo>::new(group_repo.clone()))
             .service(fetch_tasks)
             .service(create_schema)
             .service(fetch_schemas)
+            .service(create_schema_version)
+            .service(deprecate_schema)
+            .service(check_schema)
             .service(create_task)
             .configure(task_id::config)
     );
@@ -1,5 +1,6 @@
 // Documentation was created by ChatGPT
-use actix_web::{web::{self, ServiceConfig, Data, Query}, HttpResponse, HttpRequest, get, HttpMessage};
+use actix_web::{web::{self, ServiceConfig, Data, Query, Path}, HttpResponse, HttpRequest, get, post, HttpMessage};
+use diesel::result::Error;
 use uuid::Uuid;
 
 use crate::{repository::{postgres::PgRepo, 
@@ -7,7 +8,7 @@ use crate::{repository::{postgres::PgRepo,
     permissions::PermissionsRepo}, jwt, 
     models::{groups::{GroupPagingSchema, 
         GroupPagingResponse, GroupInfoResponse, 
-        GroupInfoListWithCount}, util::{PagingSchema, OrderDir, AccessType}}};
+        GroupInfoListWithCount, GroupPath}, members::MemberInfoResponse, util::{PagingSchema, OrderDir, AccessType}}};
 
 /// # Get Groups Endpoint
 ///
@@ -75,6 +76,62 @@ pub async fn get_groups(
     })
 }
 
+/// # Leave Group Endpoint
+///
+/// This endpoint removes the membership of the authenticated user from a group together with the
+/// group scoped permissions of the user. No group permission is needed.
+///
+/// Leaving is refused if the group disabled leaving in its settings (e.g. for mandatory courses) or
+/// if the user is the last one able to manage the members of the group.
+#[utoipa::path(
+    post,
+    path = "/api/user/groups/{group_id}/leave",
+    tag = "group",
+    params(
+        ("group_id" = Uuid, Path, description = "The unique identifier of the group the user leaves."),
+    ),
+    responses(
+        (status = 200, description = "The user left the group, the former membership is returned.", body = MemberInfoResponse),
+        (status = 403, description = "The group does not allow its members to leave.", body = ErrorSchema),
+        (status = 404, description = "The group could not be found or the user is not a member.", body = ErrorSchema),
+        (status = 409, description = "The user is the last one able to manage the group.", body = ErrorSchema),
+    ),
+)]
+#[post("/{group_id}/leave")]
+pub async fn leave_group(
+    path: Path<GroupPath>,
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+) -> HttpResponse {
+    let settings = match group_repo.fetch_group_settings(&path.group_id) {
+        Ok(v) => v,
+        Err(Error::NotFound) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Group couldnt be found"})
+        ),
+        Err(_) => return HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    };
+
+    if settings.leaving_disabled {
+        return HttpResponse::Forbidden().json(
+            serde_json::json!({"message": "Members cannot leave this group"})
+        )
+    }
+
+    match group_repo.leave_group(&path.group_id, &jwt.user_id) {
+        Ok(Some(member)) => HttpResponse::Ok().json(MemberInfoResponse::from(member)),
+        Ok(None) => HttpResponse::Conflict().json(
+            serde_json::json!({"message": "User is the last member able to manage the group"})
+        ),
+        Err(Error::NotFound) => HttpResponse::NotFound().json(
+            serde_json::json!({"message": "User is not a member of the group"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
 
 pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
     let group_repo = pgdb.new_group_repo();
@@ -82,5 +139,6 @@ pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
         web::scope("/groups")
             .app_data(Data::<GroupRepo>::new(group_repo.clone()))
             .service(get_groups)
+            .service(leave_group)
     );
 }
\ No newline at end of file
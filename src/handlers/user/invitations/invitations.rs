@@ -0,0 +1,93 @@
+// Documentation was created by ChatGPT
+use actix_web::{web::{self, ServiceConfig, Data, Json}, HttpResponse, post};
+use diesel::result::{Error, DatabaseErrorKind};
+
+use crate::{repository::{postgres::PgRepo, group::GroupRepo, users::UsersRepo}, jwt, permission,
+    models::{invitations::{RedeemInvitationSchema, RedeemedInvitationResponse}, members::MemberInfoResponse, users::UserResponse}};
+
+/// # Redeem Invitation Endpoint
+///
+/// This endpoint lets the authenticated user join a group with a join code.
+/// The user receives the permissions of the role of the invitation for the group.
+/// Invitations restricted to an email can only be redeemed by the user with that email.
+#[utoipa::path(
+    post,
+    path = "/api/user/invitations/redeem",
+    tag = "invitation",
+    request_body = RedeemInvitationSchema,
+    responses(
+        (status = 201, description = "The user joined the group.", body = RedeemedInvitationResponse),
+        (status = 403, description = "The invitation is restricted to another email.", body = ErrorSchema),
+        (status = 404, description = "No active invitation with this code exists.", body = ErrorSchema),
+        (status = 409, description = "The user is already a member of the group.", body = ErrorSchema),
+        (status = 410, description = "The invitation is expired or used up.", body = ErrorSchema),
+    )
+)]
+#[post("/redeem")]
+pub async fn redeem_invitation(
+    body: Json<RedeemInvitationSchema>,
+    group_repo: Data<GroupRepo>,
+    user_repo: Data<UsersRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    let invitation = match group_repo.fetch_invitation_by_code(&body.code) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::NotFound().json(
+            serde_json::json!({"message": "Invitation couldnt be found"})
+        )
+    };
+
+    if invitation.is_expired() || invitation.is_used_up() {
+        return HttpResponse::Gone().json(
+            serde_json::json!({"message": "Invitation is expired or used up"})
+        )
+    }
+
+    if let Some(email) = &invitation.email {
+        let user = match user_repo.fetch_user_by_id(jwt.user_id) {
+            Ok(v) => v,
+            Err(_) => return HttpResponse::InternalServerError().json(
+                serde_json::json!({"message": "Something went wrong"})
+            )
+        };
+
+        if &user.email.to_lowercase() != email {
+            return HttpResponse::Forbidden().json(
+                serde_json::json!({"message": "Invitation is restricted to another email"})
+            )
+        }
+    }
+
+    match group_repo.redeem_invitation(&invitation, &jwt.user_id) {
+        Ok(member) => HttpResponse::Created().json(RedeemedInvitationResponse {
+            group_id: invitation.group_id,
+            member: MemberInfoResponse {
+                id: member.member_id,
+                user: UserResponse {
+                    id: member.user_id,
+                    name: member.name,
+                    email: member.email,
+                }
+            }
+        }),
+        Err(Error::NotFound) => HttpResponse::Gone().json(
+            serde_json::json!({"message": "Invitation is expired or used up"})
+        ),
+        Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => HttpResponse::Conflict().json(
+            serde_json::json!({"message": "User is already a member of the group"})
+        ),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
+    let group_repo = pgdb.new_group_repo();
+    cfg.service(
+        web::scope("/invitations")
+            .app_data(Data::<GroupRepo>::new(group_repo.clone()))
+            .service(redeem_invitation)
+    );
+}
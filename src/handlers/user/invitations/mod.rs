@@ -0,0 +1 @@
+pub mod invitations;
\ No newline at end of file
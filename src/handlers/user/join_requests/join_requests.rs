@@ -0,0 +1,42 @@
+// Documentation was created by ChatGPT
+use actix_web::{web::{self, ServiceConfig, Data}, HttpResponse, get};
+
+use crate::{repository::{postgres::PgRepo, group::GroupRepo}, jwt, permission,
+    models::join_requests::{JoinRequestResponse, JoinRequestListResponse}};
+
+/// # Get My Join Requests Endpoint
+///
+/// This endpoint lists the join requests of the authenticated user for active groups, newest first,
+/// so the user can see whether a request is still pending, was approved or was rejected.
+#[utoipa::path(
+    get,
+    path = "/api/user/join_requests/",
+    tag = "join_request",
+    responses(
+        (status = 200, description = "Successfully retrieved the join requests of the current user.", body = JoinRequestListResponse),
+    )
+)]
+#[get("/")]
+pub async fn get_my_join_requests(
+    group_repo: Data<GroupRepo>,
+    jwt: jwt::JwtMiddleware,
+    _: permission::PermissionMiddleware,
+) -> HttpResponse {
+    match group_repo.fetch_join_requests_of_user(&jwt.user_id) {
+        Ok(join_requests) => HttpResponse::Ok().json(JoinRequestListResponse {
+            join_requests: join_requests.into_iter().map(JoinRequestResponse::from).collect(),
+        }),
+        Err(_) => HttpResponse::InternalServerError().json(
+            serde_json::json!({"message": "Something went wrong"})
+        ),
+    }
+}
+
+pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
+    let group_repo = pgdb.new_group_repo();
+    cfg.service(
+        web::scope("/join_requests")
+            .app_data(Data::<GroupRepo>::new(group_repo.clone()))
+            .service(get_my_join_requests)
+    );
+}
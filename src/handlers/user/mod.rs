@@ -1,2 +1,4 @@
 pub mod user;
-pub mod groups;
\ No newline at end of file
+pub mod groups;
+pub mod invitations;
+pub mod join_requests;
\ No newline at end of file
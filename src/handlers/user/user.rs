@@ -3,7 +3,7 @@ use actix_web::{web::{ServiceConfig, self, Data, Query}, get, HttpResponse};
 
 use crate::{repository::{postgres::PgRepo, users::UsersRepo, permissions::PermissionsRepo}, models::{users::UserResponse, util::{OrderDir, PagingSchema}, permissions::{PermissionRequest, PermissionListResponse}}, permission, jwt};
 
-use super::groups::groups;
+use super::{groups::groups, invitations::invitations, join_requests::join_requests};
 
 /// # Get Current User Endpoint
 ///
@@ -86,5 +86,7 @@ pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
             .service(me)
             .service(get_my_global_permissions)
             .configure(|cfg| groups::config(cfg, pgdb.clone()))
+            .configure(|cfg| invitations::config(cfg, pgdb.clone()))
+            .configure(|cfg| join_requests::config(cfg, pgdb.clone()))
     );
 }
\ No newline at end of file
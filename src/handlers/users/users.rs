@@ -1,12 +1,14 @@
 use actix_web::web::{ServiceConfig, self, Data};
 
-use crate::repository::{postgres::PgRepo, users::UsersRepo};
+use crate::repository::{postgres::PgRepo, group::GroupRepo};
 
 use super::user_id::user_id;
 
-pub fn config(cfg: &mut ServiceConfig) {
+pub fn config(cfg: &mut ServiceConfig, pgdb: PgRepo) {
+    let group_repo = pgdb.new_group_repo();
     cfg.service(
         web::scope("/users")
+            .app_data(Data::<GroupRepo>::new(group_repo.clone()))
             .configure(user_id::config)
     );
 }
\ No newline at end of file
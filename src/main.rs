@@ -1,18 +1,24 @@
+use std::sync::Arc;
+
 use actix_cors::Cors;
 use actix_web::{
     web::{self, Data},
     App, HttpResponse, HttpServer,
 };
 use config::Config;
+use grading::{grader::GraderRegistry, template::GeneratorRegistry};
+use handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::resume_background_grading;
 use permission_config::PermissionConfig;
 use repository::{postgres::PgRepo, mongodb::MongoDbRepo};
 use serde_json::json;
+use tokio::sync::Semaphore;
 use utoipa_swagger_ui::SwaggerUi;
 
 use utoipa::OpenApi;
 
 mod config;
 mod docs;
+mod grading;
 mod handlers;
 mod jwt;
 mod models;
@@ -31,6 +37,10 @@ pub struct AppState {
     pgdb: PgRepo,
     mongodb: MongoDbRepo,
     permission_config: PermissionConfig,
+    graders: GraderRegistry,
+    generators: GeneratorRegistry,
+    /// Permits of the answers graded in the background at the same time.
+    grading_workers: Arc<Semaphore>,
 }
 
 impl AppState {
@@ -39,11 +49,17 @@ impl AppState {
         let pgdb = repository::postgres::PgRepo::establish_connection(env.database_url.to_string());
         let mongodb: MongoDbRepo = repository::mongodb::MongoDbRepo::establish_connection(&env.mongodb_database_url, &env.mongodb_database_name).await;
         let permission_config = PermissionConfig::new();
+        let graders = GraderRegistry::new(&env);
+        let generators = GeneratorRegistry::new();
+        let grading_workers = Arc::new(Semaphore::new(env.grading_workers.max(1)));
         AppState {
             env,
             pgdb,
             mongodb,
             permission_config,
+            graders,
+            generators,
+            grading_workers,
         }
     }
 }
@@ -62,6 +78,10 @@ async fn main() -> std::io::Result<()> {
         seeder::seeder::fill_seeder().await;
     }
 
+    app_state.mongodb.create_indexes().await.expect("Error while creating the Mongodb indexes");
+
+    actix_web::rt::spawn(resume_background_grading(Data::new(app_state.pgdb.new_group_repo()), Data::new(app_state.clone())));
+
     HttpServer::new(move || {
         App::new()
             .app_data(Data::new(app_state.clone()))
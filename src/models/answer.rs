@@ -5,6 +5,8 @@ use serde::{Serialize, Deserialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::grading::grader::GradeFeedback;
+
 use super::util::AnswerState;
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -13,6 +15,8 @@ pub struct AnswerDocIdWithString {
     pub id: String,
     pub solution: Document,
     pub updated_at: BDateTime,
+    #[serde(default)]
+    pub feedback: Vec<GradeFeedback>,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -20,6 +24,8 @@ pub struct AnswerDoc {
     pub id: Uuid,
     pub solution: Document,
     pub updated_at: NaiveDateTime,
+    /// The feedback of the grader, empty until the answer is graded.
+    pub feedback: Vec<GradeFeedback>,
 }
 
 #[derive(ToSchema, Deserialize, Debug)]
@@ -35,6 +41,9 @@ pub struct NewAnswer<'a> {
     pub task_id: &'a Uuid,
     pub state: &'a AnswerState,
     pub created_from: &'a Uuid,
+    pub task_revision: &'a i32,
+    pub seed: Option<&'a i64>,
+    pub permutation: Option<&'a Vec<i32>>,
 }
 
 #[derive(ToSchema, Debug, Selectable, Deserialize, Serialize, Queryable, Clone)]
@@ -82,9 +91,13 @@ pub struct AnswerResponse {
     pub state: AnswerState,
     pub task_id: Uuid,
     pub correct: bool,
+    pub score: f64,
+    pub max_score: f64,
     pub created_from: Uuid,
+    pub task_revision: i32,
     #[schema(value_type = String)]
     pub updated_at: Option<DateTime<Utc>>,
+    pub feedback: Vec<GradeFeedback>,
 }
 
 #[derive(ToSchema, Debug, Selectable, Deserialize, Serialize, Queryable, Clone)]
@@ -98,6 +111,13 @@ pub struct Answer {
     pub task_id: Uuid,
     pub state: AnswerState,
     pub created_from: Uuid,
+    pub task_revision: i32,
+    pub score: f64,
+    pub max_score: f64,
+    /// The seed the instance of the task is generated with if the task is a template.
+    pub seed: Option<i64>,
+    /// The order the options of the task are shown in if they are shuffled, listing the original index of every shown option.
+    pub permutation: Option<Vec<i32>>,
 }
 
 #[derive(ToSchema, Serialize, Debug, Deserialize)]
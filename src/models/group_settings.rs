@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+
+use super::util::Visibility;
+
+/// Settings of a group, stored as JSON document next to the group.
+///
+/// Missing fields take their default value, so documents written by older versions stay readable.
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct GroupSettings {
+    /// Description of the group, shown e.g. in the course catalogue.
+    pub description: Option<String>,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub term_start: Option<NaiveDate>,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub term_end: Option<NaiveDate>,
+    /// Whether the group is listed in the course catalogue.
+    pub catalogue_visible: bool,
+    /// Visibility of solution attempts created without an explicit visibility.
+    pub default_visibility: Option<Visibility>,
+    /// Role whose permissions new members get. The default member role is used if not set.
+    pub default_member_role: Option<String>,
+    /// Whether members may see the other members of the group. Members are visible by default.
+    pub members_visible: bool,
+    /// Whether members cannot leave the group on their own, e.g. for mandatory courses.
+    pub leaving_disabled: bool,
+}
+
+impl Default for GroupSettings {
+    fn default() -> Self {
+        GroupSettings {
+            description: None,
+            term_start: None,
+            term_end: None,
+            catalogue_visible: false,
+            default_visibility: None,
+            default_member_role: None,
+            members_visible: true,
+            leaving_disabled: false,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{NaiveDateTime, DateTime, Utc};
 use diesel::{Insertable, Selectable, Queryable, AsChangeset};
 use utoipa::ToSchema;
@@ -5,9 +7,9 @@ use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
 use super::permissions::OptionalUserAccessType;
-use super::util::{PagingSchema, OrderDir};
+use super::util::{PagingSchema, OrderDir, JoinPolicy};
 
-use super::util::deserialize_option_vec_uuid;
+use super::util::{deserialize_option_vec_uuid, deserialize_double_option};
 
 #[derive(ToSchema, Deserialize, Debug)]
 pub struct CreateGroupSchema {
@@ -15,6 +17,41 @@ pub struct CreateGroupSchema {
     pub parent: Option<Uuid>,
 }
 
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct UpdateGroupSchema {
+    pub name: Option<String>,
+    /// `null` moves the group to the top level, a missing field keeps the current parent.
+    #[serde(default, deserialize_with = "deserialize_double_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub parent: Option<Option<Uuid>>,
+    /// Decides whether users can join the group on their own, have to request to join or cannot join at all.
+    pub join_policy: Option<JoinPolicy>,
+}
+
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct CloneGroupSchema {
+    /// Name of the copy. Default is the name of the group.
+    pub name: Option<String>,
+    /// `null` creates the copy on the top level, a missing field places it next to the group.
+    #[serde(default, deserialize_with = "deserialize_double_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub parent: Option<Option<Uuid>>,
+    /// Whether the active subgroups are copied as well. Default is false.
+    pub include_subgroups: Option<bool>,
+    /// Whether the active task packages and their tasks are copied. Default is true.
+    pub include_task_packages: Option<bool>,
+}
+
+/// Id mapping of a cloned group, every map goes from the id of the original to the id of the copy.
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct CloneGroupResponse {
+    /// Id of the copy of the group.
+    pub id: Uuid,
+    pub groups: HashMap<Uuid, Uuid>,
+    pub task_packages: HashMap<Uuid, Uuid>,
+    pub tasks: HashMap<Uuid, Uuid>,
+}
+
 #[derive(ToSchema, Serialize, Deserialize, Debug)]
 pub struct CreateGroupResponse {
     pub id: Uuid,
@@ -33,6 +70,7 @@ pub struct GroupMetaDataResponse {
     pub created_at: DateTime<Utc>,
     #[schema(value_type = String)]
     pub updated_at: DateTime<Utc>,
+    pub join_policy: JoinPolicy,
 }
 
 #[derive(ToSchema, Serialize, Debug, Deserialize, PartialEq, Eq, Hash)]
@@ -42,6 +80,46 @@ pub struct GroupInfoResponse {
     pub parent: Option<Uuid>,
 }
 
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct GroupTreeQuery {
+    pub depth: Option<i32>,
+    pub with_counts: Option<bool>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct GroupTreeResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub parent: Option<Uuid>,
+    pub depth: i32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub member_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub task_package_count: Option<i64>,
+    pub children: Vec<GroupTreeResponse>,
+}
+
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct GroupDescendantsQuery {
+    pub depth: Option<i32>,
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct GroupDescendantResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub parent: Option<Uuid>,
+    pub depth: i32,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct GroupDescendantsResponse {
+    pub groups: Vec<GroupDescendantResponse>,
+    pub total_count: i64,
+}
+
 #[derive(ToSchema, Deserialize, Debug)]
 pub struct GroupAddMemberSchema {
     pub new_members: Vec<String>
@@ -85,6 +163,41 @@ pub struct NewGroup {
     pub parent: Option<Uuid>,
 }
 
+pub struct CloneGroup {
+    pub name: String,
+    pub parent: Option<Uuid>,
+    pub include_subgroups: bool,
+    pub include_task_packages: bool,
+    pub created_from: Uuid,
+}
+
+pub struct ClonedGroup {
+    pub id: Uuid,
+    pub groups: HashMap<Uuid, Uuid>,
+    pub task_packages: HashMap<Uuid, Uuid>,
+    pub tasks: HashMap<Uuid, Uuid>,
+}
+
+/// The parts of a group that are copied when the group is cloned.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = crate::schema::groups)]
+pub struct GroupTemplate {
+    pub id: Uuid,
+    pub name: String,
+    pub parent: Option<Uuid>,
+    pub join_policy: JoinPolicy,
+    pub settings: serde_json::Value,
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = crate::schema::groups)]
+pub struct UpdateGroup {
+    pub name: Option<String>,
+    pub parent: Option<Option<Uuid>>,
+    pub join_policy: Option<JoinPolicy>,
+    pub updated_from: Uuid,
+}
+
 #[derive(Insertable, AsChangeset, Clone)]
 #[diesel(table_name = crate::schema::user_permissions)]
 pub struct NewUserPermission {
@@ -134,6 +247,7 @@ pub struct GroupMetaData {
     pub updated_from: Uuid,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub join_policy: JoinPolicy,
 }
 
 #[derive(ToSchema, Debug, Selectable, Queryable, Clone)]
@@ -145,6 +259,15 @@ pub struct GroupInfo {
     pub parent: Option<Uuid>,
 }
 
+#[derive(Debug, Selectable, Queryable, Clone)]
+#[diesel(table_name = crate::schema::groups)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct DeletedGroupInfo {
+    pub parent: Option<Uuid>,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub deleted_with: Option<Uuid>,
+}
+
 pub struct GroupInfoListWithCount {
     pub group_info_list: Vec<GroupInfo>,
     pub total_count: i64,
@@ -165,6 +288,66 @@ pub struct GroupPagingResponse {
     pub total_count: i64,
 }
 
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct GroupSearchSchema {
+    /// Search text. Every word has to occur in the name or the description of a group,
+    /// also as prefix or part of a longer word.
+    pub q: Option<String>,
+    /// Only groups below this group.
+    pub ancestor: Option<Uuid>,
+    /// `true` only returns groups the user is a member of, `false` only groups the user is not a member of.
+    pub member: Option<bool>,
+    /// `true` only returns groups listed in the course catalogue, `false` only unlisted groups.
+    pub catalogue: Option<bool>,
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+}
+
+pub struct GroupSearchFilter {
+    pub text: Option<String>,
+    pub ancestor: Option<Uuid>,
+    pub member: Option<bool>,
+    pub catalogue: Option<bool>,
+    /// Whether the user may read all groups. Otherwise only groups the user can read
+    /// and groups listed in the course catalogue are found.
+    pub all_groups: bool,
+}
+
+#[derive(Debug, Selectable, Queryable, Clone)]
+#[diesel(table_name = crate::schema::groups)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GroupSearchInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub parent: Option<Uuid>,
+    pub settings: serde_json::Value,
+}
+
+pub struct GroupSearchListWithCount {
+    pub group_info_list: Vec<GroupSearchInfo>,
+    /// The groups of `group_info_list` the user is a member of.
+    pub member_of: HashSet<Uuid>,
+    pub total_count: i64,
+}
+
+#[derive(ToSchema, Serialize, Debug, Deserialize)]
+pub struct GroupSearchResult {
+    pub id: Uuid,
+    pub name: String,
+    pub parent: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    pub catalogue_visible: bool,
+    pub member: bool,
+}
+
+#[derive(ToSchema, Serialize, Debug, Deserialize)]
+pub struct GroupSearchResponse {
+    /// Groups ordered by relevance, groups whose name starts with the search text come first.
+    pub groups: Vec<GroupSearchResult>,
+    pub total_count: i64,
+}
+
 #[derive(Deserialize)]
 pub struct GroupPath {
     pub group_id: Uuid,
@@ -0,0 +1,108 @@
+use chrono::{NaiveDateTime, DateTime, Utc};
+use diesel::{Selectable, prelude::{Queryable, Insertable}};
+use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{members::MemberInfoResponse, util::State};
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct CreateInvitationSchema {
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub max_uses: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct RedeemInvitationSchema {
+    pub code: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct InvitationPath {
+    pub group_id: Uuid,
+    pub invitation_id: Uuid,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::group_invitations)]
+pub struct NewGroupInvitation {
+    pub group_id: Uuid,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub max_uses: Option<i32>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_from: Uuid,
+}
+
+#[derive(Debug, Selectable, Queryable, Clone)]
+#[diesel(table_name = crate::schema::group_invitations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GroupInvitation {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    pub code: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+    pub expires_at: Option<NaiveDateTime>,
+    pub state: State,
+    pub created_at: NaiveDateTime,
+    pub created_from: Uuid,
+}
+
+impl GroupInvitation {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= Utc::now().naive_utc())
+    }
+
+    pub fn is_used_up(&self) -> bool {
+        self.max_uses.is_some_and(|max_uses| self.uses >= max_uses)
+    }
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    pub code: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub state: State,
+    pub created_at: DateTime<Utc>,
+    pub created_from: Uuid,
+}
+
+impl From<GroupInvitation> for InvitationResponse {
+    fn from(invitation: GroupInvitation) -> Self {
+        InvitationResponse {
+            id: invitation.id,
+            group_id: invitation.group_id,
+            code: invitation.code,
+            email: invitation.email,
+            role: invitation.role,
+            max_uses: invitation.max_uses,
+            uses: invitation.uses,
+            expires_at: invitation.expires_at.map(|expires_at| expires_at.and_utc()),
+            state: invitation.state,
+            created_at: invitation.created_at.and_utc(),
+            created_from: invitation.created_from,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct InvitationListResponse {
+    pub invitations: Vec<InvitationResponse>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct RedeemedInvitationResponse {
+    pub group_id: Uuid,
+    pub member: MemberInfoResponse,
+}
@@ -0,0 +1,84 @@
+use chrono::{NaiveDateTime, DateTime, Utc};
+use diesel::{Selectable, prelude::{Queryable, Insertable}};
+use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{users::{UserInfo, UserResponse}, util::JoinRequestState};
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct CreateJoinRequestSchema {
+    pub message: Option<String>,
+}
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct JoinRequestQuery {
+    pub state: Option<JoinRequestState>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct JoinRequestPath {
+    pub group_id: Uuid,
+    pub join_request_id: Uuid,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::group_join_requests)]
+pub struct NewGroupJoinRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    pub message: Option<String>,
+    pub state: JoinRequestState,
+    pub decided_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Selectable, Queryable, Clone)]
+#[diesel(table_name = crate::schema::group_join_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct JoinRequestInfo {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    #[diesel(embed)]
+    pub user: UserInfo,
+    pub message: Option<String>,
+    pub state: JoinRequestState,
+    pub decided_at: Option<NaiveDateTime>,
+    pub decided_from: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct JoinRequestResponse {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    pub user: UserResponse,
+    pub message: Option<String>,
+    pub state: JoinRequestState,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub decided_from: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JoinRequestInfo> for JoinRequestResponse {
+    fn from(join_request: JoinRequestInfo) -> Self {
+        JoinRequestResponse {
+            id: join_request.id,
+            group_id: join_request.group_id,
+            user: UserResponse {
+                id: join_request.user.id,
+                name: join_request.user.name,
+                email: join_request.user.email,
+            },
+            message: join_request.message,
+            state: join_request.state,
+            decided_at: join_request.decided_at.map(|decided_at| decided_at.and_utc()),
+            decided_from: join_request.decided_from,
+            created_at: join_request.created_at.and_utc(),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct JoinRequestListResponse {
+    pub join_requests: Vec<JoinRequestResponse>,
+}
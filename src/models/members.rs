@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
 use diesel::prelude::Queryable;
 use utoipa::ToSchema;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use super::{users::UserResponse, util::OrderDir};
+use super::{users::UserResponse, util::{OrderDir, AccessType}};
 
 use super::util::deserialize_option_vec_uuid;
 
@@ -20,6 +23,50 @@ pub struct MembersPagingSchema {
     pub page: Option<i32>,
     pub limit: Option<i32>,
     pub order: Option<OrderDir>,
+    /// Only members who joined with this role.
+    pub role: Option<String>,
+    /// Only members with a permission for this ressource in the group.
+    pub ressource: Option<String>,
+    /// Together with `ressource`, only members granted this access type for the ressource.
+    pub access_type: Option<AccessType>,
+    pub sort: Option<MemberSort>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberSort {
+    /// Sorts by user name, members with the same name are sorted by email.
+    Name,
+    #[default]
+    JoinedAt,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemberFilter {
+    pub member_ids: Option<Vec<Uuid>>,
+    pub role: Option<String>,
+    pub ressource: Option<String>,
+    pub access_type: Option<AccessType>,
+    pub sort: MemberSort,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct MemberPermissionResponse {
+    pub ressource: String,
+    pub access_types: Vec<AccessType>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct GroupMemberResponse {
+    pub id: Uuid,
+    pub user: UserResponse,
+    #[schema(value_type = String)]
+    pub joined_at: DateTime<Utc>,
+    /// The role the member joined the group with. Missing for members who joined before roles were recorded.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub role: Option<String>,
+    /// The granted group scoped permissions of the member.
+    pub permissions: Vec<MemberPermissionResponse>,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug)]
@@ -27,9 +74,55 @@ pub struct MemberListResponse {
     pub members: Vec<MemberInfoResponse>,
 }
 
+/// How a batch of member changes reacts to failing entries.
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    /// Failing entries are reported, all other entries are applied.
+    Continue,
+    /// If one entry fails, no entry is applied.
+    Abort,
+}
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct MemberBatchQuery {
+    pub on_error: Option<OnError>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MemberEntryStatus {
+    Added,
+    Removed,
+    Failed,
+    /// The entry is valid, but was not applied because the batch was aborted.
+    Skipped,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct MemberEntryResult {
+    /// The entry of the request, an email when adding and a member id when removing members.
+    pub entry: String,
+    pub status: MemberEntryStatus,
+    /// One of `unknown_email`, `inactive_user`, `already_member`, `member_not_found`,
+    /// `duplicate_entry` or `internal_error` for failed entries.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub member: Option<MemberInfoResponse>,
+}
+
 #[derive(ToSchema, Serialize, Deserialize, Debug)]
-pub struct MemberListWithCountResponse {
+pub struct MemberBatchResponse {
+    /// The added or removed members.
     pub members: Vec<MemberInfoResponse>,
+    /// The result of every entry, in the order of the request.
+    pub results: Vec<MemberEntryResult>,
+    pub failed_count: usize,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct MemberListWithCountResponse {
+    pub members: Vec<GroupMemberResponse>,
     pub total_count: i64,
 }
 
@@ -41,7 +134,32 @@ pub struct MemberInfo {
     pub email: String,
 }
 
-pub struct MemberListWithCount {
-    pub member_list: Vec<MemberInfo>,
+impl From<MemberInfo> for MemberInfoResponse {
+    fn from(member: MemberInfo) -> Self {
+        MemberInfoResponse {
+            id: member.member_id,
+            user: UserResponse {
+                id: member.user_id,
+                name: member.name,
+                email: member.email,
+            }
+        }
+    }
+}
+
+#[derive(Queryable)]
+pub struct GroupMemberInfo {
+    pub member_id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub joined_at: NaiveDateTime,
+    pub role: Option<String>,
+}
+
+pub struct GroupMemberListWithCount {
+    pub member_list: Vec<GroupMemberInfo>,
+    /// The granted group scoped permissions per user id, ordered by ressource.
+    pub permissions: HashMap<Uuid, Vec<(String, AccessType)>>,
     pub total_count: i64,
 }
\ No newline at end of file
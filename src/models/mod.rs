@@ -9,4 +9,9 @@ pub mod task;
 pub mod answer;
 pub mod solution_attempts;
 pub mod task_package;
-pub mod roles;
\ No newline at end of file
+pub mod roles;
+pub mod invitations;
+pub mod join_requests;
+pub mod roster;
+pub mod group_settings;
+pub mod validation;
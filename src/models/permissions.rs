@@ -118,6 +118,23 @@ pub struct NewUserAccessType {
     pub user_permission_id: Uuid,
 }
 
+/// A group scoped grant that was revoked because its group got deleted.
+/// It is kept so the grant can be handed out again when the group is restored.
+#[derive(Debug, Selectable, Queryable, Insertable, Clone)]
+#[diesel(table_name = crate::schema::revoked_user_permissions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RevokedUserPermission {
+    pub user_permission_id: Uuid,
+    pub access_type: AccessType,
+    pub user_id: Uuid,
+    pub ressource: String,
+    pub group_id: Uuid,
+    pub permission: bool,
+    pub set_permission: bool,
+    pub set_set_permission: bool,
+    pub deleted_with: Uuid,
+}
+
 #[derive(ToSchema, Debug, Clone, Insertable, AsChangeset)]
 #[diesel(table_name = crate::schema::user_access_types)]
 pub struct UpdateUserAccessType {
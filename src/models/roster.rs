@@ -0,0 +1,79 @@
+use actix_multipart::form::{MultipartForm, bytes::Bytes, text::Text};
+use diesel::deserialize::Queryable;
+use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(MultipartForm, ToSchema)]
+pub struct ImportMembersForm {
+    /// CSV file with a header row. Columns: `email` (required), `name` and `role` (optional).
+    #[schema(value_type = String, format = Binary)]
+    pub file: Bytes,
+    /// Only report what the import would do. Default is false.
+    #[schema(value_type = Option<bool>)]
+    pub dry_run: Option<Text<bool>>,
+    /// How rows without an existing account are handled. Default is `Skip`.
+    #[schema(value_type = Option<MissingUserStrategy>)]
+    pub missing_users: Option<Text<MissingUserStrategy>>,
+}
+
+/// What happens to rows whose email does not belong to an existing user.
+#[derive(ToSchema, Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum MissingUserStrategy {
+    /// The row fails with the reason `unknown_user`.
+    Skip,
+    /// An invitation restricted to the email is created, the user joins the group when registering.
+    Invite,
+    /// An account with a generated password is created. The password is part of the report.
+    Password,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RosterRow {
+    pub email: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ImportRowStatus {
+    Added,
+    Created,
+    Invited,
+    AlreadyMember,
+    Failed,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct ImportRowResult {
+    /// Line of the row in the CSV file, the header is line 1.
+    pub row: usize,
+    pub email: String,
+    pub status: ImportRowStatus,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub user_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub invitation_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub password: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct ImportMembersResponse {
+    pub dry_run: bool,
+    pub failed_count: usize,
+    pub rows: Vec<ImportRowResult>,
+}
+
+/// A row of an exported roster, in the columns of the import.
+#[derive(Queryable, Debug, Clone)]
+pub struct RosterMember {
+    pub email: String,
+    pub name: String,
+    /// The role the member joined with, `None` for members who joined before roles were recorded.
+    pub role: Option<String>,
+}
@@ -35,6 +35,8 @@ pub struct CreatedSolutionAttempt {
     pub task_package_id: Uuid,
     pub visibility: Visibility,
     pub created_at: NaiveDateTime,
+    pub score: f64,
+    pub max_score: f64,
 }
 
 #[derive(Serialize, Debug, Deserialize)]
@@ -54,6 +56,8 @@ pub struct CreatedSolutionAttemptResponse {
     #[schema(value_type = String)]
     pub created_at: DateTime<Utc>,
     pub state: AnswerState,
+    pub score: f64,
+    pub max_score: f64,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug)]
@@ -66,6 +70,8 @@ pub struct SolutionAttemptWithAnswerListResponse {
     #[schema(value_type = String)]
     pub created_at: DateTime<Utc>,
     pub state: AnswerState,
+    pub score: f64,
+    pub max_score: f64,
 }
 
 #[derive(ToSchema, Serialize, Debug, Deserialize, PartialEq)]
@@ -75,6 +81,11 @@ pub struct AnswerEntry {
     pub task_id: Uuid,
     pub task_doc_id: Uuid,
     pub task_type: String,
+    pub task_revision: i32,
+    /// The seed the instance of the task is generated with if the task is a template.
+    pub seed: Option<i64>,
+    /// The order the options of the task are shown in if they are shuffled, listing the original index of every shown option.
+    pub permutation: Option<Vec<i32>>,
 }
 
 #[derive(ToSchema, Serialize, Debug)]
@@ -86,6 +97,8 @@ pub struct SolutionAttemptResponse {
     #[schema(value_type = String)]
     pub created_at: DateTime<Utc>,
     pub state: AnswerState,
+    pub score: f64,
+    pub max_score: f64,
 }
 
 #[derive(ToSchema, Serialize, Debug)]
@@ -1,7 +1,8 @@
+use chrono::NaiveDateTime;
 use diesel::deserialize::Queryable;
 use diesel::prelude::Insertable;
 use diesel::Selectable;
-use mongodb::bson::Document;
+use mongodb::bson::{Document, DateTime as BDateTime};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -12,12 +13,52 @@ use super::util::deserialize_option_vec_string;
 use super::util::deserialize_option_vec_uuid;
 use super::util::OrderDir;
 use super::util::State;
+use super::util::state_from_str;
 
 #[derive(ToSchema, Deserialize, Debug, Clone)]
 pub struct CreateTaskRequest {
     pub task_type: String,
     pub task: Document,
+    /// Can be left out for task types whose grader computes the solution from the task.
+    #[serde(default)]
     pub solution: Document,
+    /// Parameter definitions by name, turning the task into a template whose placeholders like `{{a + 1}}` in the
+    /// task and solution are filled in per answer.
+    pub parameters: Option<Document>,
+    #[serde(default)]
+    pub metadata: TaskMetadata,
+}
+
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct UpdateTaskRequest {
+    pub task: Document,
+    /// Can be left out for task types whose grader computes the solution from the task.
+    #[serde(default)]
+    pub solution: Document,
+    pub parameters: Option<Document>,
+    /// Replaces the metadata of the task, which is kept if left out.
+    pub metadata: Option<TaskMetadata>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// Describes a task for searching the task bank. It belongs to the task rather than to a revision.
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TaskMetadata {
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub topic: Option<String>,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    /// The language the task is written in, e.g. `de`.
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 #[derive(ToSchema, Deserialize, Debug, Clone)]
@@ -27,6 +68,33 @@ pub struct CreateSchemaRequest {
     pub solution_schema: Document,
 }
 
+#[derive(ToSchema, Deserialize, Debug, Clone)]
+pub struct SchemaVersionRequest {
+    pub task_schema: Document,
+    pub solution_schema: Document,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaCheckTaskEntry {
+    pub id: Uuid,
+    pub revision: i32,
+    pub task_valid: bool,
+    pub solution_valid: bool,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaCheckAnswerEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub answer_doc_id: Uuid,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
+pub struct SchemaCheckResponse {
+    pub tasks: Vec<SchemaCheckTaskEntry>,
+    pub answers: Vec<SchemaCheckAnswerEntry>,
+}
+
 #[derive(ToSchema, Deserialize, Serialize, Debug, Clone)]
 pub struct CreateTaskResponse {
     pub task: TaskDoc,
@@ -40,6 +108,38 @@ pub struct TaskDocIdString {
     pub task: Document,
     pub state: String,
     pub solution: Document,
+    #[serde(default = "first_version")]
+    pub revision: i32,
+    #[serde(default = "first_version")]
+    pub schema_version: i32,
+    #[serde(default)]
+    pub parameters: Option<Document>,
+    #[serde(default)]
+    pub metadata: TaskMetadata,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Missing for tasks stored before the creation date was recorded.
+    #[serde(default)]
+    pub created_at: Option<BDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TaskRevisionDocIdString {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub task_id: String,
+    pub revision: i32,
+    pub task: Document,
+    pub solution: Document,
+    #[serde(default = "first_version")]
+    pub schema_version: i32,
+    #[serde(default)]
+    pub parameters: Option<Document>,
+}
+
+/// Documents stored before revisions and schema versions were introduced are at their first version.
+pub fn first_version() -> i32 {
+    1
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -49,6 +149,49 @@ pub struct TaskDoc {
     pub task: Document,
     pub state: State,
     pub solution: Document,
+    pub revision: i32,
+    pub schema_version: i32,
+    /// The parameter definitions if the task is a template.
+    pub parameters: Option<Document>,
+    pub metadata: TaskMetadata,
+    /// The user who created the task.
+    pub author: Option<Uuid>,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+impl From<TaskDocIdString> for TaskDoc {
+    fn from(task: TaskDocIdString) -> Self {
+        TaskDoc {
+            id: Uuid::parse_str(&task.id).unwrap(),
+            task_type: task.task_type,
+            task: task.task,
+            state: state_from_str(&task.state),
+            solution: task.solution,
+            revision: task.revision,
+            schema_version: task.schema_version,
+            parameters: task.parameters,
+            metadata: task.metadata,
+            author: task.author.and_then(|author| Uuid::parse_str(&author).ok()),
+            created_at: task.created_at.and_then(|created_at| NaiveDateTime::from_timestamp_millis(created_at.timestamp_millis())),
+        }
+    }
+}
+
+impl From<TaskDoc> for TaskResponse {
+    fn from(task: TaskDoc) -> Self {
+        TaskResponse {
+            id: task.id,
+            task_type: task.task_type,
+            task: task.task,
+            state: task.state,
+            revision: task.revision,
+            schema_version: task.schema_version,
+            parameters: task.parameters,
+            metadata: task.metadata,
+            author: task.author,
+            created_at: task.created_at,
+        }
+    }
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -57,6 +200,12 @@ pub struct TaskResponse {
     pub task_type: String,
     pub task: Document,
     pub state: State,
+    pub revision: i32,
+    pub schema_version: i32,
+    pub parameters: Option<Document>,
+    pub metadata: TaskMetadata,
+    pub author: Option<Uuid>,
+    pub created_at: Option<NaiveDateTime>,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -68,7 +217,48 @@ pub struct TaskSolution {
 #[derive(ToSchema, Serialize, Debug, Deserialize)]
 pub struct TaskPagingResponse {
     pub tasks: Vec<TaskResponse>,
+    /// The amount of all tasks, regardless of the filters.
     pub total_count: u64,
+    /// The amount of tasks matching the filters.
+    pub match_count: u64,
+    pub facets: TaskFacets,
+}
+
+#[derive(ToSchema, Serialize, Debug, Deserialize, Clone, PartialEq)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: u64,
+}
+
+/// The amount of tasks matching the filters by value of their task type and metadata, most frequent first.
+#[derive(ToSchema, Serialize, Debug, Deserialize, Clone, Default)]
+pub struct TaskFacets {
+    pub task_types: Vec<FacetCount>,
+    pub tags: Vec<FacetCount>,
+    pub topics: Vec<FacetCount>,
+    pub difficulties: Vec<FacetCount>,
+    pub languages: Vec<FacetCount>,
+}
+
+#[derive(ToSchema, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSort {
+    TaskType,
+    CreatedAt,
+}
+
+/// Filters of the task bank. Tasks have to match every given filter and carry all given `tags`.
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    pub task_ids: Option<Vec<Uuid>>,
+    pub task_types: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub topic: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub language: Option<String>,
+    pub author: Option<Uuid>,
+    /// Words searched for in the questions, options and metadata of the tasks, but not in their solutions.
+    pub search: Option<String>,
 }
 
 #[derive(ToSchema, Serialize, Debug, Deserialize)]
@@ -90,11 +280,44 @@ pub struct TaskPagingSchema {
         deserialize_with = "deserialize_option_vec_uuid"
     )]
     pub task_ids: Option<Vec<Uuid>>,
+    #[serde(
+        alias = "task_types[]",
+        default,
+        deserialize_with = "deserialize_option_vec_string"
+    )]
+    pub task_types: Option<Vec<String>>,
+    #[serde(
+        alias = "tags[]",
+        default,
+        deserialize_with = "deserialize_option_vec_string"
+    )]
+    pub tags: Option<Vec<String>>,
+    pub topic: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub language: Option<String>,
+    pub author: Option<Uuid>,
+    pub search: Option<String>,
+    pub sort: Option<TaskSort>,
     pub page: Option<i32>,
     pub limit: Option<i32>,
     pub order: Option<OrderDir>,
 }
 
+impl From<&TaskPagingSchema> for TaskFilter {
+    fn from(query: &TaskPagingSchema) -> Self {
+        TaskFilter {
+            task_ids: query.task_ids.clone(),
+            task_types: query.task_types.clone(),
+            tags: query.tags.clone(),
+            topic: query.topic.clone(),
+            difficulty: query.difficulty,
+            language: query.language.clone(),
+            author: query.author,
+            search: query.search.clone(),
+        }
+    }
+}
+
 #[derive(ToSchema, Deserialize, Debug, Clone)]
 pub struct SchemaPagingSchema {
     #[serde(
@@ -113,6 +336,16 @@ pub struct TaskPath {
     pub task_id: Uuid,
 }
 
+#[derive(ToSchema, Deserialize)]
+pub struct SchemaPath {
+    pub task_type: String,
+}
+
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct TaskRevisionQuery {
+    pub revision: Option<i32>,
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = crate::schema::tasks)]
 pub struct NewTask<'a> {
@@ -144,6 +377,8 @@ pub struct Task {
     pub task_package_id: Uuid,
     pub task_doc_id: Uuid,
     pub task_type: String,
+    pub task_revision: i32,
+    pub points: f64,
 }
 
 #[derive(ToSchema, Deserialize, Debug)]
@@ -151,6 +386,18 @@ pub struct AddTasksToPackageSchema {
     pub task_doc_ids: Vec<Uuid>,
 }
 
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct UpdateTaskPointsSchema {
+    pub tasks: Vec<TaskPoints>,
+}
+
+#[derive(ToSchema, Deserialize, Debug)]
+pub struct TaskPoints {
+    pub task_doc_id: Uuid,
+    /// The points answers to the task are weighted with, `1` by default.
+    pub points: f64,
+}
+
 #[derive(ToSchema, Deserialize, Debug)]
 pub struct RemoveTasksFromPackageSchema {
     pub task_ids: Vec<Uuid>,
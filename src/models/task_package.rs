@@ -88,4 +88,6 @@ pub struct TaskPackageUserStatisticValue {
     pub solution_attempt_id: Uuid,
     pub date: DateTime<Utc>,
     pub amount_correct: usize, 
+    pub score: f64,
+    pub max_score: f64,
 }
\ No newline at end of file
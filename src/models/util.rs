@@ -48,12 +48,29 @@ pub enum State {
     Active,
 }
 
+#[derive(ToSchema, diesel_derive_enum::DbEnum, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[ExistingTypePath = "crate::schema::sql_types::JoinPolicy"]
+pub enum JoinPolicy {
+    Closed,
+    Request,
+    Open,
+}
+
+#[derive(ToSchema, diesel_derive_enum::DbEnum, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[ExistingTypePath = "crate::schema::sql_types::JoinRequestState"]
+pub enum JoinRequestState {
+    Pending,
+    Approved,
+    Rejected,
+}
+
 #[derive(ToSchema, diesel_derive_enum::DbEnum, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[ExistingTypePath = "crate::schema::sql_types::AnswerState"]
 pub enum AnswerState {
     Deleted,
     Active,
     Done,
+    Grading,
 }
 
 pub fn solution_state_from_str(state_from_string: &str) -> AnswerState {
@@ -61,6 +78,7 @@ pub fn solution_state_from_str(state_from_string: &str) -> AnswerState {
         "ACTIVE"    => AnswerState::Active,
         "DELETED"   => AnswerState::Deleted,
         "DONE"      => AnswerState::Done,
+        "GRADING"   => AnswerState::Grading,
         _           => AnswerState::Deleted,
     } 
 }
@@ -71,6 +89,7 @@ impl ToString for AnswerState {
             AnswerState::Active   => String::from("ACTIVE"),
             AnswerState::Deleted  => String::from("DELETED"),
             AnswerState::Done     => String::from("DONE"),
+            AnswerState::Grading  => String::from("GRADING"),
         }
     }
 }
@@ -185,6 +204,16 @@ where
     Ok(Some(values))
 }
 
+/// Distinguishes a missing field (`None`) from an explicit `null` (`Some(None)`).
+/// Has to be combined with `#[serde(default)]`.
+pub fn deserialize_double_option<'de, T, D>(de: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(de).map(Some)
+}
+
 fn sort_json_object(obj: &Value) -> Value {
     match obj {
         Value::Object(map) => {
@@ -0,0 +1,110 @@
+use jsonschema::{JSONSchema, ValidationError};
+use mongodb::bson::Document;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// The draft used for schemas without a known `$schema`, same as the default of `jsonschema`.
+const DEFAULT_DRAFT_URL: &str = "http://json-schema.org/draft-07/schema#";
+
+const DRAFT_URLS: [&str; 3] = [
+    "http://json-schema.org/draft-04/schema#",
+    "http://json-schema.org/draft-06/schema#",
+    DEFAULT_DRAFT_URL,
+];
+
+/// `ErrorSchema` extended with every validation error of a document or schema.
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+pub struct ValidationErrorSchema {
+    pub message: String,
+    pub errors: Vec<ValidationErrorEntry>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidationErrorEntry {
+    /// JSON pointer to the failing value, e.g. `/answers/0`. Empty for the document itself.
+    pub instance_path: String,
+    /// JSON pointer to the failing keyword in the schema, e.g. `/properties/answers/items/type`.
+    pub schema_path: String,
+    /// The failing keyword, e.g. `required` or `type`.
+    pub keyword: String,
+    pub message: String,
+}
+
+impl From<ValidationError<'_>> for ValidationErrorEntry {
+    fn from(error: ValidationError<'_>) -> Self {
+        ValidationErrorEntry {
+            instance_path: error.instance_path.to_string(),
+            schema_path: error.schema_path.to_string(),
+            keyword: error.schema_path.clone().into_vec().pop().unwrap_or_default(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl ValidationErrorSchema {
+    pub fn new(message: &str, errors: Vec<ValidationErrorEntry>) -> Self {
+        ValidationErrorSchema {
+            message: message.to_string(),
+            errors,
+        }
+    }
+}
+
+fn to_value(document: &Document) -> Result<Value, Vec<ValidationErrorEntry>> {
+    serde_json::to_value(document).map_err(|err| vec![ValidationErrorEntry {
+        instance_path: String::new(),
+        schema_path: String::new(),
+        keyword: String::new(),
+        message: err.to_string(),
+    }])
+}
+
+/// Validates `schema` against the meta schema of its draft to collect all errors, as compiling it only reports the first one.
+fn meta_schema_errors(schema: &Value) -> Vec<ValidationErrorEntry> {
+    let draft_url = schema.get("$schema")
+        .and_then(Value::as_str)
+        .filter(|url| DRAFT_URLS.contains(url))
+        .unwrap_or(DEFAULT_DRAFT_URL);
+
+    let meta_schema = match JSONSchema::options().with_meta_schemas().compile(&serde_json::json!({ "$ref": draft_url })) {
+        Ok(meta_schema) => meta_schema,
+        Err(_) => return vec![],
+    };
+
+    meta_schema.validate(schema)
+        .err()
+        .map(|errors| errors.map(ValidationErrorEntry::from).collect())
+        .unwrap_or_default()
+}
+
+/// Compiles a JSON schema stored as document.
+///
+/// # Errors
+///
+/// Returns every error of the schema against the meta schema of its draft, or the compilation error if the schema
+/// is valid against its meta schema but still cannot be compiled, e.g. because of an unresolvable `$ref`.
+pub fn compile_schema(schema: &Document) -> Result<JSONSchema, Vec<ValidationErrorEntry>> {
+    let schema = to_value(schema)?;
+
+    match JSONSchema::compile(&schema) {
+        Ok(compiled_schema) => Ok(compiled_schema),
+        Err(err) => {
+            let compile_error = ValidationErrorEntry::from(err);
+            let errors = meta_schema_errors(&schema);
+
+            Err(if errors.is_empty() { vec![compile_error] } else { errors })
+        }
+    }
+}
+
+/// Validates a document against a compiled JSON schema.
+///
+/// # Errors
+///
+/// Returns every validation error of the document.
+pub fn validate_document(schema: &JSONSchema, document: &Document) -> Result<(), Vec<ValidationErrorEntry>> {
+    let document = to_value(document)?;
+
+    schema.validate(&document).map_err(|errors| errors.map(ValidationErrorEntry::from).collect())
+}
@@ -3,22 +3,43 @@ use std::collections::{HashSet, HashMap};
 use chrono::{NaiveDate, NaiveDateTime};
 // Documentation was created by ChatGPT
 use diesel::{
-    dsl::{self, count_star}, prelude::*, result::Error, sql_types::{Bool, BigInt, Timestamptz}, upsert::excluded,
+    dsl::{self, count_star}, prelude::*, result::Error, sql_types::{Bool, BigInt, Text, Timestamptz}, upsert::excluded,
 };
 
 use itertools::Itertools;
+use rand_core::{OsRng, RngCore};
 use uuid::Uuid;
 
 use crate::{models::{
         groups::{
-            CreateGroup, GroupInfo, GroupInfoListWithCount, GroupMetaData, NewGroup,
-            NewGroupMember, NewUserPermission,
+            CreateGroup, DeletedGroupInfo, GroupInfo, GroupInfoListWithCount, GroupMetaData, NewGroup, GroupSearchFilter, GroupSearchInfo, GroupSearchListWithCount,
+            NewGroupMember, NewUserPermission, UpdateGroup, CloneGroup, ClonedGroup, GroupTemplate,
         },
-        members::{MemberInfo, MemberListWithCount},
-        util::{PagingSchema, State, AnswerState, TaskPackageType, Visibility, AccessType}, task::{NewTask, Task, NewTempTask}, answer::{NewAnswer, CreatedAnswer, Answer}, task_package::{NewTaskPackage, CreatedTaskPackage, TaskPackage, TaskPackageUserStatisticValue}, solution_attempts::{CreatedSolutionAttempt, NewSolutionAttempt, SolutionAttempt, AnswerEntry}, permissions::{RoleAccesType, NewUserAccessType, OptionalUserAccessType, UpdateUserAccessType}, roles::{NewRole, UpdateRolePermission},
+        group_settings::GroupSettings,
+        invitations::{GroupInvitation, NewGroupInvitation},
+        join_requests::{JoinRequestInfo, NewGroupJoinRequest},
+        members::{MemberInfo, MemberFilter, MemberSort, GroupMemberInfo, GroupMemberListWithCount},
+        roster::RosterMember,
+        users::NewUser,
+        util::{PagingSchema, OrderDir, State, AnswerState, TaskPackageType, Visibility, AccessType, JoinRequestState}, task::{NewTask, Task, NewTempTask, TaskPoints}, answer::{NewAnswer, CreatedAnswer, Answer}, task_package::{NewTaskPackage, CreatedTaskPackage, TaskPackage, TaskPackageUserStatisticValue}, solution_attempts::{CreatedSolutionAttempt, NewSolutionAttempt, SolutionAttempt, AnswerEntry}, permissions::{RevokedUserPermission, RoleAccesType, NewUserAccessType, OptionalUserAccessType, UpdateUserAccessType}, roles::{NewRole, UpdateRolePermission},
     }, repository::group};
 
-use super::postgres::DBPool;
+use super::{postgres::DBPool, users::{UsersRepo, CREATED_USER_ROLE_KEY}};
+
+sql_function! {
+    /// Trigram similarity between the first argument and the most similar part of the second argument.
+    fn word_similarity(needle: Text, haystack: Text) -> Float;
+}
+
+/// The members of a group joined with their users.
+type MemberQuerySource = diesel::helper_types::InnerJoinQuerySource<
+    crate::schema::group_members::table,
+    crate::schema::users::table,
+    dsl::Eq<crate::schema::group_members::user_id, crate::schema::users::id>,
+>;
+
+/// The columns of an answer and its task an `AnswerEntry` is built from.
+type AnswerEntryRow = (Uuid, Uuid, Uuid, Uuid, String, i32, Option<i64>, Option<Vec<i32>>);
 
 #[derive(Clone)]
 pub struct GroupRepo {
@@ -28,6 +49,9 @@ pub struct GroupRepo {
 pub const CREATED_GROUP_ROLE_KEY: &str = "created_group";
 pub const ADD_MEMBER_ROLE_KEY: &str = "add_member";
 pub const READ_GROUP_INFO: &str = "group";
+pub const GROUP_MEMBER_INFO: &str = "group_member";
+pub const GROUP_RESTORE_RETENTION_DAYS: i64 = 30;
+pub const INVITATION_CODE_LENGTH: usize = 10;
 
 impl GroupRepo {
     pub fn new(pool: DBPool) -> Self {
@@ -456,6 +480,7 @@ impl GroupRepo {
                 groups::updated_from,
                 groups::created_at,
                 groups::updated_at,
+                groups::join_policy,
             ))
             .filter(
                 groups::id
@@ -494,8 +519,8 @@ impl GroupRepo {
     /// # Errors
     ///
     /// This function may return an error if there is an issue with 
-    /// the database connection or if the specified
-    /// member or group does not exist.
+    /// the database connection. If the specified member does not
+    /// belong to the group, `Error::NotFound` is returned.
     ///
     /// # Example
     ///
@@ -523,48 +548,93 @@ impl GroupRepo {
         remove_member_id: &Uuid,
         group_id: &Uuid,
     ) -> Result<MemberInfo, Error> {
-        use crate::schema::group_members;
-        use crate::schema::users;
-        use crate::schema::user_permissions;
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| Self::delete_group_member(conn, remove_member_id, group_id))
+    }
 
+    /// Removes several members from a group in a single transaction.
+    ///
+    /// Either all members are removed or none of them: if one of the members does not belong to
+    /// the group, the transaction is rolled back.
+    ///
+    /// # Arguments
+    ///
+    /// * `remove_member_ids` - The unique identifiers of the group members to be removed.
+    /// * `group_id` - The unique identifier of the group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `MemberInfo` of every removed member, in the order of `remove_member_ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if one of the members does not belong to the group, or an error
+    /// if there is an issue with the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let removed_members = group_repo.remove_users_from_group(&vec![member_id], &group_id)?;
+    /// ```
+    pub fn remove_users_from_group(
+        &self,
+        remove_member_ids: &[Uuid],
+        group_id: &Uuid,
+    ) -> Result<Vec<MemberInfo>, Error> {
         let conn = &mut self.pool.get().unwrap();
 
         conn.transaction(|conn| {
-            let member: MemberInfo = group_members::table
-                .inner_join(users::table)
-                .select((group_members::id, users::id, users::name, users::email))
-                .filter(
-                    group_members::id
-                        .eq(remove_member_id)
-                        .and(group_members::group_id.eq(group_id)),
-                )
-                .first(conn)
-                .unwrap();
+            remove_member_ids.iter()
+                .map(|remove_member_id| Self::delete_group_member(conn, remove_member_id, group_id))
+                .collect()
+        })
+    }
 
-            diesel::delete(group_members::table)
-                .filter(
-                    group_members::id
-                        .eq(remove_member_id)
-                        .and(group_members::group_id.eq(group_id)),
-                )
-                .execute(conn)?;
+    /// Deletes the membership and the group permissions of the member on an existing connection.
+    fn delete_group_member(
+        conn: &mut PgConnection,
+        remove_member_id: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<MemberInfo, Error> {
+        use crate::schema::group_members;
+        use crate::schema::users;
+        use crate::schema::user_permissions;
 
-            diesel::delete(user_permissions::table)
-                .filter(
-                    user_permissions::group_id
-                        .eq(group_id)
-                        .and(user_permissions::user_id.eq(&member.user_id)),
-                )
-                .execute(conn)?;
+        let member: MemberInfo = group_members::table
+            .inner_join(users::table)
+            .select((group_members::id, users::id, users::name, users::email))
+            .filter(
+                group_members::id
+                    .eq(remove_member_id)
+                    .and(group_members::group_id.eq(group_id)),
+            )
+            .first(conn)?;
 
-            Ok(member)
-        })
+        diesel::delete(group_members::table)
+            .filter(
+                group_members::id
+                    .eq(remove_member_id)
+                    .and(group_members::group_id.eq(group_id)),
+            )
+            .execute(conn)?;
+
+        diesel::delete(user_permissions::table)
+            .filter(
+                user_permissions::group_id
+                    .eq(group_id)
+                    .and(user_permissions::user_id.eq(&member.user_id)),
+            )
+            .execute(conn)?;
+
+        Ok(member)
     }
 
     /// Adds a new user to a group, granting them specified permissions.
     ///
     /// This function adds a new user to a group, along with 
-    /// granting the user specified permissions for the group.
+    /// granting the user the permissions of the default member
+    /// role of the group (see `fetch_default_member_role`).
     /// The function performs a transaction that includes adding 
     /// the user to the group members, retrieving the created
     /// member information, and assigning the user relevant 
@@ -616,575 +686,2440 @@ impl GroupRepo {
     ///
     /// In this example, the function is used to add a new user to a group and print the created member information.
     pub fn add_user_to_group(&self, new_member: &NewGroupMember) -> Result<MemberInfo, Error> {
-        use crate::schema::group_members;
-        use crate::schema::users;
-        use crate::schema::user_permissions;
-        use crate::schema::role_access_types;
-        use crate::schema::user_access_types;
-
-        let conn = &mut self.pool.get().unwrap();
-
-        let role_permissions = match self.fetch_role_permissions(ADD_MEMBER_ROLE_KEY) {
-            Ok(v) => v,
-            Err(err) => return Err(err),
-        };
-
-        conn.transaction(|conn| {
-            diesel::insert_into(group_members::table)
-                .values(new_member)
-                .execute(conn)?;
-
-            let created_member: MemberInfo = match group_members::table
-                .inner_join(users::table.on(users::id.eq(group_members::user_id)))
-                .select((group_members::id, users::id, users::name, users::email))
-                .filter(
-                    group_members::user_id
-                        .eq(new_member.user_id)
-                        .and(group_members::group_id.eq(new_member.group_id)),
-                )
-                .first(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
-
-            for role_permission in role_permissions {
-
-                let access_types: Vec<RoleAccesType> = role_access_types::table
-                    .select((
-                        role_access_types::access_type,
-                        role_access_types::set_permission,
-                        role_access_types::set_set_permission,
-                        role_access_types::permission,
-                    )).filter(
-                        role_access_types::role_permission_id.eq(role_permission.0)
-                    ).load(conn).unwrap();
-
-                let user_permission_id: Uuid = diesel::insert_into(user_permissions::table)
-                    .values((
-                        user_permissions::user_id.eq(&new_member.user_id),
-                        user_permissions::ressource.eq(&role_permission.1),
-                        user_permissions::group_id.eq(&new_member.group_id),
-                    ))
-                    .on_conflict_do_nothing()
-                    .returning(user_permissions::id)
-                    .get_result(conn).unwrap();
-
-                diesel::insert_into(user_access_types::table)
-                    .values(access_types.into_iter().map(|user_access_type| NewUserAccessType {
-                        access_type: user_access_type.access_type,
-                        permission: Some(user_access_type.permission),
-                        set_permission: Some(user_access_type.set_permission),
-                        set_set_permission: Some(user_access_type.set_set_permission),
-                        user_permission_id,
-                    }).collect::<Vec<NewUserAccessType>>()
-                ).on_conflict((
-                    user_access_types::access_type,
-                    user_access_types::user_permission_id,
-                )).do_update()
-                .set((
-                    user_access_types::access_type.eq(excluded(user_access_types::access_type)),
-                    user_access_types::user_permission_id.eq(excluded(user_access_types::user_permission_id))
-                )).execute(conn)?;
-            };
+        let role_key = self.fetch_default_member_role(new_member.group_id)?;
 
-            Ok(created_member)
-        })
+        self.add_user_to_group_with_role(new_member, &role_key)
     }
 
-    /// Fetches a paginated list of groups where a user has 
-    /// specific rights, optionally filtered by group IDs.
+    /// Adds a user to a group and grants the permissions of the given role for the group.
     ///
-    /// This function retrieves a paginated list of groups for 
-    /// which a specified user has specific rights,
-    /// providing both a subset of group information and the total 
-    /// count of eligible groups. Additionally,
-    /// you can provide a list of specific group IDs to further 
-    /// filter the results.
+    /// Works like `add_user_to_group`, but uses the permissions of `role_key` instead of the
+    /// default member role of the group.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The unique identifier of the user for whom 
-    /// groups with specific rights are to be fetched.
-    /// * `pagination` - A reference to a `PagingSchema` struct that 
-    /// defines the pagination parameters,
-    ///                 such as page number and limit.
-    /// * `group_ids` - An optional vector of group IDs to filter the 
-    /// fetched groups. If `None`, all eligible
-    ///                groups will be retrieved.
+    /// * `new_member` - The user and group of the new membership.
+    /// * `role_key` - The `value_key` of the role whose permissions are granted.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `GroupInfoListWithCount` struct, 
-    /// which includes:
-    ///
-    /// 1. `Vec<(Uuid, String)>` - A paginated list of tuples, where 
-    /// each tuple represents a group. The tuple
-    ///    includes the group's unique ID and name.
-    /// 2. `usize` - The total count of groups for which the user has 
-    /// specific rights.
+    /// A `Result` containing the `MemberInfo` of the created membership.
     ///
     /// # Errors
     ///
-    /// This function may return an error if there is an issue with the 
-    /// database connection.
+    /// This function may return an error if there is an issue with the database connection or if the user is
+    /// already a member of the group.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use diesel::prelude::*;
-    ///
-    /// let db_conn = PgRepo::establish_connection("database_url");
-    /// let group_repo = db_conn.new_group_repo();
-    /// let user_id = Uuid::new_v4();
-    /// let pagination = PagingSchema {
-    ///     page: 1,
-    ///     limit: 10,
-    /// };
-    /// let group_ids = Some(vec![Uuid::new_v4(), Uuid::new_v4()]);
-    ///
-    /// match group_repo.fetch_groups_where_user_has_rights(&user_id, &pagination, &group_ids) {
-    ///     Ok(group_info_list_with_count) => {
-    ///         let GroupInfoListWithCount { group_info_list, total_count } = group_info_list_with_count;
-    ///         for (group_id, group_name) in group_info_list {
-    ///             println!("Group ID: {}, Group Name: {}", group_id, group_name);
-    ///         }
-    ///         println!("Total Groups with Rights: {}", total_count);
-    ///     },
-    ///     Err(err) => eprintln!("Error: {}", err),
-    /// }
+    /// let member = group_repo.add_user_to_group_with_role(&new_member, "tutor")?;
     /// ```
-    ///
-    /// In this example, the function is used to fetch a paginated list of groups for which a user has specific
-    /// rights, optionally filtered by specific group IDs, and the retrieved information is printed.
-    pub fn fetch_groups_where_user_has_rights(
-        &self,
-        user_id: &Uuid,
-        pagination: &PagingSchema,
-        group_ids: &Option<Vec<Uuid>>,
-    ) -> Result<GroupInfoListWithCount, Error> {
-        use crate::schema::groups;
-        use crate::schema::ressources;
-        use crate::schema::user_permissions;
-        use crate::schema::user_access_types;
-
+    pub fn add_user_to_group_with_role(&self, new_member: &NewGroupMember, role_key: &str) -> Result<MemberInfo, Error> {
         let conn = &mut self.pool.get().unwrap();
 
-        let offset = pagination.page * pagination.limit;
-
-        let mut filter_query: Box<dyn BoxableExpression<_, diesel::pg::Pg, SqlType = Bool>> =
-            Box::new(
-                user_permissions::user_id
-                    .eq(user_id)
-                    .and(ressources::key_value.eq(READ_GROUP_INFO))
-                    .and(user_access_types::access_type.eq(AccessType::Read))
-                    .and(user_access_types::permission.eq(true))
-                    .and(groups::state.eq(State::Active)),
-            );
-
-        conn.transaction(|conn| {
-            let total_count = match groups::table
-                .inner_join(
-                    user_permissions::table
-                    .inner_join(ressources::table)
-                    .inner_join(user_access_types::table)
-                )
-                .select(count_star())
-                .filter(&filter_query)
-                .first(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
-
-            if group_ids.is_some() {
-                filter_query =
-                    Box::new(filter_query.and(groups::id.eq_any(group_ids.as_ref().unwrap())));
-            }
-
-            let group_info_list = match groups::table
-                .inner_join(
-                    user_permissions::table
-                    .inner_join(ressources::table)
-                    .inner_join(user_access_types::table)
-                )
-                .select((groups::id, groups::name, groups::parent))
-                .limit(pagination.limit.into())
-                .offset(offset.into())
-                .filter(filter_query)
-                .load(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+        let role_permissions = self.fetch_role_permissions(role_key)?;
 
-            Ok(GroupInfoListWithCount {
-                group_info_list,
-                total_count,
-            })
-        })
+        conn.transaction(|conn| Self::insert_group_member(conn, new_member, role_key, role_permissions))
     }
 
-    /// Fetches a paginated list of active groups along with the 
-    /// total count, optionally filtered by group IDs.
+    /// Creates a user and adds them to a group in a single transaction.
     ///
-    /// This function retrieves a paginated list of active groups, 
-    /// providing both a subset of group information
-    /// and the total count of active groups. Additionally, you can 
-    /// provide a list of specific group IDs to
-    /// further filter the results.
+    /// The user gets the permissions of the `created_user` role and the permissions of `role_key`
+    /// for the group. If the membership cannot be created, the user is not created either.
     ///
     /// # Arguments
     ///
-    /// * `pagination` - A reference to a `PagingSchema` struct that 
-    /// defines the pagination parameters,
-    ///                 such as page number and limit.
-    /// * `group_ids` - An optional vector of group IDs to filter the 
-    /// fetched groups. If `None`, all active
-    ///                groups will be retrieved.
+    /// * `new_user` - The name, email and hashed password of the new user.
+    /// * `group_id` - The unique identifier of the group.
+    /// * `role_key` - The `value_key` of the role whose permissions are granted for the group.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `GroupInfoListWithCount` struct, which 
-    /// includes:
-    ///
-    /// 1. `Vec<(Uuid, String)>` - A paginated list of tuples, where each 
-    /// tuple represents a group. The tuple
-    ///    includes the group's unique ID and name.
-    /// 2. `usize` - The total count of active groups.
+    /// A `Result` containing the `MemberInfo` of the created membership.
     ///
     /// # Errors
     ///
-    /// This function may return an error if there is an issue with 
-    /// the database connection.
+    /// This function may return an error if there is an issue with the database connection or if a
+    /// user with the email already exists.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use diesel::prelude::*;
-    ///
-    /// let db_conn = PgRepo::establish_connection("database_url");
+    /// let member = group_repo.create_user_as_member(&new_user, &group_id, "add_member")?;
+    /// ```
+    pub fn create_user_as_member(&self, new_user: &NewUser, group_id: &Uuid, role_key: &str) -> Result<MemberInfo, Error> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let user_permissions = self.fetch_role_permissions(CREATED_USER_ROLE_KEY)?;
+        let role_permissions = self.fetch_role_permissions(role_key)?;
+
+        conn.transaction(|conn| {
+            let user = UsersRepo::insert_user(conn, new_user, user_permissions)?;
+
+            Self::insert_group_member(conn, &NewGroupMember {
+                group_id,
+                user_id: &user.id,
+            }, role_key, role_permissions)
+        })
+    }
+
+    /// Adds several users to a group in a single transaction and grants them the permissions of the
+    /// default member role of the group.
+    ///
+    /// Either all users are added or none of them: if one insert fails, e.g. because the user is
+    /// already a member or not active, the transaction is rolled back.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `user_ids` - The unique identifiers of the users to be added.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `MemberInfo` of every created membership, in the order of `user_ids`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection or if one
+    /// of the users cannot be added to the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let added_members = group_repo.add_users_to_group(&group_id, &vec![user_id])?;
+    /// ```
+    pub fn add_users_to_group(&self, group_id: &Uuid, user_ids: &[Uuid]) -> Result<Vec<MemberInfo>, Error> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let role_key = self.fetch_default_member_role(group_id)?;
+        let role_permissions = self.fetch_role_permissions(&role_key)?;
+
+        conn.transaction(|conn| {
+            user_ids.iter()
+                .map(|user_id| Self::insert_group_member(conn, &NewGroupMember {
+                    user_id,
+                    group_id,
+                }, &role_key, role_permissions.clone()))
+                .collect()
+        })
+    }
+
+    /// Inserts the membership with its role and the role permissions on an existing connection,
+    /// so callers can combine it with other statements in one transaction.
+    fn insert_group_member(
+        conn: &mut PgConnection,
+        new_member: &NewGroupMember,
+        role_key: &str,
+        role_permissions: Vec<(Uuid, String)>,
+    ) -> Result<MemberInfo, Error> {
+        use crate::schema::group_members;
+        use crate::schema::users;
+
+        diesel::insert_into(group_members::table)
+            .values((new_member, group_members::role.eq(role_key)))
+            .execute(conn)?;
+
+        let created_member: MemberInfo = group_members::table
+            .inner_join(users::table.on(users::id.eq(group_members::user_id)))
+            .select((group_members::id, users::id, users::name, users::email))
+            .filter(
+                group_members::user_id
+                    .eq(new_member.user_id)
+                    .and(group_members::group_id.eq(new_member.group_id)),
+            )
+            .first(conn)?;
+
+        Self::grant_role_permissions(conn, new_member.user_id, new_member.group_id, &role_permissions)?;
+
+        Ok(created_member)
+    }
+
+    /// Grants a user the access types of the given role permissions for a group on an existing connection.
+    fn grant_role_permissions(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        group_id: &Uuid,
+        role_permissions: &[(Uuid, String)],
+    ) -> Result<(), Error> {
+        use crate::schema::user_permissions;
+        use crate::schema::role_access_types;
+        use crate::schema::user_access_types;
+
+        for role_permission in role_permissions {
+
+            let access_types: Vec<RoleAccesType> = role_access_types::table
+                .select((
+                    role_access_types::access_type,
+                    role_access_types::set_permission,
+                    role_access_types::set_set_permission,
+                    role_access_types::permission,
+                )).filter(
+                    role_access_types::role_permission_id.eq(role_permission.0)
+                ).load(conn)?;
+
+            let inserted_permission_id: Option<Uuid> = diesel::insert_into(user_permissions::table)
+                .values((
+                    user_permissions::user_id.eq(user_id),
+                    user_permissions::ressource.eq(&role_permission.1),
+                    user_permissions::group_id.eq(group_id),
+                ))
+                .on_conflict_do_nothing()
+                .returning(user_permissions::id)
+                .get_result(conn)
+                .optional()?;
+
+            // the user may already hold the group permission, e.g. from an earlier membership
+            let user_permission_id = match inserted_permission_id {
+                Some(user_permission_id) => user_permission_id,
+                None => user_permissions::table
+                    .select(user_permissions::id)
+                    .filter(
+                        user_permissions::user_id.eq(user_id)
+                            .and(user_permissions::ressource.eq(&role_permission.1))
+                            .and(user_permissions::group_id.eq(group_id)),
+                    )
+                    .first(conn)?,
+            };
+
+            diesel::insert_into(user_access_types::table)
+                .values(access_types.into_iter().map(|user_access_type| NewUserAccessType {
+                    access_type: user_access_type.access_type,
+                    permission: Some(user_access_type.permission),
+                    set_permission: Some(user_access_type.set_permission),
+                    set_set_permission: Some(user_access_type.set_set_permission),
+                    user_permission_id,
+                }).collect::<Vec<NewUserAccessType>>()
+            ).on_conflict((
+                user_access_types::access_type,
+                user_access_types::user_permission_id,
+            )).do_update()
+            .set((
+                user_access_types::access_type.eq(excluded(user_access_types::access_type)),
+                user_access_types::user_permission_id.eq(excluded(user_access_types::user_permission_id))
+            )).execute(conn)?;
+        };
+
+        Ok(())
+    }
+
+    /// Creates a new invitation for a group with a freshly generated join code.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_invitation` - The group and the restrictions of the invitation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the created `GroupInvitation`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection or
+    /// if the role of the invitation does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let invitation = group_repo.create_invitation(&NewGroupInvitation {
+    ///     group_id,
+    ///     email: None,
+    ///     role: None,
+    ///     max_uses: Some(30),
+    ///     expires_at: None,
+    ///     created_from: user_id,
+    /// })?;
+    /// println!("Join with code {}", invitation.code);
+    /// ```
+    pub fn create_invitation(&self, new_invitation: &NewGroupInvitation) -> Result<GroupInvitation, Error> {
+        use crate::schema::group_invitations;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        diesel::insert_into(group_invitations::table)
+            .values((
+                new_invitation,
+                group_invitations::code.eq(generate_invitation_code()),
+            ))
+            .returning(GroupInvitation::as_returning())
+            .get_result(conn)
+    }
+
+    /// Fetches all invitations of a group, including revoked ones, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `GroupInvitation`s of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let invitations = group_repo.fetch_invitations(&group_id)?;
+    /// ```
+    pub fn fetch_invitations(&self, group_id: &Uuid) -> Result<Vec<GroupInvitation>, Error> {
+        use crate::schema::group_invitations;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_invitations::table
+            .select(GroupInvitation::as_select())
+            .filter(group_invitations::group_id.eq(group_id))
+            .order(group_invitations::created_at.desc())
+            .load(conn)
+    }
+
+    /// Revokes an active invitation of a group, so it can no longer be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group the invitation belongs to.
+    /// * `invitation_id` - The unique identifier of the invitation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the revoked `GroupInvitation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the group has no active invitation with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// group_repo.revoke_invitation(&group_id, &invitation_id)?;
+    /// ```
+    pub fn revoke_invitation(&self, group_id: &Uuid, invitation_id: &Uuid) -> Result<GroupInvitation, Error> {
+        use crate::schema::group_invitations;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        diesel::update(group_invitations::table)
+            .set((
+                group_invitations::state.eq(State::Deleted),
+                group_invitations::updated_at.eq(dsl::now),
+            ))
+            .filter(
+                group_invitations::id.eq(invitation_id)
+                .and(group_invitations::group_id.eq(group_id))
+                .and(group_invitations::state.eq(State::Active))
+            )
+            .returning(GroupInvitation::as_returning())
+            .get_result(conn)
+    }
+
+    /// Fetches an active invitation of an active group by its join code.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The join code of the invitation, compared case insensitive.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `GroupInvitation`. Expired or used up invitations are returned as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no such invitation or it was revoked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let invitation = group_repo.fetch_invitation_by_code("K7M2QX9PTA")?;
+    /// ```
+    pub fn fetch_invitation_by_code(&self, code: &str) -> Result<GroupInvitation, Error> {
+        use crate::schema::group_invitations;
+        use crate::schema::groups;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_invitations::table
+            .inner_join(groups::table)
+            .select(GroupInvitation::as_select())
+            .filter(
+                group_invitations::code.eq(code.trim().to_uppercase())
+                .and(group_invitations::state.eq(State::Active))
+                .and(groups::state.eq(State::Active))
+            )
+            .first(conn)
+    }
+
+    /// Fetches all invitations restricted to the given email that can still be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - The email address, already lowercased.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the active, not expired and not used up invitations of active groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// for invitation in group_repo.fetch_open_invitations_for_email("student@example.org")? {
+    ///     group_repo.redeem_invitation(&invitation, &user_id)?;
+    /// }
+    /// ```
+    pub fn fetch_open_invitations_for_email(&self, email: &str) -> Result<Vec<GroupInvitation>, Error> {
+        use crate::schema::group_invitations;
+        use crate::schema::groups;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_invitations::table
+            .inner_join(groups::table)
+            .select(GroupInvitation::as_select())
+            .filter(
+                group_invitations::email.eq(email)
+                .and(group_invitations::state.eq(State::Active))
+                .and(groups::state.eq(State::Active))
+                .and(group_invitations::expires_at.is_null().or(group_invitations::expires_at.gt(dsl::now)))
+                .and(group_invitations::max_uses.is_null().or(group_invitations::uses.lt(group_invitations::max_uses.assume_not_null())))
+            )
+            .order(group_invitations::created_at.asc())
+            .load(conn)
+    }
+
+    /// Redeems an invitation for a user: the use is counted and the user becomes a member of the group
+    /// with the permissions of the invitation role, or of the default `add_member` role if the
+    /// invitation has none.
+    ///
+    /// Both happen in one transaction. The use is only counted if the invitation is still active,
+    /// not expired and not used up at that moment, so concurrent redemptions cannot exceed `max_uses`.
+    ///
+    /// # Arguments
+    ///
+    /// * `invitation` - The invitation to redeem.
+    /// * `user_id` - The unique identifier of the user joining the group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `MemberInfo` of the created membership.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the invitation cannot be redeemed anymore, or a database error
+    /// if the user is already a member of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let invitation = group_repo.fetch_invitation_by_code(&code)?;
+    /// let member = group_repo.redeem_invitation(&invitation, &user_id)?;
+    /// ```
+    pub fn redeem_invitation(&self, invitation: &GroupInvitation, user_id: &Uuid) -> Result<MemberInfo, Error> {
+        use crate::schema::group_invitations;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let role_key = match &invitation.role {
+            Some(role) => role.clone(),
+            None => self.fetch_default_member_role(&invitation.group_id)?,
+        };
+
+        let role_permissions = self.fetch_role_permissions(&role_key)?;
+
+        conn.transaction(|conn| {
+            let counted_uses = diesel::update(group_invitations::table)
+                .set((
+                    group_invitations::uses.eq(group_invitations::uses + 1),
+                    group_invitations::updated_at.eq(dsl::now),
+                ))
+                .filter(
+                    group_invitations::id.eq(invitation.id)
+                    .and(group_invitations::state.eq(State::Active))
+                    .and(group_invitations::expires_at.is_null().or(group_invitations::expires_at.gt(dsl::now)))
+                    .and(group_invitations::max_uses.is_null().or(group_invitations::uses.lt(group_invitations::max_uses.assume_not_null())))
+                )
+                .execute(conn)?;
+
+            if counted_uses == 0 {
+                return Err(Error::NotFound)
+            }
+
+            Self::insert_group_member(conn, &NewGroupMember {
+                user_id,
+                group_id: &invitation.group_id,
+            }, &role_key, role_permissions)
+        })
+    }
+
+    /// Checks whether a user is a member of a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `user_id` - The unique identifier of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the user is a member of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// if group_repo.is_member_of_group(&group_id, &user_id)? {
+    ///     println!("Already a member");
+    /// }
+    /// ```
+    pub fn is_member_of_group(&self, group_id: &Uuid, user_id: &Uuid) -> Result<bool, Error> {
+        use crate::schema::group_members;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        diesel::select(dsl::exists(
+            group_members::table.filter(
+                group_members::group_id.eq(group_id)
+                .and(group_members::user_id.eq(user_id))
+            )
+        )).get_result(conn)
+    }
+
+    /// Checks whether a user is the last one able to manage the members of a group.
+    ///
+    /// Managing the members requires the `Write` access type on the group member ressource. This is
+    /// the case if the user has a group scoped grant for the group and neither another active user
+    /// has a group scoped grant nor any active user has a global grant. Global grants of the user
+    /// are kept when leaving the group, so they count as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `user_id` - The unique identifier of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the group could no longer be managed without the group scoped
+    /// grants of the user.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// if group_repo.is_last_group_manager(&group_id, &user_id)? {
+    ///     println!("Cannot leave the group");
+    /// }
+    /// ```
+    pub fn is_last_group_manager(&self, group_id: &Uuid, user_id: &Uuid) -> Result<bool, Error> {
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| Self::is_last_group_manager_on(conn, group_id, user_id))
+    }
+
+    /// Removes the membership of a user who leaves a group, unless the user is the last one able to
+    /// manage the members of the group.
+    ///
+    /// The check and the removal run in one transaction which locks the grants of the managers, so
+    /// managers leaving at the same time cannot leave the group without any manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `user_id` - The unique identifier of the user who leaves.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `MemberInfo` of the removed membership, or `None` if the user is the
+    /// last one able to manage the group and stays a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the user is not a member of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// if group_repo.leave_group(&group_id, &user_id)?.is_none() {
+    ///     println!("Cannot leave the group");
+    /// }
+    /// ```
+    pub fn leave_group(&self, group_id: &Uuid, user_id: &Uuid) -> Result<Option<MemberInfo>, Error> {
+        use crate::schema::group_members;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let member_id: Uuid = group_members::table
+                .select(group_members::id)
+                .filter(
+                    group_members::group_id.eq(group_id)
+                    .and(group_members::user_id.eq(user_id))
+                )
+                .first(conn)?;
+
+            if Self::is_last_group_manager_on(conn, group_id, user_id)? {
+                return Ok(None)
+            }
+
+            Self::delete_group_member(conn, &member_id, group_id).map(Some)
+        })
+    }
+
+    /// Works like `is_last_group_manager` on an open transaction and locks the grants of the managers
+    /// until the transaction ends.
+    fn is_last_group_manager_on(conn: &mut PgConnection, group_id: &Uuid, user_id: &Uuid) -> Result<bool, Error> {
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+        use crate::schema::users;
+
+        let manager_ids: Vec<(Uuid, Option<Uuid>)> = user_permissions::table
+            .inner_join(user_access_types::table)
+            .inner_join(users::table)
+            .select((user_permissions::user_id, user_permissions::group_id))
+            .filter(
+                user_permissions::ressource.eq(GROUP_MEMBER_INFO)
+                .and(user_permissions::group_id.eq(group_id).or(user_permissions::group_id.is_null()))
+                .and(user_access_types::access_type.eq(AccessType::Write))
+                .and(user_access_types::permission.eq(true))
+                .and(users::state.eq(State::Active))
+            )
+            .for_update()
+            .load(conn)?;
+
+        let is_group_manager = manager_ids.iter()
+            .any(|(manager_id, manager_group_id)| manager_id == user_id && manager_group_id.is_some());
+
+        let has_other_managers = manager_ids.iter()
+            .any(|(manager_id, manager_group_id)| manager_id != user_id || manager_group_id.is_none());
+
+        Ok(is_group_manager && !has_other_managers)
+    }
+
+    /// Creates a join request of a user for a group.
+    ///
+    /// Requests for groups with the `Request` join policy are created as `Pending`. For groups with the
+    /// `Open` join policy the caller adds the member first and records the request as already `Approved`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_join_request` - The group, the user, an optional message and the initial state.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the created `JoinRequestInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a unique violation if the user already has a pending request for the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let join_request = group_repo.create_join_request(&NewGroupJoinRequest {
+    ///     group_id,
+    ///     user_id,
+    ///     message: Some("I attend the lecture on Mondays".to_string()),
+    ///     state: JoinRequestState::Pending,
+    ///     decided_at: None,
+    /// })?;
+    /// ```
+    pub fn create_join_request(&self, new_join_request: &NewGroupJoinRequest) -> Result<JoinRequestInfo, Error> {
+        use crate::schema::group_join_requests;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let join_request_id: Uuid = diesel::insert_into(group_join_requests::table)
+                .values(new_join_request)
+                .returning(group_join_requests::id)
+                .get_result(conn)?;
+
+            Self::fetch_join_request_on(conn, &new_join_request.group_id, &join_request_id)
+        })
+    }
+
+    /// Fetches a join request of a group together with the requesting user.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group the request belongs to.
+    /// * `join_request_id` - The unique identifier of the join request.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `JoinRequestInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the group has no join request with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let join_request = group_repo.fetch_join_request(&group_id, &join_request_id)?;
+    /// println!("{} wants to join", join_request.user.name);
+    /// ```
+    pub fn fetch_join_request(&self, group_id: &Uuid, join_request_id: &Uuid) -> Result<JoinRequestInfo, Error> {
+        let conn = &mut self.pool.get().unwrap();
+
+        Self::fetch_join_request_on(conn, group_id, join_request_id)
+    }
+
+    fn fetch_join_request_on(conn: &mut PgConnection, group_id: &Uuid, join_request_id: &Uuid) -> Result<JoinRequestInfo, Error> {
+        use crate::schema::group_join_requests;
+        use crate::schema::users;
+
+        group_join_requests::table
+            .inner_join(users::table.on(users::id.eq(group_join_requests::user_id)))
+            .select(JoinRequestInfo::as_select())
+            .filter(
+                group_join_requests::id.eq(join_request_id)
+                .and(group_join_requests::group_id.eq(group_id))
+            )
+            .first(conn)
+    }
+
+    /// Fetches the join requests of a group, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `state` - Only requests in this state are returned. `None` returns all requests.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `JoinRequestInfo`s of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let pending = group_repo.fetch_join_requests(&group_id, Some(JoinRequestState::Pending))?;
+    /// ```
+    pub fn fetch_join_requests(&self, group_id: &Uuid, state: Option<JoinRequestState>) -> Result<Vec<JoinRequestInfo>, Error> {
+        use crate::schema::group_join_requests;
+        use crate::schema::users;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let mut query = group_join_requests::table
+            .inner_join(users::table.on(users::id.eq(group_join_requests::user_id)))
+            .select(JoinRequestInfo::as_select())
+            .filter(group_join_requests::group_id.eq(group_id))
+            .order(group_join_requests::created_at.desc())
+            .into_boxed();
+
+        if let Some(state) = state {
+            query = query.filter(group_join_requests::state.eq(state));
+        }
+
+        query.load(conn)
+    }
+
+    /// Fetches the join requests a user made for active groups, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `JoinRequestInfo`s of the user.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let join_requests = group_repo.fetch_join_requests_of_user(&user_id)?;
+    /// ```
+    pub fn fetch_join_requests_of_user(&self, user_id: &Uuid) -> Result<Vec<JoinRequestInfo>, Error> {
+        use crate::schema::group_join_requests;
+        use crate::schema::groups;
+        use crate::schema::users;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_join_requests::table
+            .inner_join(users::table.on(users::id.eq(group_join_requests::user_id)))
+            .inner_join(groups::table)
+            .select(JoinRequestInfo::as_select())
+            .filter(
+                group_join_requests::user_id.eq(user_id)
+                .and(groups::state.eq(State::Active))
+            )
+            .order(group_join_requests::created_at.desc())
+            .load(conn)
+    }
+
+    /// Approves or rejects a pending join request.
+    ///
+    /// This only records the decision. Approving a request does not add the member, use
+    /// `approve_join_request` to add the user and record the approval together.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group the request belongs to.
+    /// * `join_request_id` - The unique identifier of the join request.
+    /// * `state` - The decision, either `Approved` or `Rejected`.
+    /// * `decided_from` - The unique identifier of the user who made the decision.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the decided `JoinRequestInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the group has no pending join request with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// group_repo.decide_join_request(&group_id, &join_request_id, JoinRequestState::Rejected, &user_id)?;
+    /// ```
+    pub fn decide_join_request(
+        &self,
+        group_id: &Uuid,
+        join_request_id: &Uuid,
+        state: JoinRequestState,
+        decided_from: &Uuid,
+    ) -> Result<JoinRequestInfo, Error> {
+        use crate::schema::group_join_requests;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            diesel::update(group_join_requests::table)
+                .set((
+                    group_join_requests::state.eq(state),
+                    group_join_requests::decided_at.eq(dsl::now.into_sql::<Timestamptz>().nullable()),
+                    group_join_requests::decided_from.eq(decided_from),
+                    group_join_requests::updated_at.eq(dsl::now),
+                ))
+                .filter(
+                    group_join_requests::id.eq(join_request_id)
+                    .and(group_join_requests::group_id.eq(group_id))
+                    .and(group_join_requests::state.eq(JoinRequestState::Pending))
+                )
+                .returning(group_join_requests::id)
+                .get_result::<Uuid>(conn)?;
+
+            Self::fetch_join_request_on(conn, group_id, join_request_id)
+        })
+    }
+
+    /// Approves a pending join request and adds the user to the group with the permissions of the
+    /// default member role of the group.
+    ///
+    /// The request is locked before the user is added, so concurrent decisions wait for each other
+    /// and only one of them succeeds. A user who already joined in another way is kept as member
+    /// and the request is approved anyway.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group the request belongs to.
+    /// * `join_request_id` - The unique identifier of the join request.
+    /// * `decided_from` - The unique identifier of the user who approved the request.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the approved `JoinRequestInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the group has no pending join request with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let join_request = group_repo.approve_join_request(&group_id, &join_request_id, &user_id)?;
+    /// ```
+    pub fn approve_join_request(
+        &self,
+        group_id: &Uuid,
+        join_request_id: &Uuid,
+        decided_from: &Uuid,
+    ) -> Result<JoinRequestInfo, Error> {
+        use crate::schema::group_join_requests;
+
+        let role_key = self.fetch_default_member_role(group_id)?;
+        let role_permissions = self.fetch_role_permissions(&role_key)?;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let user_id: Uuid = group_join_requests::table
+                .select(group_join_requests::user_id)
+                .filter(
+                    group_join_requests::id.eq(join_request_id)
+                    .and(group_join_requests::group_id.eq(group_id))
+                    .and(group_join_requests::state.eq(JoinRequestState::Pending))
+                )
+                .for_update()
+                .first(conn)?;
+
+            // The savepoint keeps the transaction usable if the user is already a member
+            match conn.transaction(|conn| Self::insert_group_member(conn, &NewGroupMember {
+                group_id,
+                user_id: &user_id,
+            }, &role_key, role_permissions)) {
+                Ok(_) | Err(Error::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _)) => (),
+                Err(err) => return Err(err),
+            }
+
+            diesel::update(group_join_requests::table)
+                .set((
+                    group_join_requests::state.eq(JoinRequestState::Approved),
+                    group_join_requests::decided_at.eq(dsl::now.into_sql::<Timestamptz>().nullable()),
+                    group_join_requests::decided_from.eq(decided_from),
+                    group_join_requests::updated_at.eq(dsl::now),
+                ))
+                .filter(group_join_requests::id.eq(join_request_id))
+                .execute(conn)?;
+
+            Self::fetch_join_request_on(conn, group_id, join_request_id)
+        })
+    }
+
+    /// Fetches a paginated list of groups where a user has 
+    /// specific rights, optionally filtered by group IDs.
+    ///
+    /// This function retrieves a paginated list of groups for 
+    /// which a specified user has specific rights,
+    /// providing both a subset of group information and the total 
+    /// count of eligible groups. Additionally,
+    /// you can provide a list of specific group IDs to further 
+    /// filter the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user for whom 
+    /// groups with specific rights are to be fetched.
+    /// * `pagination` - A reference to a `PagingSchema` struct that 
+    /// defines the pagination parameters,
+    ///                 such as page number and limit.
+    /// * `group_ids` - An optional vector of group IDs to filter the 
+    /// fetched groups. If `None`, all eligible
+    ///                groups will be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `GroupInfoListWithCount` struct, 
+    /// which includes:
+    ///
+    /// 1. `Vec<(Uuid, String)>` - A paginated list of tuples, where 
+    /// each tuple represents a group. The tuple
+    ///    includes the group's unique ID and name.
+    /// 2. `usize` - The total count of groups for which the user has 
+    /// specific rights.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the 
+    /// database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use diesel::prelude::*;
+    ///
+    /// let db_conn = PgRepo::establish_connection("database_url");
+    /// let group_repo = db_conn.new_group_repo();
+    /// let user_id = Uuid::new_v4();
+    /// let pagination = PagingSchema {
+    ///     page: 1,
+    ///     limit: 10,
+    /// };
+    /// let group_ids = Some(vec![Uuid::new_v4(), Uuid::new_v4()]);
+    ///
+    /// match group_repo.fetch_groups_where_user_has_rights(&user_id, &pagination, &group_ids) {
+    ///     Ok(group_info_list_with_count) => {
+    ///         let GroupInfoListWithCount { group_info_list, total_count } = group_info_list_with_count;
+    ///         for (group_id, group_name) in group_info_list {
+    ///             println!("Group ID: {}, Group Name: {}", group_id, group_name);
+    ///         }
+    ///         println!("Total Groups with Rights: {}", total_count);
+    ///     },
+    ///     Err(err) => eprintln!("Error: {}", err),
+    /// }
+    /// ```
+    ///
+    /// In this example, the function is used to fetch a paginated list of groups for which a user has specific
+    /// rights, optionally filtered by specific group IDs, and the retrieved information is printed.
+    pub fn fetch_groups_where_user_has_rights(
+        &self,
+        user_id: &Uuid,
+        pagination: &PagingSchema,
+        group_ids: &Option<Vec<Uuid>>,
+    ) -> Result<GroupInfoListWithCount, Error> {
+        use crate::schema::groups;
+        use crate::schema::ressources;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let offset = pagination.page * pagination.limit;
+
+        let mut filter_query: Box<dyn BoxableExpression<_, diesel::pg::Pg, SqlType = Bool>> =
+            Box::new(
+                user_permissions::user_id
+                    .eq(user_id)
+                    .and(ressources::key_value.eq(READ_GROUP_INFO))
+                    .and(user_access_types::access_type.eq(AccessType::Read))
+                    .and(user_access_types::permission.eq(true))
+                    .and(groups::state.eq(State::Active)),
+            );
+
+        conn.transaction(|conn| {
+            let total_count = match groups::table
+                .inner_join(
+                    user_permissions::table
+                    .inner_join(ressources::table)
+                    .inner_join(user_access_types::table)
+                )
+                .select(count_star())
+                .filter(&filter_query)
+                .first(conn)
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+
+            if group_ids.is_some() {
+                filter_query =
+                    Box::new(filter_query.and(groups::id.eq_any(group_ids.as_ref().unwrap())));
+            }
+
+            let group_info_list = match groups::table
+                .inner_join(
+                    user_permissions::table
+                    .inner_join(ressources::table)
+                    .inner_join(user_access_types::table)
+                )
+                .select((groups::id, groups::name, groups::parent))
+                .limit(pagination.limit.into())
+                .offset(offset.into())
+                .filter(filter_query)
+                .load(conn)
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+
+            Ok(GroupInfoListWithCount {
+                group_info_list,
+                total_count,
+            })
+        })
+    }
+
+    /// Fetches a paginated list of active groups along with the 
+    /// total count, optionally filtered by group IDs.
+    ///
+    /// This function retrieves a paginated list of active groups, 
+    /// providing both a subset of group information
+    /// and the total count of active groups. Additionally, you can 
+    /// provide a list of specific group IDs to
+    /// further filter the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `pagination` - A reference to a `PagingSchema` struct that 
+    /// defines the pagination parameters,
+    ///                 such as page number and limit.
+    /// * `group_ids` - An optional vector of group IDs to filter the 
+    /// fetched groups. If `None`, all active
+    ///                groups will be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `GroupInfoListWithCount` struct, which 
+    /// includes:
+    ///
+    /// 1. `Vec<(Uuid, String)>` - A paginated list of tuples, where each 
+    /// tuple represents a group. The tuple
+    ///    includes the group's unique ID and name.
+    /// 2. `usize` - The total count of active groups.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with 
+    /// the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use diesel::prelude::*;
+    ///
+    /// let db_conn = PgRepo::establish_connection("database_url");
+    /// let group_repo = db_conn.new_group_repo();
+    /// let pagination = PagingSchema {
+    ///     page: 1,
+    ///     limit: 10,
+    /// };
+    /// let group_ids = Some(vec![Uuid::new_v4(), Uuid::new_v4()]);
+    ///
+    /// match group_repo.fetch_all_active_groups(&pagination, &group_ids) {
+    ///     Ok(group_info_list_with_count) => {
+    ///         let GroupInfoListWithCount { group_info_list, total_count } = group_info_list_with_count;
+    ///         for (group_id, group_name) in group_info_list {
+    ///             println!("Group ID: {}, Group Name: {}", group_id, group_name);
+    ///         }
+    ///         println!("Total Active Groups: {}", total_count);
+    ///     },
+    ///     Err(err) => eprintln!("Error: {}", err),
+    /// }
+    /// ```
+    ///
+    /// In this example, the function is used to fetch a paginated list of active groups, optionally filtered
+    /// by specific group IDs, and the retrieved information is printed.
+    pub fn fetch_all_active_groups(
+        &self,
+        pagination: &PagingSchema,
+        group_ids: &Option<Vec<Uuid>>,
+    ) -> Result<GroupInfoListWithCount, Error> {
+        use crate::schema::groups;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let offset = pagination.page * pagination.limit;
+
+        let mut filter_query: Box<dyn BoxableExpression<_, diesel::pg::Pg, SqlType = Bool>> =
+            Box::new(groups::state.eq(State::Active));
+
+        if group_ids.is_some() {
+            filter_query = Box::new(groups::id.eq_any(group_ids.as_ref().unwrap()));
+        }
+
+        conn.transaction(|conn| {
+            let group_info_list = match groups::table
+                .select((groups::id, groups::name, groups::parent))
+                .limit(pagination.limit.into())
+                .offset(offset.into())
+                .filter(filter_query)
+                .load(conn)
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+
+            let total_count = match groups::table.select(count_star()).first(conn) {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+
+            Ok(GroupInfoListWithCount {
+                group_info_list,
+                total_count,
+            })
+        })
+    }
+
+    /// Searches the active groups by name and description.
+    ///
+    /// Every word of the search text has to occur in the name or the description of a group,
+    /// a word also matches as prefix or part of a longer word. The search uses the trigram
+    /// indexes on `groups`, so it stays fast for large amounts of groups.
+    ///
+    /// The result is ordered by relevance: groups whose name starts with the search text come first,
+    /// afterwards the groups are ordered by their trigram similarity to the search text and by name.
+    /// Without search text the groups are ordered by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the searching user, used for the membership filter and
+    ///   to restrict the result to the groups the user can see.
+    /// * `filter` - A reference to a `GroupSearchFilter` containing the search text and the filters.
+    /// * `pagination` - A reference to a `PagingSchema` struct that defines the page and limit.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `GroupSearchListWithCount` with the requested page of groups,
+    /// the groups of the page the user is a member of and the total count of found groups.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let filter = GroupSearchFilter {
+    ///     text: Some("theo inf".to_string()),
+    ///     ancestor: None,
+    ///     member: None,
+    ///     catalogue: Some(true),
+    ///     all_groups: false,
+    /// };
+    ///
+    /// let found = group_repo.search_groups(&user_id, &filter, &pagination)?;
+    /// println!("Found {} groups", found.total_count);
+    /// ```
+    pub fn search_groups(
+        &self,
+        user_id: &Uuid,
+        filter: &GroupSearchFilter,
+        pagination: &PagingSchema,
+    ) -> Result<GroupSearchListWithCount, Error> {
+        use crate::schema::groups;
+        use crate::schema::group_members;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let offset = pagination.page * pagination.limit;
+
+        let text = filter.text.as_deref().map(str::trim).filter(|text| !text.is_empty());
+
+        conn.transaction(|conn| {
+            let total_count = groups::table
+                .select(count_star())
+                .filter(Self::group_search_filter(user_id, filter, text))
+                .first(conn)?;
+
+            let mut query = groups::table
+                .select(GroupSearchInfo::as_select())
+                .filter(Self::group_search_filter(user_id, filter, text))
+                .limit(pagination.limit.into())
+                .offset(offset.into())
+                .into_boxed();
+
+            query = match text {
+                Some(text) => query.order((
+                    groups::name.ilike(format!("{}%", Self::escape_like(text))).desc(),
+                    (word_similarity(text, groups::name) + word_similarity(text, groups::search_text)).desc(),
+                    groups::name.asc(),
+                    groups::id.asc(),
+                )),
+                None => query.order((groups::name.asc(), groups::id.asc())),
+            };
+
+            let group_info_list: Vec<GroupSearchInfo> = query.load(conn)?;
+
+            let member_of: HashSet<Uuid> = group_members::table
+                .select(group_members::group_id)
+                .filter(
+                    group_members::user_id.eq(user_id)
+                    .and(group_members::group_id.eq_any(group_info_list.iter().map(|group| group.id)))
+                )
+                .load::<Uuid>(conn)?
+                .into_iter()
+                .collect();
+
+            Ok(GroupSearchListWithCount {
+                group_info_list,
+                member_of,
+                total_count,
+            })
+        })
+    }
+
+    /// Builds the condition a group has to fulfill to be found by `search_groups`.
+    fn group_search_filter(
+        user_id: &Uuid,
+        filter: &GroupSearchFilter,
+        text: Option<&str>,
+    ) -> Box<dyn BoxableExpression<crate::schema::groups::table, diesel::pg::Pg, SqlType = Bool>> {
+        use crate::schema::groups;
+        use crate::schema::group_ancestors;
+        use crate::schema::group_members;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+
+        let catalogue_visible = serde_json::json!({"catalogue_visible": true});
+
+        let mut filter_query: Box<dyn BoxableExpression<groups::table, diesel::pg::Pg, SqlType = Bool>> =
+            Box::new(groups::state.eq(State::Active));
+
+        if !filter.all_groups {
+            let readable_group_ids = user_permissions::table
+                .inner_join(user_access_types::table)
+                .select(user_permissions::group_id.assume_not_null())
+                .filter(
+                    user_permissions::user_id.eq(*user_id)
+                    .and(user_permissions::ressource.eq(READ_GROUP_INFO))
+                    .and(user_permissions::group_id.is_not_null())
+                    .and(user_access_types::access_type.eq(AccessType::Read))
+                    .and(user_access_types::permission.eq(true))
+                );
+
+            filter_query = Box::new(filter_query.and(
+                groups::id.eq_any(readable_group_ids)
+                .or(groups::settings.contains(catalogue_visible.clone()))
+            ));
+        }
+
+        for word in text.into_iter().flat_map(str::split_whitespace) {
+            filter_query = Box::new(filter_query.and(
+                groups::search_text.ilike(format!("%{}%", Self::escape_like(word)))
+            ));
+        }
+
+        if let Some(ancestor) = filter.ancestor {
+            filter_query = Box::new(filter_query.and(groups::id.eq_any(
+                group_ancestors::table
+                    .select(group_ancestors::group_id)
+                    .filter(group_ancestors::ancestor_group_id.eq(ancestor))
+            )));
+        }
+
+        if let Some(member) = filter.member {
+            let member_group_ids = group_members::table
+                .select(group_members::group_id)
+                .filter(group_members::user_id.eq(*user_id));
+
+            filter_query = match member {
+                true => Box::new(filter_query.and(groups::id.eq_any(member_group_ids))),
+                false => Box::new(filter_query.and(diesel::dsl::not(groups::id.eq_any(member_group_ids)))),
+            };
+        }
+
+        if let Some(catalogue) = filter.catalogue {
+            filter_query = match catalogue {
+                true => Box::new(filter_query.and(groups::settings.contains(catalogue_visible))),
+                false => Box::new(filter_query.and(diesel::dsl::not(groups::settings.contains(catalogue_visible)))),
+            };
+        }
+
+        filter_query
+    }
+
+    /// Escapes the wildcard characters of `LIKE` patterns.
+    fn escape_like(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    }
+
+    /// Fetches a paginated list of members belonging to a 
+    /// specific group along with their roles, their group
+    /// scoped permissions and the total member count.
+    ///
+    /// The members can be filtered by member ids, by the role they joined
+    /// with and by a ressource (and access type) they have a granted
+    /// permission for. The permissions of all members of the page are
+    /// loaded with a single additional query.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group for which members are to be fetched.
+    /// * `filter` - A reference to a `MemberFilter` with the filters and the sort column.
+    /// * `pagination` - A reference to a `PagingSchema` struct that defines the pagination
+    ///   parameters, such as page number, limit and order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `GroupMemberListWithCount` struct, which 
+    /// includes:
+    ///
+    /// 1. `Vec<GroupMemberInfo>` - The requested page of members, including
+    ///    the name, email, join date and role of every member.
+    /// 2. `HashMap<Uuid, Vec<(String, AccessType)>>` - The granted group
+    ///    scoped permissions of the members, keyed by user id.
+    /// 3. `i64` - The total count of members matching the role and ressource
+    ///    filters. Like before, `member_ids` does not reduce the total count.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with 
+    /// the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use diesel::prelude::*;
+    ///
+    /// let db_conn = PgRepo::establish_connection("database_url");
+    /// let group_repo = db_conn.new_group_repo();
+    /// let group_id = Uuid::new_v4();
+    /// let pagination = PagingSchema {
+    ///     page: 0,
+    ///     limit: 10,
+    ///     order: OrderDir::ASC,
+    /// };
+    /// let filter = MemberFilter {
+    ///     role: Some("tutor".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match group_repo.fetch_all_member_of_group(&group_id, &filter, &pagination) {
+    ///     Ok(member_list_with_count) => {
+    ///         for member in member_list_with_count.member_list {
+    ///             println!("Member ID: {}, Name: {}, Joined: {}", member.member_id, member.name, member.joined_at);
+    ///         }
+    ///         println!("Tutors in Group: {}", member_list_with_count.total_count);
+    ///     },
+    ///     Err(err) => eprintln!("Error: {}", err),
+    /// }
+    /// ```
+    pub fn fetch_all_member_of_group(
+        &self,
+        group_id: &Uuid,
+        filter: &MemberFilter,
+        pagination: &PagingSchema,
+    ) -> Result<GroupMemberListWithCount, Error> {
+        use crate::schema::group_members;
+        use crate::schema::users;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let offset = pagination.page * pagination.limit;
+
+        conn.transaction(|conn| {
+            let total_count = group_members::table
+                .inner_join(users::table.on(group_members::user_id.eq(users::id)))
+                .select(count_star())
+                .filter(Self::member_filter(group_id, filter))
+                .first(conn)?;
+
+            let mut query = group_members::table
+                .inner_join(users::table.on(group_members::user_id.eq(users::id)))
+                .select((
+                    group_members::id,
+                    users::id,
+                    users::name,
+                    users::email,
+                    group_members::joined_at,
+                    group_members::role,
+                ))
+                .filter(Self::member_filter(group_id, filter))
+                .offset(offset.into())
+                .limit(pagination.limit.into())
+                .into_boxed();
+
+            if let Some(member_ids) = &filter.member_ids {
+                query = query.filter(group_members::id.eq_any(member_ids));
+            }
+
+            query = match (filter.sort, pagination.order) {
+                (MemberSort::Name, OrderDir::ASC) => query.order((users::name.asc(), users::email.asc())),
+                (MemberSort::Name, OrderDir::DESC) => query.order((users::name.desc(), users::email.desc())),
+                (MemberSort::JoinedAt, OrderDir::ASC) => query.order((group_members::joined_at.asc(), users::name.asc())),
+                (MemberSort::JoinedAt, OrderDir::DESC) => query.order((group_members::joined_at.desc(), users::name.desc())),
+            };
+
+            let member_list: Vec<GroupMemberInfo> = query.load(conn)?;
+
+            let permission_list: Vec<(Uuid, String, AccessType)> = user_permissions::table
+                .inner_join(user_access_types::table)
+                .select((user_permissions::user_id, user_permissions::ressource, user_access_types::access_type))
+                .filter(
+                    user_permissions::group_id.eq(group_id)
+                    .and(user_permissions::user_id.eq_any(member_list.iter().map(|member| member.user_id)))
+                    .and(user_access_types::permission.eq(true))
+                )
+                .order((user_permissions::ressource.asc(), user_access_types::access_type.asc()))
+                .load(conn)?;
+
+            let mut permissions: HashMap<Uuid, Vec<(String, AccessType)>> = HashMap::new();
+
+            for (user_id, ressource, access_type) in permission_list {
+                permissions.entry(user_id).or_default().push((ressource, access_type));
+            }
+
+            Ok(GroupMemberListWithCount {
+                member_list,
+                permissions,
+                total_count,
+            })
+        })
+    }
+
+    /// Builds the condition for the members of a group matching the role and ressource filters.
+    fn member_filter(
+        group_id: &Uuid,
+        filter: &MemberFilter,
+    ) -> Box<dyn BoxableExpression<MemberQuerySource, diesel::pg::Pg, SqlType = Bool>> {
+        use crate::schema::group_members;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+
+        let mut filter_query: Box<dyn BoxableExpression<MemberQuerySource, diesel::pg::Pg, SqlType = Bool>> =
+            Box::new(group_members::group_id.eq(*group_id));
+
+        if let Some(role) = &filter.role {
+            filter_query = Box::new(filter_query.and(group_members::role.assume_not_null().eq(role.clone())));
+        }
+
+        if let Some(ressource) = &filter.ressource {
+            let mut permitted_users = user_permissions::table
+                .inner_join(user_access_types::table)
+                .select(user_permissions::user_id)
+                .filter(
+                    user_permissions::group_id.eq(*group_id)
+                    .and(user_permissions::ressource.eq(ressource.clone()))
+                    .and(user_access_types::permission.eq(true))
+                )
+                .into_boxed();
+
+            if let Some(access_type) = filter.access_type {
+                permitted_users = permitted_users.filter(user_access_types::access_type.eq(access_type));
+            }
+
+            filter_query = Box::new(filter_query.and(group_members::user_id.eq_any(permitted_users)));
+        }
+
+        filter_query
+    }
+
+    /// Fetches information about all members of a group
+    /// with specified member IDs.
+    ///
+    /// This function retrieves details about members
+    /// of a  group, specified by their unique
+    /// member IDs, including their user IDs, names, and email addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the  group.
+    /// * `member_ids` - A vector of unique member IDs to retrieve information for.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<MemberInfo>` where each `MemberInfo`
+    /// includes the following information:
+    ///
+    /// * `member_id` - The unique identifier of the group member.
+    /// * `user_id` - The unique identifier of the user.
+    /// * `name` - The name of the user.
+    /// * `email` - The email address of the user.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection or
+    /// if the specified  group or member IDs do not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use use repository::postgres::PgRepo; // Import your database connection type.
+    ///
+    /// let db_conn = PgRepo::establish_connection("database_url");
     /// let group_repo = db_conn.new_group_repo();
-    /// let pagination = PagingSchema {
-    ///     page: 1,
-    ///     limit: 10,
-    /// };
-    /// let group_ids = Some(vec![Uuid::new_v4(), Uuid::new_v4()]);
+    /// let group_id = Uuid::new_v4();
+    /// let member_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
     ///
-    /// match group_repo.fetch_all_active_groups(&pagination, &group_ids) {
-    ///     Ok(group_info_list_with_count) => {
-    ///         let GroupInfoListWithCount { group_info_list, total_count } = group_info_list_with_count;
-    ///         for (group_id, group_name) in group_info_list {
-    ///             println!("Group ID: {}, Group Name: {}", group_id, group_name);
+    /// match group_repo.fetch_all_member_of_group_in(&group_id, &member_ids) {
+    ///     Ok(member_info) => {
+    ///         for member in member_info {
+    ///             println!("Member ID: {}, User ID: {}, Name: {}, Email: {}", member.member_id, member.user_id, member.name, member.email);
     ///         }
-    ///         println!("Total Active Groups: {}", total_count);
     ///     },
     ///     Err(err) => eprintln!("Error: {}", err),
     /// }
     /// ```
     ///
-    /// In this example, the function is used to fetch a paginated list of active groups, optionally filtered
-    /// by specific group IDs, and the retrieved information is printed.
-    pub fn fetch_all_active_groups(
+    /// In this example, the function is used to fetch information about members of a  group
+    /// based on their unique member IDs, and the retrieved information is printed.
+    pub fn fetch_all_member_of_group_in(
         &self,
-        pagination: &PagingSchema,
-        group_ids: &Option<Vec<Uuid>>,
-    ) -> Result<GroupInfoListWithCount, Error> {
+        group_id: &Uuid,
+        member_ids: &Vec<Uuid>,
+    ) -> Result<Vec<MemberInfo>, Error> {
+        use crate::schema::group_members;
+        use crate::schema::users;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_members::table
+            .inner_join(users::table.on(group_members::user_id.eq(users::id)))
+            .select((group_members::id, users::id, users::name, users::email))
+            .filter(
+                group_members::group_id
+                    .eq(group_id)
+                    .and(group_members::id.eq_any(member_ids)),
+            )
+            .load(conn)
+    }
+
+    /// Fetches all members of a group without pagination, ordered by name and email.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the email, name and role of every member of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// for member in group_repo.fetch_roster(&group_id)? {
+    ///     println!("{},{},{}", member.email, member.name, member.role.unwrap_or_default());
+    /// }
+    /// ```
+    pub fn fetch_roster(&self, group_id: &Uuid) -> Result<Vec<RosterMember>, Error> {
+        use crate::schema::group_members;
+        use crate::schema::users;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        group_members::table
+            .inner_join(users::table.on(group_members::user_id.eq(users::id)))
+            .select((users::email, users::name, group_members::role))
+            .filter(group_members::group_id.eq(group_id))
+            .order((users::name.asc(), users::email.asc()))
+            .load(conn)
+    }
+
+    /// Marks a group and its whole active subtree as deleted and logs the user who initiated the action.
+    ///
+    /// Everything happens in a single transaction:
+    /// - The group and all of its active descendants are marked as deleted. `deleted_at` is set and
+    ///   `deleted_with` points to `group_id`, so the subtree can later be restored as one unit.
+    ///   Descendants that were already deleted before keep their own `deleted_with`.
+    /// - The active task packages of the deleted groups are marked as deleted as well.
+    /// - All group scoped `user_permissions` of the deleted groups are moved to
+    ///   `revoked_user_permissions`, so they no longer grant any access.
+    ///
+    /// Memberships are kept untouched, they are only reachable through the (deleted) groups.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group to be marked as deleted.
+    /// * `user_id` - The unique identifier of the user who initiated the deletion.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of deleted groups. It is 0 if there is no active group
+    /// with the given id.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use use repository::postgres::PgRepo; // Import your database connection type.
+    ///
+    /// let db_conn = PgRepo::establish_connection("database_url");
+    /// let group_repo = db_conn.new_group_repo();
+    /// let group_id = Uuid::new_v4();
+    /// let user_id = Uuid::new_v4();
+    ///
+    /// match group_repo.delete_group(&group_id, &user_id) {
+    ///     Ok(0) => println!("Group not found or already marked as deleted."),
+    ///     Ok(deleted_groups) => println!("{} groups marked as deleted.", deleted_groups),
+    ///     Err(err) => eprintln!("Error: {}", err),
+    /// }
+    /// ```
+    ///
+    /// In this example, the function is used to mark a group and its subgroups as deleted in the database.
+    pub fn delete_group(
+        &self,
+        group_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<usize, Error> {
+        use crate::schema::groups;
+        use crate::schema::group_ancestors;
+        use crate::schema::task_packages;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+        use crate::schema::revoked_user_permissions;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let deleted_group_ids: Vec<Uuid> = diesel::update(
+                groups::table
+            ).set(
+                (groups::state.eq(State::Deleted),
+                groups::updated_from.eq(user_id),
+                groups::updated_at.eq(dsl::now),
+                groups::deleted_at.eq(dsl::now.into_sql::<Timestamptz>().nullable()),
+                groups::deleted_with.eq(group_id)),
+            ).filter(
+                groups::id.eq(group_id)
+                .or(groups::id.eq_any(
+                    group_ancestors::table
+                        .select(group_ancestors::group_id)
+                        .filter(group_ancestors::ancestor_group_id.eq(group_id))
+                ))
+            ).filter(
+                groups::state.eq(State::Active)
+            ).returning(
+                groups::id
+            ).get_results(conn)?;
+
+            if !deleted_group_ids.contains(group_id) {
+                return Ok(0)
+            }
+
+            diesel::update(
+                task_packages::table
+            ).set(
+                (task_packages::state.eq(State::Deleted),
+                task_packages::updated_at.eq(dsl::now),
+                task_packages::deleted_with.eq(group_id)),
+            ).filter(
+                task_packages::group_id.eq_any(&deleted_group_ids)
+                .and(task_packages::state.eq(State::Active))
+            ).execute(conn)?;
+
+            let revoked_permissions: Vec<RevokedUserPermission> = user_permissions::table
+                .inner_join(user_access_types::table)
+                .select((
+                    user_permissions::id,
+                    user_access_types::access_type,
+                    user_permissions::user_id,
+                    user_permissions::ressource,
+                    user_permissions::group_id.assume_not_null(),
+                    user_access_types::permission,
+                    user_access_types::set_permission,
+                    user_access_types::set_set_permission,
+                ))
+                .filter(user_permissions::group_id.eq_any(&deleted_group_ids))
+                .load::<(Uuid, AccessType, Uuid, String, Uuid, bool, bool, bool)>(conn)?
+                .into_iter()
+                .map(|(user_permission_id, access_type, user_id, ressource, permission_group_id, permission, set_permission, set_set_permission)| RevokedUserPermission {
+                    user_permission_id,
+                    access_type,
+                    user_id,
+                    ressource,
+                    group_id: permission_group_id,
+                    permission,
+                    set_permission,
+                    set_set_permission,
+                    deleted_with: *group_id,
+                })
+                .collect();
+
+            diesel::insert_into(revoked_user_permissions::table)
+                .values(&revoked_permissions)
+                .execute(conn)?;
+
+            diesel::delete(
+                user_permissions::table
+                    .filter(user_permissions::group_id.eq_any(&deleted_group_ids))
+            ).execute(conn)?;
+
+            Ok(deleted_group_ids.len())
+        })
+    }
+
+    /// Fetches the deletion information of a deleted group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the deleted group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `DeletedGroupInfo` of the group. `deleted_with` is the group whose
+    /// deletion also deleted this group; it equals `group_id` if the group was deleted directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the group does not exist or is not deleted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let deleted_group = group_repo.fetch_deleted_group_info(&group_id)?;
+    /// let is_root_of_deletion = deleted_group.deleted_with == Some(group_id);
+    /// ```
+    pub fn fetch_deleted_group_info(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<DeletedGroupInfo, Error> {
+        use crate::schema::groups;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        groups::table
+            .select(DeletedGroupInfo::as_select())
+            .filter(
+                groups::id.eq(group_id)
+                .and(groups::state.eq(State::Deleted))
+            )
+            .first(conn)
+    }
+
+    /// Restores a group together with everything that was deleted along with it.
+    ///
+    /// This reverts `delete_group` in a single transaction: all groups and task packages whose
+    /// `deleted_with` points to `group_id` become active again and the revoked group scoped
+    /// permissions are granted again. Grants that were given again in the meantime are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group that was deleted directly.
+    /// * `user_id` - The unique identifier of the user who initiated the restore.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of restored groups.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// match group_repo.restore_group(&group_id, &user_id) {
+    ///     Ok(restored_groups) => println!("{} groups restored.", restored_groups),
+    ///     Err(err) => eprintln!("Error: {}", err),
+    /// }
+    /// ```
+    pub fn restore_group(
+        &self,
+        group_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<usize, Error> {
+        use crate::schema::groups;
+        use crate::schema::task_packages;
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+        use crate::schema::revoked_user_permissions;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let restored_groups = diesel::update(
+                groups::table
+            ).set(
+                (groups::state.eq(State::Active),
+                groups::updated_from.eq(user_id),
+                groups::updated_at.eq(dsl::now),
+                groups::deleted_at.eq(None::<NaiveDateTime>),
+                groups::deleted_with.eq(None::<Uuid>)),
+            ).filter(
+                groups::deleted_with.eq(group_id)
+                .and(groups::state.eq(State::Deleted))
+            ).execute(conn)?;
+
+            diesel::update(
+                task_packages::table
+            ).set(
+                (task_packages::state.eq(State::Active),
+                task_packages::updated_at.eq(dsl::now),
+                task_packages::deleted_with.eq(None::<Uuid>)),
+            ).filter(
+                task_packages::deleted_with.eq(group_id)
+            ).execute(conn)?;
+
+            let revoked_permissions: Vec<RevokedUserPermission> = revoked_user_permissions::table
+                .select(RevokedUserPermission::as_select())
+                .filter(revoked_user_permissions::deleted_with.eq(group_id))
+                .load(conn)?;
+
+            for revoked_permission in revoked_permissions {
+                diesel::insert_into(user_permissions::table)
+                    .values((
+                        user_permissions::id.eq(revoked_permission.user_permission_id),
+                        user_permissions::user_id.eq(revoked_permission.user_id),
+                        user_permissions::ressource.eq(&revoked_permission.ressource),
+                        user_permissions::group_id.eq(revoked_permission.group_id),
+                    ))
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
+
+                let user_permission_id: Uuid = user_permissions::table
+                    .select(user_permissions::id)
+                    .filter(
+                        user_permissions::user_id.eq(revoked_permission.user_id)
+                        .and(user_permissions::ressource.eq(&revoked_permission.ressource))
+                        .and(user_permissions::group_id.eq(revoked_permission.group_id))
+                    )
+                    .first(conn)?;
+
+                diesel::insert_into(user_access_types::table)
+                    .values(&NewUserAccessType {
+                        access_type: revoked_permission.access_type,
+                        permission: Some(revoked_permission.permission),
+                        set_permission: Some(revoked_permission.set_permission),
+                        set_set_permission: Some(revoked_permission.set_set_permission),
+                        user_permission_id,
+                    })
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
+            }
+
+            diesel::delete(
+                revoked_user_permissions::table
+                    .filter(revoked_user_permissions::deleted_with.eq(group_id))
+            ).execute(conn)?;
+
+            Ok(restored_groups)
+        })
+    }
+
+    /// Copies an active group, optionally with its subgroups and its task packages, in a single transaction.
+    ///
+    /// The copies keep the join policy and the settings of the originals. Task packages are copied with
+    /// links to the same task documents. Members, solution attempts and permissions of other users are not
+    /// copied, the creator of the copy gets the permissions of the `created_group` role for every new group.
+    /// Deleted groups, task packages and tasks are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group to be copied.
+    /// * `clone_group` - The name and parent of the copy, what is copied and who creates the copy.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the id of the copy and the mapping from the ids of the originals to the ids
+    /// of the copies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no active group with the given id, or an error if there is an
+    /// issue with the database connection or the parent does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let cloned_group = group_repo.clone_group(&group_id, &CloneGroup {
+    ///     name: "Automata SS24".to_string(),
+    ///     parent: None,
+    ///     include_subgroups: true,
+    ///     include_task_packages: true,
+    ///     created_from: user_id,
+    /// })?;
+    /// println!("Copied {} task packages", cloned_group.task_packages.len());
+    /// ```
+    pub fn clone_group(
+        &self,
+        group_id: &Uuid,
+        clone_group: &CloneGroup,
+    ) -> Result<ClonedGroup, Error> {
         use crate::schema::groups;
+        use crate::schema::group_ancestors;
+        use crate::schema::task_packages;
+        use crate::schema::tasks;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        let role_permissions = self.fetch_role_permissions(CREATED_GROUP_ROLE_KEY)?;
+
+        conn.transaction(|conn| {
+            let root: GroupTemplate = groups::table
+                .select(GroupTemplate::as_select())
+                .filter(
+                    groups::id.eq(group_id)
+                    .and(groups::state.eq(State::Active))
+                )
+                .first(conn)?;
 
-        let conn = &mut self.pool.get().unwrap();
+            let mut copied_groups: HashMap<Uuid, Uuid> = HashMap::new();
 
-        let offset = pagination.page * pagination.limit;
+            let root_copy_id = Self::insert_group_copy(conn, &root, &clone_group.name, clone_group.parent, clone_group, &role_permissions)?;
+            copied_groups.insert(root.id, root_copy_id);
 
-        let mut filter_query: Box<dyn BoxableExpression<_, diesel::pg::Pg, SqlType = Bool>> =
-            Box::new(groups::state.eq(State::Active));
+            if clone_group.include_subgroups {
+                let mut pending_groups: Vec<GroupTemplate> = groups::table
+                    .inner_join(group_ancestors::table.on(group_ancestors::group_id.eq(groups::id)))
+                    .select(GroupTemplate::as_select())
+                    .filter(
+                        group_ancestors::ancestor_group_id.eq(group_id)
+                        .and(groups::state.eq(State::Active))
+                    )
+                    .load(conn)?;
 
-        if group_ids.is_some() {
-            filter_query = Box::new(groups::id.eq_any(group_ids.as_ref().unwrap()));
-        }
+                // Parents are copied before their children, groups below deleted groups are never reached.
+                loop {
+                    let (ready_groups, rest): (Vec<GroupTemplate>, Vec<GroupTemplate>) = pending_groups
+                        .into_iter()
+                        .partition(|group| group.parent.is_some_and(|parent| copied_groups.contains_key(&parent)));
 
-        conn.transaction(|conn| {
-            let group_info_list = match groups::table
-                .select((groups::id, groups::name, groups::parent))
-                .limit(pagination.limit.into())
-                .offset(offset.into())
-                .filter(filter_query)
-                .load(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+                    if ready_groups.is_empty() {
+                        break;
+                    }
 
-            let total_count = match groups::table.select(count_star()).first(conn) {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+                    for group in ready_groups {
+                        let parent = group.parent.and_then(|parent| copied_groups.get(&parent).copied());
+                        let copy_id = Self::insert_group_copy(conn, &group, &group.name, parent, clone_group, &role_permissions)?;
+                        copied_groups.insert(group.id, copy_id);
+                    }
 
-            Ok(GroupInfoListWithCount {
-                group_info_list,
-                total_count,
+                    pending_groups = rest;
+                }
+            }
+
+            let mut copied_task_packages: HashMap<Uuid, Uuid> = HashMap::new();
+            let mut copied_tasks: HashMap<Uuid, Uuid> = HashMap::new();
+
+            if clone_group.include_task_packages {
+                let task_package_list: Vec<(Uuid, Uuid, String, TaskPackageType)> = task_packages::table
+                    .select((
+                        task_packages::id,
+                        task_packages::group_id,
+                        task_packages::name,
+                        task_packages::task_package_type,
+                    ))
+                    .filter(
+                        task_packages::group_id.eq_any(copied_groups.keys())
+                        .and(task_packages::state.eq(State::Active))
+                    )
+                    .load(conn)?;
+
+                for (task_package_id, task_package_group_id, name, task_package_type) in task_package_list {
+                    let new_task_package = NewTaskPackage {
+                        id: Uuid::new_v4(),
+                        group_id: copied_groups[&task_package_group_id],
+                        name,
+                        task_package_type: Some(task_package_type),
+                    };
+
+                    diesel::insert_into(task_packages::table)
+                        .values(&new_task_package)
+                        .execute(conn)?;
+
+                    copied_task_packages.insert(task_package_id, new_task_package.id);
+                }
+
+                let task_list: Vec<(Uuid, Uuid, Uuid, String, i32, f64)> = tasks::table
+                    .select((
+                        tasks::id,
+                        tasks::task_doc_id,
+                        tasks::task_package_id,
+                        tasks::task_type,
+                        tasks::task_revision,
+                        tasks::points,
+                    ))
+                    .filter(
+                        tasks::task_package_id.eq_any(copied_task_packages.keys())
+                        .and(tasks::state.eq(State::Active))
+                    )
+                    .load(conn)?;
+
+                // the copies keep the pinned revision and the points, so both groups grade the same way
+                for (task_id, task_doc_id, task_package_id, task_type, task_revision, points) in task_list {
+                    let copy_id: Uuid = diesel::insert_into(tasks::table)
+                        .values((
+                            &NewTask {
+                                task_doc_id: &task_doc_id,
+                                task_package_id: &copied_task_packages[&task_package_id],
+                                task_type: &task_type,
+                            },
+                            tasks::task_revision.eq(task_revision),
+                            tasks::points.eq(points),
+                        ))
+                        .returning(tasks::id)
+                        .get_result(conn)?;
+
+                    copied_tasks.insert(task_id, copy_id);
+                }
+            }
+
+            Ok(ClonedGroup {
+                id: root_copy_id,
+                groups: copied_groups,
+                task_packages: copied_task_packages,
+                tasks: copied_tasks,
             })
         })
     }
 
-    /// Fetches a paginated list of members belonging to a 
-    /// specific group along with the total member count.
+    /// Inserts the copy of a group and grants the creator the permissions of the `created_group` role.
+    fn insert_group_copy(
+        conn: &mut PgConnection,
+        group: &GroupTemplate,
+        name: &str,
+        parent: Option<Uuid>,
+        clone_group: &CloneGroup,
+        role_permissions: &[(Uuid, String)],
+    ) -> Result<Uuid, Error> {
+        use crate::schema::groups;
+
+        let copy_id: Uuid = diesel::insert_into(groups::table)
+            .values((
+                &NewGroup {
+                    id: Uuid::new_v4(),
+                    name: name.to_string(),
+                    created_from: clone_group.created_from,
+                    updated_from: clone_group.created_from,
+                    parent,
+                },
+                groups::join_policy.eq(group.join_policy),
+                groups::settings.eq(&group.settings),
+            ))
+            .returning(groups::id)
+            .get_result(conn)?;
+
+        Self::grant_role_permissions(conn, &clone_group.created_from, &copy_id, role_permissions)?;
+
+        Ok(copy_id)
+    }
+
+    /// Updates the name and/or the parent of an active group.
     ///
-    /// This function retrieves a list of members who belong to a 
-    /// specified group, providing both
-    /// a paginated subset of members and the total count of members in 
-    /// the group.
+    /// Fields of `update_group` that are `None` stay untouched, `parent: Some(None)` moves
+    /// the group to the top level. `updated_from` and `updated_at` are always refreshed.
+    /// Moving a group is handled by the `move_group_ancestors` trigger, which recomputes
+    /// the `group_ancestors` entries of the whole moved subtree and rejects cycles.
     ///
     /// # Arguments
     ///
-    /// * `group_id` - The unique identifier of the group for which 
-    /// members are to be fetched.
-    /// * `pagination` - A reference to a `PagingSchema` struct that 
-    /// defines the pagination parameters,
-    ///                 such as page number and limit.
+    /// * `group_id` - The unique identifier of the group to be updated.
+    /// * `update_group` - The changes to apply, including the user who made them.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `MemberListWithCount` struct, which 
-    /// includes:
-    ///
-    /// 1. `Vec<(Uuid, Uuid, String, String)>` - A paginated list of 
-    /// tuples, where each tuple represents
-    ///    a member in the group. The tuple includes the member's 
-    /// unique ID, user ID, name, and email.
-    /// 2. `usize` - The total count of members in the specified group.
+    /// A `Result` containing the updated `GroupInfo`.
     ///
     /// # Errors
     ///
-    /// This function may return an error if there is an issue with 
-    /// the database connection or if
-    /// the specified group does not exist.
+    /// Returns `Error::NotFound` if there is no active group with the given id, or a
+    /// database error if the new parent does not exist or would create a cycle.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use diesel::prelude::*;
-    ///
-    /// let db_conn = PgRepo::establish_connection("database_url");
     /// let group_repo = db_conn.new_group_repo();
-    /// let group_id = Uuid::new_v4();
-    /// let pagination = PagingSchema {
-    ///     page: 1,
-    ///     limit: 10,
-    /// };
     ///
-    /// match group_repo.fetch_all_member_of_group(&group_id, &pagination) {
-    ///     Ok(member_list_with_count) => {
-    ///         let MemberListWithCount { member_list, total_count } = member_list_with_count;
-    ///         for (member_id, user_id, name, email) in member_list {
-    ///             println!("Member ID: {}, User ID: {}, Name: {}, Email: {}", member_id, user_id, name, email);
-    ///         }
-    ///         println!("Total Members in Group: {}", total_count);
-    ///     },
+    /// match group_repo.update_group(&group_id, &UpdateGroup {
+    ///     name: Some("Renamed".to_string()),
+    ///     parent: Some(None),
+    ///     join_policy: None,
+    ///     updated_from: user_id,
+    /// }) {
+    ///     Ok(group_info) => println!("Group {} is now called {}", group_info.id, group_info.name),
     ///     Err(err) => eprintln!("Error: {}", err),
     /// }
     /// ```
-    ///
-    /// In this example, the function is used to fetch a paginated list of members belonging to a group,
-    /// along with the total count of members in the group, and the retrieved information is printed.
-    pub fn fetch_all_member_of_group(
+    pub fn update_group(
         &self,
         group_id: &Uuid,
-        pagination: &PagingSchema,
-    ) -> Result<MemberListWithCount, Error> {
-        use crate::schema::group_members;
-        use crate::schema::users;
+        update_group: &UpdateGroup,
+    ) -> Result<GroupInfo, Error> {
+        use crate::schema::groups;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        diesel::update(
+            groups::table
+        ).set(
+            (update_group,
+            groups::updated_at.eq(dsl::now)),
+        ).filter(
+            groups::id.eq(group_id)
+            .and(groups::state.eq(State::Active))
+        ).returning(
+            (groups::id, groups::name, groups::parent)
+        ).get_result(conn)
+    }
+
+    /// Fetches the settings of an active group.
+    ///
+    /// Fields missing in the stored document take their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `GroupSettings` of the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no active group with the given id, or an error if there is
+    /// an issue with the database connection or the stored document is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let settings = group_repo.fetch_group_settings(&group_id)?;
+    /// println!("Listed in catalogue: {}", settings.catalogue_visible);
+    /// ```
+    pub fn fetch_group_settings(&self, group_id: &Uuid) -> Result<GroupSettings, Error> {
+        use crate::schema::groups;
 
         let conn = &mut self.pool.get().unwrap();
 
-        let offset = pagination.page * pagination.limit;
+        let settings: serde_json::Value = groups::table
+            .select(groups::settings)
+            .filter(
+                groups::id.eq(group_id)
+                .and(groups::state.eq(State::Active))
+            )
+            .first(conn)?;
 
-        let filter_query = group_members::group_id.eq(group_id);
+        Self::parse_group_settings(settings)
+    }
 
-        conn.transaction(|conn| {
-            let member_list = match group_members::table
-                .inner_join(users::table.on(group_members::user_id.eq(users::id)))
-                .select((group_members::id, users::id, users::name, users::email))
-                .filter(filter_query)
-                .offset(offset.into())
-                .limit(pagination.limit.into())
-                .load(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+    /// Replaces the settings of an active group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the group.
+    /// * `settings` - The new, already validated settings.
+    /// * `updated_from` - The unique identifier of the user changing the settings.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the stored `GroupSettings`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if there is no active group with the given id, or an error if there is
+    /// an issue with the database connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let settings = group_repo.update_group_settings(&group_id, &GroupSettings {
+    ///     catalogue_visible: true,
+    ///     ..Default::default()
+    /// }, &user_id)?;
+    /// ```
+    pub fn update_group_settings(
+        &self,
+        group_id: &Uuid,
+        settings: &GroupSettings,
+        updated_from: &Uuid,
+    ) -> Result<GroupSettings, Error> {
+        use crate::schema::groups;
 
-            let total_count = match group_members::table
-                .select(count_star())
-                .filter(filter_query)
-                .first(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+        let conn = &mut self.pool.get().unwrap();
 
-            Ok(MemberListWithCount {
-                member_list,
-                total_count,
-            })
-        })
+        let settings = serde_json::to_value(settings)
+            .map_err(|err| Error::SerializationError(Box::new(err)))?;
+
+        let settings: serde_json::Value = diesel::update(groups::table)
+            .set((
+                groups::settings.eq(settings),
+                groups::updated_from.eq(updated_from),
+                groups::updated_at.eq(dsl::now),
+            ))
+            .filter(
+                groups::id.eq(group_id)
+                .and(groups::state.eq(State::Active))
+            )
+            .returning(groups::settings)
+            .get_result(conn)?;
+
+        Self::parse_group_settings(settings)
     }
 
-    /// Fetches information about all members of a group
-    /// with specified member IDs.
+    /// Fetches the key of the role whose permissions new members of a group get.
     ///
-    /// This function retrieves details about members
-    /// of a  group, specified by their unique
-    /// member IDs, including their user IDs, names, and email addresses.
+    /// This is the `default_member_role` of the group settings, or the `add_member` role if the
+    /// group does not define one.
     ///
     /// # Arguments
     ///
-    /// * `group_id` - The unique identifier of the  group.
-    /// * `member_ids` - A vector of unique member IDs to retrieve information for.
+    /// * `group_id` - The unique identifier of the group, independent of its state.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<MemberInfo>` where each `MemberInfo`
-    /// includes the following information:
-    ///
-    /// * `member_id` - The unique identifier of the group member.
-    /// * `user_id` - The unique identifier of the user.
-    /// * `name` - The name of the user.
-    /// * `email` - The email address of the user.
+    /// A `Result` containing the `value_key` of the role.
     ///
     /// # Errors
     ///
-    /// This function may return an error if there is an issue with the database connection or
-    /// if the specified  group or member IDs do not exist.
+    /// Returns `Error::NotFound` if the group does not exist, or an error if there is an issue with the
+    /// database connection.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use use repository::postgres::PgRepo; // Import your database connection type.
+    /// let role_key = group_repo.fetch_default_member_role(&group_id)?;
+    /// ```
+    pub fn fetch_default_member_role(&self, group_id: &Uuid) -> Result<String, Error> {
+        use crate::schema::groups;
+
+        let conn = &mut self.pool.get().unwrap();
+
+        let settings: serde_json::Value = groups::table
+            .select(groups::settings)
+            .filter(groups::id.eq(group_id))
+            .first(conn)?;
+
+        Ok(Self::parse_group_settings(settings)?
+            .default_member_role
+            .unwrap_or(ADD_MEMBER_ROLE_KEY.to_string()))
+    }
+
+    fn parse_group_settings(settings: serde_json::Value) -> Result<GroupSettings, Error> {
+        serde_json::from_value(settings).map_err(|err| Error::DeserializationError(Box::new(err)))
+    }
+
+    /// Fetches the ids of all ancestors of a group, independent of their state.
     ///
-    /// let db_conn = PgRepo::establish_connection("database_url");
-    /// let group_repo = db_conn.new_group_repo();
-    /// let group_id = Uuid::new_v4();
-    /// let member_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+    /// # Arguments
     ///
-    /// match group_repo.fetch_all_member_of_group_in(&group_id, &member_ids) {
-    ///     Ok(member_info) => {
-    ///         for member in member_info {
-    ///             println!("Member ID: {}, User ID: {}, Name: {}, Email: {}", member.member_id, member.user_id, member.name, member.email);
-    ///         }
-    ///     },
-    ///     Err(err) => eprintln!("Error: {}", err),
-    /// }
-    /// ```
+    /// * `group_id` - The unique identifier of the group whose ancestors are fetched.
     ///
-    /// In this example, the function is used to fetch information about members of a  group
-    /// based on their unique member IDs, and the retrieved information is printed.
-    pub fn fetch_all_member_of_group_in(
+    /// # Returns
+    ///
+    /// A `Result` containing the ids of the parent, grandparent, ... of the group.
+    /// The list is empty for top level groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let ancestors = group_repo.fetch_group_ancestors(&group_id)?;
+    /// let is_in_subtree = ancestors.contains(&subtree_root_id);
+    /// ```
+    pub fn fetch_group_ancestors(
         &self,
         group_id: &Uuid,
-        member_ids: &Vec<Uuid>,
-    ) -> Result<MemberListWithCount, Error> {
-        use crate::schema::group_members;
-        use crate::schema::users;
+    ) -> Result<Vec<Uuid>, Error> {
+        use crate::schema::group_ancestors;
 
-        let conn = &mut self.pool.get().unwrap();
+        let conn =
+            &mut self.pool.get().unwrap();
 
-        conn.transaction(|conn| {
-            let member_list = match group_members::table
-                .inner_join(users::table.on(group_members::user_id.eq(users::id)))
-                .select((group_members::id, users::id, users::name, users::email))
-                .filter(
-                    group_members::group_id
-                        .eq(group_id)
-                        .and(group_members::id.eq_any(member_ids)),
-                )
-                .load(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+        group_ancestors::table
+            .select(group_ancestors::ancestor_group_id)
+            .filter(group_ancestors::group_id.eq(group_id))
+            .load(conn)
+    }
 
-            let total_count = match group_members::table
-                .select(count_star())
-                .filter(group_members::group_id.eq(group_id))
-                .first(conn)
-            {
-                Ok(v) => v,
-                Err(err) => return Err(err),
-            };
+    /// Fetches an active group together with all of its active descendants.
+    ///
+    /// The descendants are looked up through `group_ancestors`, so the whole subtree is
+    /// loaded with a single query. Descendants of deleted groups are still returned; callers
+    /// building a hierarchy from the `parent` links will not reach them.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the root of the subtree.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `GroupInfo` of the root and all of its descendants, ordered by name.
+    /// The list is empty if the root is not an active group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let subtree = group_repo.fetch_active_subtree(&group_id)?;
+    /// let children = subtree.iter().filter(|group| group.parent == Some(group_id));
+    /// ```
+    pub fn fetch_active_subtree(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<Vec<GroupInfo>, Error> {
+        use crate::schema::groups;
+        use crate::schema::group_ancestors;
 
-            Ok(MemberListWithCount {
-                member_list,
-                total_count,
-            })
-        })
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        let root_is_active: i64 = groups::table
+            .select(count_star())
+            .filter(
+                groups::id.eq(group_id)
+                .and(groups::state.eq(State::Active))
+            ).first(conn)?;
+
+        if root_is_active == 0 {
+            return Ok(vec![]);
+        }
+
+        groups::table
+            .select((groups::id, groups::name, groups::parent))
+            .filter(
+                groups::id.eq(group_id)
+                .or(groups::id.eq_any(
+                    group_ancestors::table
+                        .select(group_ancestors::group_id)
+                        .filter(group_ancestors::ancestor_group_id.eq(group_id))
+                ))
+            )
+            .filter(groups::state.eq(State::Active))
+            .order(groups::name.asc())
+            .load(conn)
     }
 
-    /// Marks a group as deleted in the database and logs the user who initiated the action.
+    /// Fetches the subset of the given groups on which the user holds a group scoped
+    /// `Read` permission for the group ressource.
     ///
-    /// This function updates the status of a group in the database by marking it as deleted and
-    /// records the user who initiated the deletion action.
+    /// Global permissions are not taken into account and have to be checked separately.
     ///
     /// # Arguments
     ///
-    /// * `group_id` - The unique identifier of the group to be marked as deleted.
-    /// * `user_id` - The unique identifier of the user who initiated the deletion.
+    /// * `user_id` - The unique identifier of the user.
+    /// * `group_ids` - The groups to check.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the number of affected rows, typically 1 if the group was successfully
-    /// marked as deleted, or an error if the operation fails.
+    /// A `Result` containing the ids of all groups from `group_ids` the user can read.
+    pub fn fetch_readable_group_ids(
+        &self,
+        user_id: &Uuid,
+        group_ids: &Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, Error> {
+        use crate::schema::user_permissions;
+        use crate::schema::user_access_types;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        let readable_group_ids: Vec<Option<Uuid>> = user_permissions::table
+            .inner_join(user_access_types::table)
+            .select(user_permissions::group_id)
+            .filter(
+                user_permissions::user_id.eq(user_id)
+                .and(user_permissions::ressource.eq(READ_GROUP_INFO))
+                .and(user_permissions::group_id.eq_any(group_ids))
+                .and(user_access_types::access_type.eq(AccessType::Read))
+                .and(user_access_types::permission.eq(true))
+            ).load(conn)?;
+
+        Ok(readable_group_ids.into_iter().flatten().collect())
+    }
+
+    /// Counts the members of each of the given groups.
     ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// This function may return an error if there is an issue with the database connection or if the
-    /// specified group does not exist.
+    /// * `group_ids` - The groups whose members are counted.
     ///
-    /// # Example
+    /// # Returns
     ///
-    /// ```rust
-    /// use use repository::postgres::PgRepo; // Import your database connection type.
+    /// A `Result` containing a map from group id to its amount of members.
+    /// Groups without members are missing in the map.
+    pub fn fetch_member_counts(
+        &self,
+        group_ids: &Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, i64>, Error> {
+        use crate::schema::group_members;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        let member_counts: Vec<(Uuid, i64)> = group_members::table
+            .group_by(group_members::group_id)
+            .select((group_members::group_id, count_star()))
+            .filter(group_members::group_id.eq_any(group_ids))
+            .load(conn)?;
+
+        Ok(member_counts.into_iter().collect())
+    }
+
+    /// Counts the active task packages of each of the given groups.
     ///
-    /// let db_conn = PgRepo::establish_connection("database_url");
-    /// let group_repo = db_conn.new_group_repo();
-    /// let group_id = Uuid::new_v4();
-    /// let user_id = Uuid::new_v4();
+    /// # Arguments
     ///
-    /// match group_repo.delete_group(&group_id, &user_id) {
-    ///     Ok(affected_rows) => {
-    ///         if affected_rows == 1 {
-    ///             println!("Group marked as deleted.");
-    ///         } else {
-    ///             println!("Group not found or already marked as deleted.");
-    ///         }
-    ///     },
-    ///     Err(err) => eprintln!("Error: {}", err),
-    /// }
-    /// ```
+    /// * `group_ids` - The groups whose task packages are counted.
     ///
-    /// In this example, the function is used to mark a group as deleted in the database.
-    pub fn delete_group(
+    /// # Returns
+    ///
+    /// A `Result` containing a map from group id to its amount of active task packages.
+    /// Groups without task packages are missing in the map.
+    pub fn fetch_task_package_counts(
         &self,
-        group_id: &Uuid,
-        user_id: &Uuid,
-    ) -> Result<usize, Error> {
-        use crate::schema::groups;
+        group_ids: &Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, i64>, Error> {
+        use crate::schema::task_packages;
 
-        let conn = 
+        let conn =
             &mut self.pool.get().unwrap();
 
-        diesel::update(
-            groups::table
-        ).set(
-            (groups::state.eq(State::Deleted),
-            groups::updated_from.eq(user_id),
-            groups::updated_at.eq(dsl::now)),
-        ).filter(
-            groups::id.eq(group_id)
-        ).execute(conn)
+        let task_package_counts: Vec<(Uuid, i64)> = task_packages::table
+            .group_by(task_packages::group_id)
+            .select((task_packages::group_id, count_star()))
+            .filter(
+                task_packages::group_id.eq_any(group_ids)
+                .and(task_packages::state.eq(State::Active))
+            )
+            .load(conn)?;
+
+        Ok(task_package_counts.into_iter().collect())
     }
 
-    /// Fetches the task type associated with the provided task ID.
+    /// Fetches the task associated with the provided task ID.
     ///
     /// # Arguments
     ///
@@ -1192,7 +3127,7 @@ impl GroupRepo {
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the fetched task type as a `String` or an `Error` if the operation fails.
+    /// Returns a `Result` containing the fetched `Task`, including its task document and task type, or an `Error` if the operation fails.
     ///
     /// # Examples
     ///
@@ -1203,13 +3138,13 @@ impl GroupRepo {
     /// let your_struct_instance = YourStructName::new(); // Replace with your actual struct name and instance.
     /// let task_id = Uuid::new_v4();
     ///
-    /// match your_struct_instance.fetch_task_type(&task_id) {
-    ///     Ok(task_type) => {
-    ///         println!("Fetched task type: {}", task_type);
-    ///         // Handle the fetched task type
+    /// match your_struct_instance.fetch_task(&task_id) {
+    ///     Ok(task) => {
+    ///         println!("Fetched task type: {}", task.task_type);
+    ///         // Handle the fetched task
     ///     }
     ///     Err(err) => {
-    ///         eprintln!("Error fetching task type: {}", err);
+    ///         eprintln!("Error fetching task: {}", err);
     ///         // Handle the error
     ///     }
     /// }
@@ -1218,10 +3153,10 @@ impl GroupRepo {
     /// # Panics
     ///
     /// Panics if there is an issue retrieving the database connection or if the query execution fails.
-    pub fn fetch_task_type(
+    pub fn fetch_task(
         &self,
         task_id: &Uuid,
-    ) -> Result<String, Error> {
+    ) -> Result<Task, Error> {
         use crate::schema::tasks;
 
         let conn = 
@@ -1229,13 +3164,56 @@ impl GroupRepo {
 
         tasks::table
             .select(
-                tasks::task_type
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
             )
             .filter(
                 tasks::id.eq(task_id)
             ).first(conn)
     }
 
+    /// Sets the revision of a task document in every task package it was added to.
+    ///
+    /// Answers created afterwards are pinned to this revision, answers created before keep the revision they
+    /// were given.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_doc_id` - A reference to the UUID of the task document.
+    /// * `task_revision` - The revision the task document is now at.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of updated tasks or an `Error` if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// match group_repo.update_task_revision(&task_doc_id, &2) {
+    ///     Ok(amount) => println!("Updated {} tasks", amount),
+    ///     Err(err) => eprintln!("Error updating task revision: {}", err),
+    /// }
+    /// ```
+    pub fn update_task_revision(
+        &self,
+        task_doc_id: &Uuid,
+        task_revision: &i32,
+    ) -> Result<usize, Error> {
+        use crate::schema::tasks;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        diesel::update(tasks::table)
+            .set((
+                tasks::task_revision.eq(task_revision),
+                tasks::updated_at.eq(diesel::dsl::now),
+            ))
+            .filter(
+                tasks::task_doc_id.eq(task_doc_id)
+            )
+            .execute(conn)
+    }
+
     pub fn fetch_task_packages(
         &self,
         group_id: &Uuid,
@@ -1253,10 +3231,55 @@ impl GroupRepo {
             ))
             .filter(
                 task_packages::group_id.eq(group_id)
+                .and(task_packages::state.eq(State::Active))
             )
             .load(conn)
     }
 
+    /// Checks whether a task package is active and belongs to an active group.
+    /// New solution attempts may only be started for such task packages.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_package_id` - The unique identifier of the task package.
+    /// * `group_id` - The unique identifier of the group the task package should belong to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the task package and its group are active, `false` if one of
+    /// them is deleted or the task package does not belong to the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// if !group_repo.is_task_package_active(&task_package_id, &group_id)? {
+    ///     println!("No new solution attempts can be started.");
+    /// }
+    /// ```
+    pub fn is_task_package_active(
+        &self,
+        task_package_id: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<bool, Error> {
+        use crate::schema::task_packages;
+        use crate::schema::groups;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        let sub_query = task_packages::table
+            .inner_join(groups::table)
+            .select(task_packages::id)
+            .filter(
+                task_packages::id.eq(task_package_id)
+                .and(task_packages::group_id.eq(group_id))
+                .and(task_packages::state.eq(State::Active))
+                .and(groups::state.eq(State::Active))
+            );
+
+        diesel::select(dsl::exists(sub_query)).get_result::<bool>(conn)
+    }
+
     /// Fetches tasks from a specified task collection, optionally filtered by task types.
     ///
     /// This function retrieves tasks associated with a given task collection ID. Optionally, it
@@ -1316,7 +3339,7 @@ impl GroupRepo {
         tasks::table
             .inner_join(task_packages::table)
             .select(
-                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type)
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
             )
             .filter(filter_query)
             .load(conn)
@@ -1361,7 +3384,7 @@ impl GroupRepo {
 
             tasks::table
             .select(
-                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type)
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
             )
             .filter(
                 tasks::task_package_id.eq(task_package_id)
@@ -1432,7 +3455,7 @@ impl GroupRepo {
 
         tasks::table
             .select(
-                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type)
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
             )
             .filter(
                 tasks::task_package_id.eq(task_package_id)
@@ -1502,7 +3525,7 @@ impl GroupRepo {
 
             tasks::table
             .select(
-                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type)
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
             )
             .filter(
                 tasks::task_package_id.eq(task_package_id)
@@ -1512,6 +3535,66 @@ impl GroupRepo {
         })
     }
 
+    /// Sets the points of tasks in a task package which answers to them are weighted with.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_package_id` - A reference to the ID of the task package.
+    /// * `group_id` - A reference to the ID of the group the task package belongs to.
+    /// * `task_points` - The new points per task document ID.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the updated `Task`s or an `Error` if the operation fails.
+    /// Deleted tasks and tasks which are not part of the task package are left out.
+    /// Answers which are already finished keep the points they were scored with.
+    pub fn update_task_points(
+        &self,
+        task_package_id: &Uuid,
+        group_id: &Uuid,
+        task_points: &[TaskPoints],
+    ) -> Result<Vec<Task>, Error> {
+        use crate::schema::tasks;
+        use crate::schema::task_packages;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let task_package_exists: bool = diesel::select(diesel::dsl::exists(
+                task_packages::table.filter(
+                    task_packages::id.eq(task_package_id)
+                    .and(task_packages::group_id.eq(group_id))
+                )
+            )).get_result(conn)?;
+
+            if !task_package_exists {
+                return Ok(vec![]);
+            }
+
+            for task_point in task_points {
+                diesel::update(tasks::table)
+                    .filter(
+                        tasks::task_package_id.eq(task_package_id)
+                        .and(tasks::task_doc_id.eq(task_point.task_doc_id))
+                        .and(tasks::state.eq(State::Active))
+                    )
+                    .set(tasks::points.eq(task_point.points))
+                    .execute(conn)?;
+            }
+
+            tasks::table
+            .select(
+                (tasks::id, tasks::task_package_id, tasks::task_doc_id, tasks::task_type, tasks::task_revision, tasks::points)
+            )
+            .filter(
+                tasks::task_package_id.eq(task_package_id)
+                .and(tasks::task_doc_id.eq_any(task_points.iter().map(|task_point| task_point.task_doc_id)))
+                .and(tasks::state.eq(State::Active))
+            ).load(conn)
+        })
+    }
+
     pub fn create_user_solution(
         &self,
         new_user_soltuion: &NewAnswer,
@@ -1650,7 +3733,8 @@ impl GroupRepo {
             )
             .select(
                 (answers::id, answers::correct, answers::solution_attempt_id, answers::answer_doc_id,
-                answers::task_id, answers::state, answers::created_from)
+                answers::task_id, answers::state, answers::created_from, answers::task_revision,
+                answers::score, answers::max_score, answers::seed, answers::permutation)
             )
             .filter(
                 answers::id.eq(user_answer_doc_id)
@@ -1675,13 +3759,47 @@ impl GroupRepo {
             )
             .select(
                 (answers::id, answers::correct, answers::solution_attempt_id, answers::answer_doc_id,
-                answers::task_id, answers::state, answers::created_from)
+                answers::task_id, answers::state, answers::created_from, answers::task_revision,
+                answers::score, answers::max_score, answers::seed, answers::permutation)
             )
             .filter(
                 solution_attempts::id.eq(solution_group_id)
             ).load(conn)
     }
 
+    /// Fetches all submitted answers to tasks of a task type which are not deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_type` - A reference to the task type of the answered tasks.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Answer`s which have an answer document or an `Error` if the operation fails.
+    pub fn fetch_answers_of_task_type(
+        &self,
+        task_type: &str,
+    ) -> Result<Vec<Answer>, Error> {
+        use crate::schema::answers;
+        use crate::schema::tasks;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        answers::table
+            .inner_join(tasks::table)
+            .select(
+                (answers::id, answers::correct, answers::solution_attempt_id, answers::answer_doc_id,
+                answers::task_id, answers::state, answers::created_from, answers::task_revision,
+                answers::score, answers::max_score, answers::seed, answers::permutation)
+            )
+            .filter(
+                tasks::task_type.eq(task_type)
+                .and(answers::state.ne(AnswerState::Deleted))
+                .and(answers::answer_doc_id.ne(Uuid::default()))
+            ).load(conn)
+    }
+
     /// Creates a new solution group for a user with associated tasks and returns it.
     ///
     /// This function creates a solution group for a specific user and task collection,
@@ -1695,6 +3813,8 @@ impl GroupRepo {
     /// - `user_id`: Reference to a UUID representing the user for whom the solution group is being created.
     /// - `task_package_id`: Reference to a UUID representing the ID of the task collection associated with the solution group.
     /// - `visibility`: Optional reference to a `Visibility` enum, specifying the visibility of the solution group.
+    /// - `seeds`: The seeds of the instances of task templates by task document ID, stored with their answers.
+    /// - `permutations`: The orders of the shuffled options of tasks by task document ID, stored with their answers.
     ///
     /// # Returns
     /// `Result<SolutionGroup, Error>`: On success, returns a `SolutionGroup` struct containing
@@ -1719,6 +3839,8 @@ impl GroupRepo {
         task_package_id: &Uuid,
         group_id: &Uuid,
         visibility: &Option<Visibility>,
+        seeds: &HashMap<Uuid, i64>,
+        permutations: &HashMap<Uuid, Vec<i32>>,
     ) -> Result<SolutionAttempt, Error> {
         use crate::schema::solution_attempts;
         use crate::schema::answers;
@@ -1750,6 +3872,9 @@ impl GroupRepo {
                     task_id: &task.id, 
                     state: &AnswerState::Active,
                     created_from: user_id,
+                    task_revision: &task.task_revision,
+                    seed: seeds.get(&task.task_doc_id),
+                    permutation: permutations.get(&task.task_doc_id),
                 };
 
                 diesel::insert_into(answers::table)
@@ -1775,6 +3900,9 @@ impl GroupRepo {
                     task_id: task.id,
                     task_doc_id: task.task_doc_id,
                     task_type: task.task_type,
+                    task_revision: task.task_revision,
+                    seed: new_user_solution.seed.copied(),
+                    permutation: new_user_solution.permutation.cloned(),
                 });
             }
 
@@ -1785,6 +3913,8 @@ impl GroupRepo {
                     solution_attempts::task_package_id,
                     solution_attempts::visibility,
                     solution_attempts::created_at,
+                    solution_attempts::score,
+                    solution_attempts::max_score,
                 ))
                 .filter(
                     solution_attempts::id.eq(solution_attempt_id)
@@ -1857,13 +3987,15 @@ impl GroupRepo {
                     solution_attempts::task_package_id,
                     solution_attempts::visibility,
                     solution_attempts::created_at,
+                    solution_attempts::score,
+                    solution_attempts::max_score,
                 ))
                 .filter(
                     filter_query
                 )
                 .first(conn).unwrap();
 
-            let solution_list: Vec<(Uuid, Uuid, Uuid, Uuid, String, AnswerState)> = answers::table
+            let solution_list: Vec<(Uuid, Uuid, Uuid, Uuid, String, AnswerState, i32, Option<i64>, Option<Vec<i32>>)> = answers::table
                 .inner_join(tasks::table)
                 .select((
                     answers::id,
@@ -1872,6 +4004,9 @@ impl GroupRepo {
                     tasks::task_doc_id,
                     tasks::task_type,
                     answers::state,
+                    answers::task_revision,
+                    answers::seed,
+                    answers::permutation,
                 ))
                 .filter(
                     answers::solution_attempt_id.eq(solution_group_id)
@@ -1891,6 +4026,9 @@ impl GroupRepo {
                     task_id: entry.2,
                     task_doc_id: entry.3,
                     task_type: entry.4,
+                    task_revision: entry.6,
+                    seed: entry.7,
+                    permutation: entry.8,
                 }
             }).collect();
 
@@ -1978,6 +4116,8 @@ impl GroupRepo {
                 solution_attempts::task_package_id,
                 solution_attempts::visibility,
                 solution_attempts::created_at,
+                solution_attempts::score,
+                solution_attempts::max_score,
             ))
             .filter(
                 filter_query
@@ -2051,40 +4191,183 @@ impl GroupRepo {
         })
     }
 
+    /// Finishes the active answers of a solution attempt and stores their scores and the attempt totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution_group_id` - A reference to the ID of the solution attempt.
+    /// * `answer_scores` - The graded share of the points, between `0` and `1`, per answer ID.
+    ///   Answers without an entry get no points.
+    /// * `grading_answers` - The IDs of the answers which are graded in the background.
+    ///   They are left in the `Grading` state until their score is stored with `update_answer_score`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount of finished answers or an `Error` if the operation fails.
+    /// An answer scores the share of the points of its task, it is `correct` if it scores all of them.
     pub fn finish_solution_attempt(
         &self,
         solution_group_id: &Uuid,
-        answer_correct_state: &HashMap<Uuid, bool>,
+        answer_scores: &HashMap<Uuid, f64>,
+        grading_answers: &HashSet<Uuid>,
     ) -> Result<usize, Error>{
         use crate::schema::answers;
         use crate::schema::solution_attempts;
+        use crate::schema::tasks;
 
         let conn = 
             &mut self.pool.get().unwrap();
             
         conn.transaction(|conn| {
-            let answer_doc_ids: Vec<Uuid> = answers::table
+            let answer_points: Vec<(Uuid, f64)> = answers::table
                 .inner_join(solution_attempts::table)
+                .inner_join(tasks::table)
                 .filter(
                     solution_attempts::id.eq(solution_group_id)
                     .and(answers::state.eq(AnswerState::Active))
                 )
-                .select(answers::id).load(conn).unwrap();
+                .select((answers::id, tasks::points)).load(conn)?;
 
             let mut amount_updated = 0;
-            for answer_id in answer_doc_ids {
+            for (answer_id, points) in answer_points {
+                let share = answer_scores.get(&answer_id).copied().unwrap_or(0.0);
+                let state = match grading_answers.contains(&answer_id) {
+                    true => AnswerState::Grading,
+                    false => AnswerState::Done,
+                };
+
                 amount_updated += diesel::update(answers::table)
                 .set((
-                    answers::state.eq(AnswerState::Done),
-                    answers::correct.eq(answer_correct_state.get(&answer_id).unwrap())
+                    answers::state.eq(state),
+                    answers::correct.eq(share >= 1.0),
+                    answers::score.eq(share * points),
+                    answers::max_score.eq(points),
                 ))
                 .filter(answers::id.eq(answer_id))
-                .execute(conn).unwrap();
+                .execute(conn)?;
+            }
+
+            if amount_updated > 0 {
+                Self::update_solution_attempt_score(conn, solution_group_id)?;
             }
+
+            Ok(amount_updated)
+        })
+    }
+
+    /// Stores the score of an answer in the `Grading` state, which is graded after its solution attempt is finished,
+    /// finishes the answer and updates the totals of the attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer_id` - A reference to the ID of the answer.
+    /// * `share` - The graded share of the points of the task, between `0` and `1`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount of updated answers or an `Error` if the operation fails.
+    pub fn update_answer_score(
+        &self,
+        answer_id: &Uuid,
+        share: f64,
+    ) -> Result<usize, Error> {
+        use crate::schema::answers;
+        use crate::schema::tasks;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        conn.transaction(|conn| {
+            let (solution_attempt_id, points): (Uuid, f64) = answers::table
+                .inner_join(tasks::table)
+                .filter(
+                    answers::id.eq(answer_id)
+                    .and(answers::state.eq(AnswerState::Grading))
+                )
+                .select((answers::solution_attempt_id, tasks::points))
+                .first(conn)?;
+
+            let amount_updated = diesel::update(answers::table)
+                .set((
+                    answers::state.eq(AnswerState::Done),
+                    answers::correct.eq(share >= 1.0),
+                    answers::score.eq(share * points),
+                    answers::max_score.eq(points),
+                ))
+                .filter(answers::id.eq(answer_id))
+                .execute(conn)?;
+
+            Self::update_solution_attempt_score(conn, &solution_attempt_id)?;
+
             Ok(amount_updated)
         })
     }
 
+    /// Fetches the answers in the `Grading` state, e.g. to grade them again after the server was restarted while grading them.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the answers which are being graded or an `Error` if the operation fails.
+    pub fn fetch_grading_answers(&self) -> Result<Vec<AnswerEntry>, Error> {
+        use crate::schema::answers;
+        use crate::schema::tasks;
+
+        let conn = 
+            &mut self.pool.get().unwrap();
+
+        let answers: Vec<AnswerEntryRow> = answers::table
+            .inner_join(tasks::table)
+            .select((
+                answers::id,
+                answers::answer_doc_id,
+                tasks::id,
+                tasks::task_doc_id,
+                tasks::task_type,
+                answers::task_revision,
+                answers::seed,
+                answers::permutation,
+            ))
+            .filter(answers::state.eq(AnswerState::Grading))
+            .load(conn)?;
+
+        Ok(answers.into_iter().map(|entry| AnswerEntry {
+            answer_id: entry.0,
+            answer_doc_id: entry.1,
+            task_id: entry.2,
+            task_doc_id: entry.3,
+            task_type: entry.4,
+            task_revision: entry.5,
+            seed: entry.6,
+            permutation: entry.7,
+        }).collect())
+    }
+
+    /// Sums the scores of the finished answers of a solution attempt into the totals of the attempt on an existing connection.
+    /// Answers which are being graded count with no points.
+    fn update_solution_attempt_score(
+        conn: &mut PgConnection,
+        solution_attempt_id: &Uuid,
+    ) -> Result<usize, Error> {
+        use crate::schema::answers;
+        use crate::schema::solution_attempts;
+
+        let totals: Vec<(f64, f64)> = answers::table
+            .select((answers::score, answers::max_score))
+            .filter(
+                answers::solution_attempt_id.eq(solution_attempt_id)
+                .and(answers::state.eq_any([AnswerState::Done, AnswerState::Grading]))
+            )
+            .load(conn)?;
+
+        diesel::update(solution_attempts::table)
+            .set((
+                solution_attempts::score.eq(totals.iter().map(|total| total.0).sum::<f64>()),
+                solution_attempts::max_score.eq(totals.iter().map(|total| total.1).sum::<f64>()),
+            ))
+            .filter(solution_attempts::id.eq(solution_attempt_id))
+            .execute(conn)
+    }
+
     pub fn fetch_task_package_user_statistic(
         &self,
         group_id: &Uuid,
@@ -2124,7 +4407,7 @@ impl GroupRepo {
         }
 
         conn.transaction(|conn| {
-            let statistics: Vec<(Uuid, NaiveDateTime, bool, Uuid)> = answers::table
+            let statistics: Vec<(Uuid, NaiveDateTime, bool, Uuid, f64, f64)> = answers::table
                 .inner_join(
                     solution_attempts::table
                     .inner_join(task_packages::table)
@@ -2135,6 +4418,8 @@ impl GroupRepo {
                     solution_attempts::updated_at,
                     answers::correct,
                     answers::id,
+                    answers::score,
+                    answers::max_score,
                 ))
                 .filter(filter_query)
                 .load(conn).unwrap();
@@ -2142,8 +4427,12 @@ impl GroupRepo {
             
             let mut user_statistics: Vec<TaskPackageUserStatisticValue> = vec![];
             for (key, group) in &statistics.into_iter().group_by(|elm| (elm.0, elm.1)) {
+                let group: Vec<_> = group.collect();
+
                 user_statistics.push(TaskPackageUserStatisticValue {
-                    amount_correct: group.filter(|v| v.2 == true).count(),
+                    amount_correct: group.iter().filter(|v| v.2).count(),
+                    score: group.iter().map(|v| v.4).sum(),
+                    max_score: group.iter().map(|v| v.5).sum(),
                     date: key.1.and_utc(),
                     solution_attempt_id: key.0,
                 })
@@ -2152,4 +4441,13 @@ impl GroupRepo {
             Ok(user_statistics)
         })
     }
-}
\ No newline at end of file
+}
+
+/// Generates a random join code. The alphabet leaves out characters that are easy to mix up (`0`/`O`, `1`/`I`).
+fn generate_invitation_code() -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+    (0..INVITATION_CODE_LENGTH)
+        .map(|_| ALPHABET[(OsRng.next_u32() % ALPHABET.len() as u32) as usize] as char)
+        .collect()
+}
@@ -2,12 +2,12 @@
 use std::io::{self, Write};
 
 use chrono::NaiveDateTime;
-use mongodb::{options::FindOptions, error::Error, Client, Collection, bson::{doc, Document, Bson, DateTime}, results::DeleteResult};
+use mongodb::{options::{FindOptions, FindOneOptions, FindOneAndUpdateOptions, ReturnDocument, UpdateOptions, IndexOptions}, error::{Error, ErrorKind, WriteFailure, WriteError}, Client, Collection, IndexModel, bson::{self, doc, Document, Bson, DateTime}, results::DeleteResult};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::{models::{task::{TaskDoc, TaskDocIdString}, util::{PagingSchema, OrderDir, State, state_from_str}, answer::{AnswerDocIdWithString, AnswerDoc}}};
+use crate::{grading::grader::GradeFeedback, models::{task::{TaskDoc, TaskDocIdString, TaskRevisionDocIdString, TaskMetadata, TaskFilter, TaskSort, TaskFacets, FacetCount, first_version}, util::{PagingSchema, OrderDir, State}, answer::{AnswerDocIdWithString, AnswerDoc}}};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SchemaDocWithIdString {
@@ -16,6 +16,10 @@ pub struct SchemaDocWithIdString {
     pub task_type: String,
     pub task_schema: Document,
     pub solution_schema: Document,
+    #[serde(default = "first_version")]
+    pub version: i32,
+    #[serde(default)]
+    pub deprecated: bool,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Debug, Clone)]
@@ -24,12 +28,30 @@ pub struct SchemaDoc {
     pub task_type: String,
     pub task_schema: Document,
     pub solution_schema: Document,
+    pub version: i32,
+    pub deprecated: bool,
 }
 
+impl From<SchemaDocWithIdString> for SchemaDoc {
+    fn from(schema: SchemaDocWithIdString) -> Self {
+        SchemaDoc {
+            id: Uuid::parse_str(&schema.id).unwrap(),
+            task_type: schema.task_type,
+            task_schema: schema.task_schema,
+            solution_schema: schema.solution_schema,
+            version: schema.version,
+            deprecated: schema.deprecated,
+        }
+    }
+}
+
+/// How often storing a schema version is retried if concurrent updates took the version.
+const SCHEMA_VERSION_ATTEMPTS: u32 = 3;
 
 #[derive(Clone)]
 pub struct MongoDbRepo {
     tasks: Collection<TaskDocIdString>,
+    task_revisions: Collection<TaskRevisionDocIdString>,
     solutions: Collection<AnswerDocIdWithString>,
     schemas: Collection<SchemaDocWithIdString>,
 }
@@ -41,9 +63,40 @@ impl MongoDbRepo {
             .expect("Error while connection Mongodb");
         let db = client.database(database_name);
         let tasks: Collection<TaskDocIdString> = db.collection("Task");
+        let task_revisions: Collection<TaskRevisionDocIdString> = db.collection("TaskRevision");
         let solutions: Collection<AnswerDocIdWithString> = db.collection("Solution");
         let schemas: Collection<SchemaDocWithIdString> = db.collection("Schemas");
-        MongoDbRepo { tasks, solutions, schemas }
+        MongoDbRepo { tasks, task_revisions, solutions, schemas }
+    }
+
+    /// Creates the indexes of the collections, if they do not exist yet.
+    ///
+    /// The versions of a task type are unique, so concurrent schema updates cannot store the same version twice.
+    /// The text index of the task search only covers the fields shown to the users answering a task and its
+    /// metadata. Solutions and parameters are left out, so the search cannot be used to look up which task a
+    /// guessed solution belongs to.
+    pub async fn create_indexes(&self) -> Result<(), Error> {
+        let version_index = IndexModel::builder()
+            .keys(doc!{ "task_type": 1, "version": 1 })
+            .options(IndexOptions::builder().name("schema_version".to_string()).unique(true).build())
+            .build();
+
+        self.schemas.create_index(version_index, None).await?;
+
+        let text_index = IndexModel::builder()
+            .keys(doc!{
+                "task.question": "text",
+                "task.answers": "text",
+                "task.items": "text",
+                "task.left": "text",
+                "task.right": "text",
+                "metadata.tags": "text",
+                "metadata.topic": "text",
+            })
+            .options(IndexOptions::builder().name("task_search".to_string()).build())
+            .build();
+
+        self.tasks.create_index(text_index, None).await.map(|_| ())
     }
 
 
@@ -86,6 +139,7 @@ impl MongoDbRepo {
             id: Uuid::new_v4().to_string(), 
             solution: solution.clone(),
             updated_at: DateTime::now(),
+            feedback: vec![],
         };
         let insert_result = self.solutions.insert_one(&new_doc, None).await;
 
@@ -94,6 +148,7 @@ impl MongoDbRepo {
                 id: Uuid::parse_str(&new_doc.id).unwrap(), 
                 solution, 
                 updated_at: NaiveDateTime::from_timestamp_millis(new_doc.updated_at.timestamp_millis()).unwrap(),
+                feedback: vec![],
             }),
             Err(err) => Err(err)
         }
@@ -103,19 +158,14 @@ impl MongoDbRepo {
         let new_doc = SchemaDocWithIdString {
             id: Uuid::new_v4().to_string(),
             task_type: task_type.to_string(),
-            task_schema: task_schema.clone(),
-            solution_schema: solution_schema.clone(),
+            task_schema,
+            solution_schema,
+            version: 1,
+            deprecated: false,
         };
 
-        let insert_result = self.schemas.insert_one(&new_doc, None).await;
-
-        match insert_result {
-            Ok(_) => Ok(SchemaDoc {
-                id: Uuid::parse_str(&new_doc.id).unwrap(),
-                task_schema,
-                task_type,
-                solution_schema,
-            }),
+        match self.schemas.insert_one(&new_doc, None).await {
+            Ok(_) => Ok(SchemaDoc::from(new_doc)),
             Err(err) => Err(err)
         }
     }
@@ -174,6 +224,7 @@ impl MongoDbRepo {
                 id: solution_id,
                 solution,
                 updated_at: NaiveDateTime::from_timestamp_millis(updated_at.timestamp_millis()).unwrap(),
+                feedback: vec![],
             }),
             Err(err) => {
                 print!("{}", err);
@@ -192,6 +243,9 @@ impl MongoDbRepo {
     /// - `task_type`: A `String` representing the type of the task.
     /// - `task`: A `Document` containing the task data to be stored in the MongoDB collection.
     /// - `solution`: A `Document` containing the associated solution data to be stored in the MongoDB collection.
+    /// - `schema_version`: The version of the schema the task and solution were validated against.
+    /// - `metadata`: The tags, topic, difficulty and language the task is found by.
+    /// - `author`: The user creating the task.
     ///
     /// # Returns
     ///
@@ -218,7 +272,7 @@ impl MongoDbRepo {
     ///     // Example solution data
     ///     let solution_data = Document::new(); // Replace this with your actual solution data.
     ///
-    ///     match db_conn.create_task(task_type, task_data, solution_data).await {
+    ///     match db_conn.create_task(task_type, task_data, solution_data, None, 1, TaskMetadata::default(), None).await {
     ///         Ok(new_task) => {
     ///             println!("New Task ID: {}", new_task.id);
     ///             println!("Task Type: {}", new_task.task_type);
@@ -231,29 +285,30 @@ impl MongoDbRepo {
     /// ```
     ///
     /// In this example, the function is used to asynchronously create a new task in the MongoDB collection, print the new task's unique identifier, task type, and state, and perform additional actions based on the new task.
-    pub async fn create_task(&self, task_type: String, task: Document, solution: Document) -> Result<TaskDoc, Error> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_task(&self, task_type: String, task: Document, solution: Document, parameters: Option<Document>, schema_version: i32, metadata: TaskMetadata, author: Option<Uuid>) -> Result<TaskDoc, Error> {
         let new_doc = TaskDocIdString {
             id: Uuid::new_v4().to_string(),
             task_type,
             task,
             state: State::Active.to_string(),
-            solution
+            solution,
+            revision: 1,
+            schema_version,
+            parameters,
+            metadata,
+            author: author.map(|author| author.to_string()),
+            created_at: Some(DateTime::now()),
         };
         let insert_result = self.tasks.insert_one(&new_doc, None).await;
 
         match insert_result {
-            Ok(_) => Ok(TaskDoc {
-                id: Uuid::parse_str(&new_doc.id).unwrap(),
-                task_type: new_doc.task_type,
-                task: new_doc.task,
-                state: state_from_str(&new_doc.state),
-                solution: new_doc.solution,
-            }),
+            Ok(_) => Ok(TaskDoc::from(new_doc)),
             Err(err) => Err(err),
         }
     }
 
-    /// Asynchronously fetches the schema associated with the provided task type.
+    /// Asynchronously fetches the latest schema version associated with the provided task type.
     ///
     /// # Arguments
     ///
@@ -293,19 +348,117 @@ impl MongoDbRepo {
     pub async fn fetch_schema(&self, task_type: String) -> Result<Option<SchemaDoc>, Error> {
         let filter_doc = doc!{ "task_type": task_type.to_string() };
 
+        match self.schemas.find_one(filter_doc, FindOneOptions::builder().sort(doc!{ "version": -1 }).build()).await {
+            Ok(Some(schema)) => Ok(Some(SchemaDoc::from(schema))),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        }
+
+    }
+
+    /// Asynchronously fetches a specific version of the schema of a task type.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_type` - A `String` representing the task type of the schema.
+    /// * `version` - The version of the schema to fetch.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option` with the `SchemaDoc` or `None` if the version does not exist.
+    /// Returns an `Error` if the operation fails.
+    pub async fn fetch_schema_version(&self, task_type: String, version: i32) -> Result<Option<SchemaDoc>, Error> {
+        let mut filter_doc = doc!{ "task_type": task_type };
+
+        filter_doc = if version == 1 {
+            filter_doc.into_iter().chain(doc!{ "$or": [{ "version": 1 }, { "version": { "$exists": false } }] }).collect()
+        } else {
+            filter_doc.into_iter().chain(doc!{ "version": version }).collect()
+        };
+
         match self.schemas.find_one(filter_doc, None).await {
-            Ok(Some(schema)) => Ok(Some(
-                SchemaDoc {
-                    id: Uuid::parse_str(&schema.id).unwrap(),
-                    task_schema: schema.task_schema,
-                    task_type: schema.task_type,
-                    solution_schema: schema.solution_schema,
-                }
-            )),
+            Ok(Some(schema)) => Ok(Some(SchemaDoc::from(schema))),
             Ok(None) => Ok(None),
             Err(err) => Err(err),
         }
+    }
+
+    /// Asynchronously stores a new version of the schema of a task type.
+    ///
+    /// Earlier versions are kept, so tasks validated against them stay readable. The new version inherits the
+    /// deprecation of the current one. If a concurrent update stored the next version first, the new version is
+    /// stored after the latest one instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_schema` - The latest `SchemaDoc` of the task type.
+    /// * `task_schema` - A `Document` containing the task schema of the new version.
+    /// * `solution_schema` - A `Document` containing the solution schema of the new version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the new `SchemaDoc` or an `Error` if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let current_schema = db_conn.fetch_schema("Multiple-Choice".to_string()).await.unwrap().unwrap();
+    ///
+    /// match db_conn.create_schema_version(&current_schema, Document::new(), Document::new()).await {
+    ///     Ok(schema) => println!("Created version {}", schema.version),
+    ///     Err(err) => eprintln!("Error creating schema version: {}", err),
+    /// }
+    /// ```
+    pub async fn create_schema_version(&self, current_schema: &SchemaDoc, task_schema: Document, solution_schema: Document) -> Result<SchemaDoc, Error> {
+        let mut current_schema = current_schema.clone();
+        let mut attempts = 0;
+
+        loop {
+            let new_doc = SchemaDocWithIdString {
+                id: Uuid::new_v4().to_string(),
+                task_type: current_schema.task_type.to_string(),
+                task_schema: task_schema.clone(),
+                solution_schema: solution_schema.clone(),
+                version: current_schema.version + 1,
+                deprecated: current_schema.deprecated,
+            };
+
+            match self.schemas.insert_one(&new_doc, None).await {
+                Ok(_) => return Ok(SchemaDoc::from(new_doc)),
+                // another update stored this version in the meantime, so the version after the latest one is used
+                Err(err) if is_duplicate_key(&err) && attempts < SCHEMA_VERSION_ATTEMPTS => {
+                    attempts += 1;
+                    current_schema = match self.fetch_schema(current_schema.task_type.clone()).await? {
+                        Some(latest_schema) => latest_schema,
+                        None => return Err(err),
+                    };
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Asynchronously deprecates all versions of the schema of a task type.
+    ///
+    /// Deprecated task types can no longer be used for new tasks, existing tasks and their answers stay readable.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_type` - A reference to the task type to deprecate.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of deprecated schema versions or an `Error` if the operation fails.
+    pub async fn deprecate_schema(&self, task_type: &str) -> Result<u64, Error> {
+        let update_result = self.schemas.update_many(
+            doc!{ "task_type": task_type },
+            doc!{ "$set": { "deprecated": true } },
+            None).await;
 
+        match update_result {
+            Ok(result) => Ok(result.matched_count),
+            Err(err) => Err(err),
+        }
     }
 
     pub async fn fetch_task(&self, task_id: Uuid, only_active: bool) -> Result<Option<TaskDoc>, Error> {
@@ -317,13 +470,7 @@ impl MongoDbRepo {
         match self.tasks.find_one(filter_doc, None).await {
             Ok(task) => {
                 if let Some(task) = task {
-                    Ok(Some(TaskDoc {
-                        id: Uuid::parse_str(&task.id).unwrap(),
-                        task_type: task.task_type,
-                        task: task.task,
-                        state: state_from_str(&task.state),
-                        solution: task.solution,
-                    }))
+                    Ok(Some(TaskDoc::from(task)))
                 } else {
                     Ok(None)
                 }
@@ -332,6 +479,165 @@ impl MongoDbRepo {
         }
     }
 
+    /// Asynchronously stores a new revision of an active task.
+    ///
+    /// The revision the task is currently at is copied into the `TaskRevision` collection before the task document
+    /// is overwritten, so answers pinned to that revision can still be graded and shown. Stored revisions are never
+    /// modified afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `current_task`: The `TaskDoc` the task is currently at, as fetched before validating the new revision.
+    /// - `task`: A `Document` containing the task data of the new revision.
+    /// - `solution`: A `Document` containing the solution data of the new revision.
+    /// - `schema_version`: The version of the schema the new revision was validated against.
+    /// - `metadata`: The new metadata of the task, the current metadata is kept if it is `None`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option` with the updated `TaskDoc`. It returns `None` if the task was deleted or
+    /// another revision was stored since `current_task` was fetched.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the MongoDB connection or if there is an error
+    /// while storing the revision.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let db_conn = MongoDbRepo::establish_connection("database_url", "database_name").await;
+    ///
+    ///     let current_task = db_conn.fetch_task(task_id, true).await.unwrap().unwrap();
+    ///
+    ///     match db_conn.update_task(&current_task, Document::new(), Document::new(), None, 1, None).await {
+    ///         Ok(Some(updated_task)) => println!("Task is now at revision {}", updated_task.revision),
+    ///         Ok(None) => println!("Task was deleted or changed in the meantime."),
+    ///         Err(err) => eprintln!("Error: {}", err),
+    ///     }
+    /// }
+    /// ```
+    pub async fn update_task(&self, current_task: &TaskDoc, task: Document, solution: Document, parameters: Option<Document>, schema_version: i32, metadata: Option<TaskMetadata>) -> Result<Option<TaskDoc>, Error> {
+        let revision_id = format!("{}_{}", current_task.id, current_task.revision);
+
+        self.task_revisions.update_one(
+            doc!{ "_id": &revision_id },
+            doc!{ "$setOnInsert": {
+                "task_id": current_task.id.to_string(),
+                "revision": current_task.revision,
+                "task": current_task.task.clone(),
+                "solution": current_task.solution.clone(),
+                "schema_version": current_task.schema_version,
+                "parameters": current_task.parameters.clone(),
+            }},
+            UpdateOptions::builder().upsert(true).build()).await?;
+
+        let mut changes = doc!{
+            "task": task,
+            "solution": solution,
+            "revision": current_task.revision + 1,
+            "schema_version": schema_version,
+            "parameters": parameters,
+        };
+
+        // metadata belongs to the task rather than to a revision, so it is only replaced if given
+        if let Some(metadata) = metadata {
+            changes.insert("metadata", bson::to_bson(&metadata)?);
+        }
+
+        let update_result = self.tasks.find_one_and_update(
+            doc!{
+                "_id": current_task.id.to_string(),
+                "state": State::Active.to_string(),
+                "$or": [
+                    { "revision": current_task.revision },
+                    { "revision": { "$exists": false } },
+                ],
+            },
+            doc!{ "$set": changes },
+            FindOneAndUpdateOptions::builder().return_document(ReturnDocument::After).build()).await;
+
+        match update_result {
+            Ok(Some(task)) => Ok(Some(TaskDoc::from(task))),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Asynchronously restores a task to the revision it had before an update.
+    ///
+    /// Used to undo `update_task` if a later step of the update fails. The task is only restored if it is still at
+    /// the revision written by that update, so changes made in the meantime are never overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// - `previous_task`: A reference to the `TaskDoc` as it was before the update.
+    /// - `updated_revision`: The revision written by the update.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or an `Error` if the task could not be restored.
+    pub async fn restore_task_revision(&self, previous_task: &TaskDoc, updated_revision: i32) -> Result<(), Error> {
+        self.tasks.update_one(
+            doc!{ "_id": previous_task.id.to_string(), "revision": updated_revision },
+            doc!{ "$set": {
+                "task": previous_task.task.clone(),
+                "solution": previous_task.solution.clone(),
+                "revision": previous_task.revision,
+                "schema_version": previous_task.schema_version,
+                "parameters": previous_task.parameters.clone(),
+                "metadata": bson::to_bson(&previous_task.metadata)?,
+            }},
+            None).await?;
+
+        Ok(())
+    }
+
+    /// Asynchronously fetches a task at the given revision.
+    ///
+    /// The current revision is read from the task document itself, earlier revisions from the `TaskRevision`
+    /// collection. The task type and state are always the ones of the current task document.
+    ///
+    /// # Arguments
+    ///
+    /// - `task_id`: A `Uuid` representing the unique identifier of the task.
+    /// - `revision`: The revision of the task to fetch.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option` with the `TaskDoc` at the given revision, or `None` if the task or the
+    /// revision does not exist.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the MongoDB connection.
+    pub async fn fetch_task_revision(&self, task_id: Uuid, revision: i32) -> Result<Option<TaskDoc>, Error> {
+        let task = match self.fetch_task(task_id, false).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+
+        if task.revision == revision {
+            return Ok(Some(task));
+        }
+
+        match self.task_revisions.find_one(doc!{ "_id": format!("{}_{}", task_id, revision) }, None).await {
+            Ok(Some(task_revision)) => Ok(Some(TaskDoc {
+                task: task_revision.task,
+                solution: task_revision.solution,
+                revision: task_revision.revision,
+                schema_version: task_revision.schema_version,
+                parameters: task_revision.parameters,
+                ..task
+            })),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Asynchronously retrieves a task from the MongoDB collection based on the provided task ID and optional filter for active tasks.
     ///
     /// This function takes a `task_id` of type `Uuid` and an `only_active` boolean indicating whether to filter for active tasks only. It fetches a task document from the MongoDB collection based on the provided task ID and, if specified, the active state filter. The function returns a `Result` indicating the success or failure of the operation.
@@ -474,13 +780,7 @@ impl MongoDbRepo {
 
                 while cursor.advance().await? {
                     let task = cursor.deserialize_current().unwrap();
-                    ret_vec.push(TaskDoc {
-                        id: Uuid::parse_str(&task.id).unwrap(),
-                        task_type: task.task_type,
-                        task: task.task,
-                        state: state_from_str(&task.state),
-                        solution: task.solution,
-                    })
+                    ret_vec.push(TaskDoc::from(task))
                 }
 
                 Ok(ret_vec)
@@ -489,6 +789,99 @@ impl MongoDbRepo {
         }
     }
 
+    /// Asynchronously searches the tasks matching `filter` and counts them by task type and metadata.
+    ///
+    /// Tasks are sorted by `sort` in the order of `pagination`. The facet counts are taken over every matching task,
+    /// not just the returned page, so they show how many tasks a further filter would leave. The page is fetched on
+    /// its own, as the counts are returned in a single document whose size is limited.
+    ///
+    /// # Arguments
+    ///
+    /// - `pagination`: A reference to a `PagingSchema` struct containing page, limit, and ordering settings.
+    /// - `filter`: The filters the tasks have to match. The `search` filter requires the index of `create_indexes`.
+    /// - `sort`: The field the tasks are sorted by.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the page of matching tasks, the amount of all matching tasks and their facet counts.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if there is an issue with the MongoDB connection or the text index is missing.
+    pub async fn search_tasks(&self, pagination: &PagingSchema, filter: &TaskFilter, sort: TaskSort) -> Result<(Vec<TaskDoc>, u64, TaskFacets), Error> {
+        let order_dir = if pagination.order == OrderDir::ASC { 1 } else { -1 };
+        let sort_field = match sort {
+            TaskSort::TaskType => "task_type",
+            TaskSort::CreatedAt => "created_at",
+        };
+
+        let mut sort_doc = doc!{ sort_field: order_dir };
+
+        // tasks of the same type keep the order they were created in
+        if sort == TaskSort::TaskType {
+            sort_doc.insert("created_at", 1);
+        }
+
+        // like in `fetch_all_tasks`, a limit of 0 returns every task
+        let find_options = FindOptions::builder()
+            .sort(sort_doc)
+            .skip((pagination.page * pagination.limit).max(0) as u64)
+            .limit(i64::from(pagination.limit))
+            .build();
+
+        let mut cursor = self.tasks.find(task_filter_doc(filter), find_options).await?;
+        let mut tasks = vec![];
+
+        while cursor.advance().await? {
+            tasks.push(TaskDoc::from(cursor.deserialize_current()?))
+        }
+
+        let facet = |field: &str, unwind: bool| {
+            let mut stages = vec![];
+
+            if unwind {
+                stages.push(doc!{ "$unwind": format!("${}", field) });
+            }
+
+            stages.push(doc!{ "$match": { field: { "$type": "string" } } });
+            stages.push(doc!{ "$group": { "_id": format!("${}", field), "count": { "$sum": 1 } } });
+            stages.push(doc!{ "$sort": { "count": -1, "_id": 1 } });
+
+            stages
+        };
+
+        let pipeline = vec![
+            doc!{ "$match": task_filter_doc(filter) },
+            doc!{ "$facet": {
+                "match_count": [{ "$count": "count" }],
+                "task_types": facet("task_type", false),
+                "tags": facet("metadata.tags", true),
+                "topics": facet("metadata.topic", false),
+                "difficulties": facet("metadata.difficulty", false),
+                "languages": facet("metadata.language", false),
+            }},
+        ];
+
+        let mut cursor = self.tasks.aggregate(pipeline, None).await?;
+
+        let result = match cursor.advance().await? {
+            true => cursor.deserialize_current()?,
+            false => Document::new(),
+        };
+
+        let match_count = facet_counts(&result, "match_count").first().map(|count| count.count).unwrap_or(0);
+
+        let facets = TaskFacets {
+            task_types: facet_counts(&result, "task_types"),
+            tags: facet_counts(&result, "tags"),
+            topics: facet_counts(&result, "topics"),
+            difficulties: facet_counts(&result, "difficulties"),
+            languages: facet_counts(&result, "languages"),
+        };
+
+        Ok((tasks, match_count, facets))
+    }
+
     pub async fn fetch_all_schemas(&self, pagination: &PagingSchema, task_types: Option<Vec<String>>) -> Result<Vec<SchemaDoc>, Error> {
         let start_position = pagination.page * pagination.limit;
         let order_dir = if pagination.order == OrderDir::ASC { 1 } else { -1 };
@@ -503,7 +896,8 @@ impl MongoDbRepo {
 
 
         let find_result = self.schemas.find(filter_doc, FindOptions::builder().limit(i64::from(pagination.limit)).skip(start_position as u64).sort(doc!{
-            "task_type": order_dir
+            "task_type": order_dir,
+            "version": order_dir,
         }).build()).await;
 
         match find_result {
@@ -512,12 +906,7 @@ impl MongoDbRepo {
 
                 while cursor.advance().await? {
                     let schema_doc = cursor.deserialize_current().unwrap();
-                    ret_vec.push(SchemaDoc {
-                        id: Uuid::parse_str(&schema_doc.id).unwrap(),
-                        task_type: schema_doc.task_type,
-                        task_schema: schema_doc.task_schema,
-                        solution_schema: schema_doc.solution_schema
-                    })
+                    ret_vec.push(SchemaDoc::from(schema_doc))
                 }
 
                 Ok(ret_vec)
@@ -526,6 +915,31 @@ impl MongoDbRepo {
         }
     }
 
+    /// Asynchronously fetches the current revision of all tasks of a task type which are not deleted.
+    ///
+    /// # Arguments
+    ///
+    /// - `task_type`: A reference to the task type of the tasks.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `TaskDoc` or an `Error` if the operation fails.
+    pub async fn fetch_tasks_of_type(&self, task_type: &str) -> Result<Vec<TaskDoc>, Error> {
+        let filter_doc = doc!{
+            "task_type": task_type,
+            "state": { "$ne": State::Deleted.to_string() },
+        };
+
+        let mut cursor = self.tasks.find(filter_doc, None).await?;
+        let mut ret_vec = vec![];
+
+        while cursor.advance().await? {
+            ret_vec.push(TaskDoc::from(cursor.deserialize_current()?))
+        }
+
+        Ok(ret_vec)
+    }
+
     /// Asynchronously marks a task as deleted in the MongoDB collection based on the provided task ID.
     ///
     /// This function takes a `task_id` parameter of type `Uuid` representing the unique identifier of the task to delete. The function returns a `Result` indicating the success or failure of the operation.
@@ -624,6 +1038,26 @@ impl MongoDbRepo {
         }
     }
 
+    /// Asynchronously stores the feedback of the grader on an answer document.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer_doc_id` - The ID of the graded answer document.
+    /// * `feedback` - The feedback of the grader, replacing the feedback stored before.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount of updated answer documents or an `Error` if the operation fails.
+    pub async fn update_answer_feedback(&self, answer_doc_id: &Uuid, feedback: &[GradeFeedback]) -> Result<u64, Error> {
+        let feedback = mongodb::bson::to_bson(feedback)?;
+
+        let update_result = self.solutions.update_one(
+            doc!{ "_id": answer_doc_id.to_string() },
+            doc!{ "$set": { "feedback": feedback } }, None).await?;
+
+        Ok(update_result.modified_count)
+    }
+
     pub async fn fetch_answer_doc(&self, solution_id: Uuid) -> Result<Option<AnswerDoc>, Error> {
         let fetch_result = self.solutions.find_one(
             doc!{ "_id": solution_id.to_string() }, None).await;
@@ -633,16 +1067,108 @@ impl MongoDbRepo {
                 id: solution_id,
                 solution: solution.solution,
                 updated_at: NaiveDateTime::from_timestamp_millis(solution.updated_at.timestamp_millis()).unwrap(),
+                feedback: solution.feedback,
             })),
             Ok(None) => Ok(None),
             Err(err) => Err(err),
         }
     }
 
+    /// Asynchronously fetches the answer documents with the given IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer_doc_ids` - A slice of the IDs of the answer documents.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the found `AnswerDoc`s or an `Error` if the operation fails.
+    pub async fn fetch_answer_docs(&self, answer_doc_ids: &[Uuid]) -> Result<Vec<AnswerDoc>, Error> {
+        let filter_doc = doc!{
+            "_id": { "$in": answer_doc_ids.iter().map(|id| Bson::String(id.to_string())).collect::<Vec<Bson>>() },
+        };
+
+        let mut cursor = self.solutions.find(filter_doc, None).await?;
+        let mut ret_vec = vec![];
+
+        while cursor.advance().await? {
+            let answer_doc = cursor.deserialize_current()?;
+            ret_vec.push(AnswerDoc {
+                id: Uuid::parse_str(&answer_doc.id).unwrap(),
+                solution: answer_doc.solution,
+                updated_at: NaiveDateTime::from_timestamp_millis(answer_doc.updated_at.timestamp_millis()).unwrap(),
+                feedback: answer_doc.feedback,
+            })
+        }
+
+        Ok(ret_vec)
+    }
+
     #[cfg(test)]
     pub async fn clear_db(&self) {
         self.tasks.drop(None).await;
+        let _ = self.task_revisions.drop(None).await;
         self.schemas.drop(None).await;
         self.solutions.drop(None).await;
+        let _ = self.create_indexes().await;
+    }
+}
+
+/// Whether the write failed because a unique index already contains the value.
+fn is_duplicate_key(err: &Error) -> bool {
+    matches!(*err.kind, ErrorKind::Write(WriteFailure::WriteError(WriteError { code: 11000, .. })))
+}
+
+/// Builds the query of the tasks matching every filter which is set.
+fn task_filter_doc(filter: &TaskFilter) -> Document {
+    let mut filter_doc = doc!{};
+    let strings = |values: &[String]| values.iter().map(|value| Bson::String(value.clone())).collect::<Vec<Bson>>();
+
+    if let Some(task_ids) = &filter.task_ids {
+        filter_doc.insert("_id", doc!{ "$in": task_ids.iter().map(|id| Bson::String(id.to_string())).collect::<Vec<Bson>>() });
+    }
+
+    if let Some(task_types) = &filter.task_types {
+        filter_doc.insert("task_type", doc!{ "$in": strings(task_types) });
+    }
+
+    if let Some(tags) = filter.tags.as_ref().filter(|tags| !tags.is_empty()) {
+        filter_doc.insert("metadata.tags", doc!{ "$all": strings(tags) });
     }
+
+    if let Some(topic) = &filter.topic {
+        filter_doc.insert("metadata.topic", topic);
+    }
+
+    if let Some(difficulty) = filter.difficulty {
+        filter_doc.insert("metadata.difficulty", bson::to_bson(&difficulty).unwrap_or(Bson::Null));
+    }
+
+    if let Some(language) = &filter.language {
+        filter_doc.insert("metadata.language", language);
+    }
+
+    if let Some(author) = filter.author {
+        filter_doc.insert("author", author.to_string());
+    }
+
+    if let Some(search) = filter.search.as_ref().filter(|search| !search.trim().is_empty()) {
+        filter_doc.insert("$text", doc!{ "$search": search });
+    }
+
+    filter_doc
+}
+
+/// Reads the counts of a `$facet` stage, grouped by value into `_id` and `count` or counted by `$count` into `count`.
+fn facet_counts(result: &Document, facet: &str) -> Vec<FacetCount> {
+    let groups = result.get_array(facet).map(|groups| groups.as_slice()).unwrap_or_default();
+
+    groups.iter().filter_map(Bson::as_document).map(|group| FacetCount {
+        value: group.get_str("_id").unwrap_or_default().to_string(),
+        count: match group.get("count") {
+            Some(Bson::Int32(count)) => *count as u64,
+            Some(Bson::Int64(count)) => *count as u64,
+            _ => 0,
+        },
+    }).collect()
 }
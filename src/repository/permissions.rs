@@ -631,6 +631,20 @@ impl PermissionsRepo {
             .execute(conn)
     }
 
+    pub fn role_exists(
+        &self,
+        role_key: &str,
+    ) -> Result<bool, Error> {
+        use crate::schema::roles;
+
+        let conn =
+            &mut self.pool.get().unwrap();
+
+        diesel::select(exists(
+            roles::table.filter(roles::value_key.eq(role_key))
+        )).get_result(conn)
+    }
+
     pub fn update_permission_on_role(
         &self,
         update_role: &UpdateRolePermission,
@@ -73,15 +73,21 @@ impl PgRepo {
         use crate::schema::task_packages;
         use crate::schema::solution_attempts;
         use crate::schema::role_access_types;
+        use crate::schema::revoked_user_permissions;
+        use crate::schema::group_invitations;
+        use crate::schema::group_join_requests;
 
         let conn = &mut self.pool.get().unwrap();
 
+        diesel::delete(group_invitations::table).execute(conn).unwrap();
+        diesel::delete(group_join_requests::table).execute(conn).unwrap();
         diesel::delete(role_access_types::table).execute(conn).unwrap();
         diesel::delete(role_permissions::table).execute(conn).unwrap();
         diesel::delete(roles::table).execute(conn).unwrap();
         diesel::delete(user_access_types::table).execute(conn).unwrap();
         diesel::delete(ressource_access_types::table).execute(conn).unwrap();
         diesel::delete(user_permissions::table).execute(conn).unwrap();
+        diesel::delete(revoked_user_permissions::table).execute(conn).unwrap();
         diesel::delete(group_ancestors::table).execute(conn).unwrap();
         diesel::delete(group_members::table).execute(conn).unwrap();
         diesel::delete(answers::table).execute(conn).unwrap();
@@ -304,10 +304,6 @@ impl UsersRepo {
     ///
     /// In this example, the function is used to create a new user in the database with the specified details and print the user information. 
     pub fn create_user(&self, new_user: &NewUser) -> Result<UserInfo, Error> {
-        use crate::schema::users;
-        use crate::schema::role_access_types;
-        use crate::schema::user_access_types;
-
         let conn = &mut self.pool.get().unwrap();
 
         let role_permissions = match self.fetch_role_permissions(CREATED_USER_ROLE_KEY) {
@@ -315,53 +311,65 @@ impl UsersRepo {
             Err(err) => return Err(err),
         };
 
-        conn.transaction(|conn | {
-            diesel::insert_into(users::table)
-                .values(new_user)
-                .execute(conn)?;
-
-            let user: UserInfo = match users::table
-                .select((users::id, users::name, users::email))
-                .filter(users::email.eq(&new_user.email))
-                .first(conn){
-                    Ok(v) => v,
-                    Err(err) => return Err(err),
-                };
-
-            for role_permission in role_permissions {
-
-                let user_permission_id = Uuid::new_v4();
-
-                let access_types: Vec<RoleAccesType> = role_access_types::table
-                    .select((
-                        role_access_types::access_type,
-                        role_access_types::set_permission,
-                        role_access_types::set_set_permission,
-                        role_access_types::permission,
-                    )).filter(
-                        role_access_types::role_permission_id.eq(role_permission.0)
-                    ).load(conn).unwrap();
-
-                diesel::insert_into(user_access_types::table)
-                    .values(access_types.into_iter().map(|access_type| NewUserAccessType {
-                        access_type: access_type.access_type,
-                        permission: Some(access_type.permission),
-                        set_permission: Some(access_type.set_permission),
-                        set_set_permission: Some(access_type.set_set_permission),
-                        user_permission_id,
-                    }).collect::<Vec<NewUserAccessType>>()
-                ).on_conflict((
-                    user_access_types::access_type,
-                    user_access_types::user_permission_id,
-                )).do_update()
-                .set((
-                    user_access_types::access_type.eq(excluded(user_access_types::access_type)),
-                    user_access_types::user_permission_id.eq(excluded(user_access_types::user_permission_id))
-                )).execute(conn)?;
+        conn.transaction(|conn| Self::insert_user(conn, new_user, role_permissions))
+    }
+
+    /// Inserts a user and grants the given role permissions on an open connection, so the user can be
+    /// created in the transaction of other changes.
+    pub(crate) fn insert_user(
+        conn: &mut PgConnection,
+        new_user: &NewUser,
+        role_permissions: Vec<(Uuid, String)>,
+    ) -> Result<UserInfo, Error> {
+        use crate::schema::users;
+        use crate::schema::role_access_types;
+        use crate::schema::user_access_types;
+
+        diesel::insert_into(users::table)
+            .values(new_user)
+            .execute(conn)?;
+
+        let user: UserInfo = match users::table
+            .select((users::id, users::name, users::email))
+            .filter(users::email.eq(&new_user.email))
+            .first(conn){
+                Ok(v) => v,
+                Err(err) => return Err(err),
             };
-            
-            Ok(user)
-        })
+
+        for role_permission in role_permissions {
+
+            let user_permission_id = Uuid::new_v4();
+
+            let access_types: Vec<RoleAccesType> = role_access_types::table
+                .select((
+                    role_access_types::access_type,
+                    role_access_types::set_permission,
+                    role_access_types::set_set_permission,
+                    role_access_types::permission,
+                )).filter(
+                    role_access_types::role_permission_id.eq(role_permission.0)
+                ).load(conn).unwrap();
+
+            diesel::insert_into(user_access_types::table)
+                .values(access_types.into_iter().map(|access_type| NewUserAccessType {
+                    access_type: access_type.access_type,
+                    permission: Some(access_type.permission),
+                    set_permission: Some(access_type.set_permission),
+                    set_set_permission: Some(access_type.set_set_permission),
+                    user_permission_id,
+                }).collect::<Vec<NewUserAccessType>>()
+            ).on_conflict((
+                user_access_types::access_type,
+                user_access_types::user_permission_id,
+            )).do_update()
+            .set((
+                user_access_types::access_type.eq(excluded(user_access_types::access_type)),
+                user_access_types::user_permission_id.eq(excluded(user_access_types::user_permission_id))
+            )).execute(conn)?;
+        };
+        
+        Ok(user)
     }
 
     #[cfg(test)]
@@ -9,6 +9,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "answer_state"))]
     pub struct AnswerState;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "join_policy"))]
+    pub struct JoinPolicy;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "join_request_state"))]
+    pub struct JoinRequestState;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "state"))]
     pub struct State;
@@ -34,6 +42,11 @@ diesel::table! {
         created_from -> Uuid,
         correct -> Bool,
         state -> AnswerState,
+        task_revision -> Int4,
+        score -> Float8,
+        max_score -> Float8,
+        seed -> Nullable<Int8>,
+        permutation -> Nullable<Array<Int4>>,
     }
 }
 
@@ -44,17 +57,62 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::State;
+
+    group_invitations (id) {
+        id -> Uuid,
+        group_id -> Uuid,
+        #[max_length = 16]
+        code -> Varchar,
+        #[max_length = 100]
+        email -> Nullable<Varchar>,
+        #[max_length = 45]
+        role -> Nullable<Varchar>,
+        max_uses -> Nullable<Int4>,
+        uses -> Int4,
+        expires_at -> Nullable<Timestamptz>,
+        state -> State,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        created_from -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::JoinRequestState;
+
+    group_join_requests (id) {
+        id -> Uuid,
+        group_id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 500]
+        message -> Nullable<Varchar>,
+        state -> JoinRequestState,
+        decided_at -> Nullable<Timestamptz>,
+        decided_from -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     group_members (id) {
         id -> Uuid,
         group_id -> Uuid,
         user_id -> Uuid,
+        joined_at -> Timestamptz,
+        #[max_length = 45]
+        role -> Nullable<Varchar>,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::State;
+    use super::sql_types::JoinPolicy;
 
     groups (id) {
         id -> Uuid,
@@ -66,6 +124,11 @@ diesel::table! {
         updated_at -> Timestamptz,
         created_from -> Uuid,
         updated_from -> Uuid,
+        deleted_at -> Nullable<Timestamptz>,
+        deleted_with -> Nullable<Uuid>,
+        join_policy -> JoinPolicy,
+        settings -> Jsonb,
+        search_text -> Text,
     }
 }
 
@@ -89,6 +152,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AccessType;
+
+    revoked_user_permissions (user_permission_id, access_type) {
+        user_permission_id -> Uuid,
+        access_type -> AccessType,
+        user_id -> Uuid,
+        #[max_length = 45]
+        ressource -> Varchar,
+        group_id -> Uuid,
+        permission -> Bool,
+        set_permission -> Bool,
+        set_set_permission -> Bool,
+        deleted_with -> Uuid,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::AccessType;
@@ -138,6 +219,8 @@ diesel::table! {
         visibility -> Visibility,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        score -> Float8,
+        max_score -> Float8,
     }
 }
 
@@ -155,6 +238,7 @@ diesel::table! {
         task_package_type -> TaskPackageType,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        deleted_with -> Nullable<Uuid>,
     }
 }
 
@@ -171,6 +255,8 @@ diesel::table! {
         state -> State,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        task_revision -> Int4,
+        points -> Float8,
     }
 }
 
@@ -218,9 +304,15 @@ diesel::table! {
 diesel::joinable!(answers -> solution_attempts (solution_attempt_id));
 diesel::joinable!(answers -> tasks (task_id));
 diesel::joinable!(answers -> users (created_from));
+diesel::joinable!(group_invitations -> groups (group_id));
+diesel::joinable!(group_invitations -> roles (role));
+diesel::joinable!(group_invitations -> users (created_from));
+diesel::joinable!(group_join_requests -> groups (group_id));
 diesel::joinable!(group_members -> groups (group_id));
 diesel::joinable!(group_members -> users (user_id));
 diesel::joinable!(ressource_access_types -> ressources (ressource));
+diesel::joinable!(revoked_user_permissions -> ressources (ressource));
+diesel::joinable!(revoked_user_permissions -> users (user_id));
 diesel::joinable!(role_access_types -> role_permissions (role_permission_id));
 diesel::joinable!(role_permissions -> ressources (ressource));
 diesel::joinable!(role_permissions -> roles (role));
@@ -236,10 +328,13 @@ diesel::joinable!(user_permissions -> users (user_id));
 diesel::allow_tables_to_appear_in_same_query!(
     answers,
     group_ancestors,
+    group_invitations,
+    group_join_requests,
     group_members,
     groups,
     ressource_access_types,
     ressources,
+    revoked_user_permissions,
     role_access_types,
     role_permissions,
     roles,
@@ -57,7 +57,7 @@ fn create_ressources(permission_repo: PermissionsRepo) -> Vec<(Ressource, Vec<Ac
         key_name: &"Group".to_string(),
         key_value: &"group".to_string(),
     }).unwrap();
-    let perm_1_access_types = &vec![AccessType::Create, AccessType::Read, AccessType::Delete];
+    let perm_1_access_types = &vec![AccessType::Create, AccessType::Read, AccessType::Write, AccessType::Delete];
     for access_type in perm_1_access_types {
         permission_repo.add_ressource_access_type(access_type, &perm_1.key_value).unwrap();
     }
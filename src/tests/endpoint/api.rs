@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod api_tests {
+    use actix_web::{test::TestRequest, http};
+    use serde_json::Value;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, groups::NewUserPermission, permissions::{NewRessource, OptionalUserAccessType}}};
+
+    /// # Test: `test_users_scope_repositories`
+    ///
+    /// Validates that the `/users` scope registers the repositories `add_permissions_to_user` needs when it is
+    /// routed like the server in `main`.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user who may set the `Write` permission of the `permission` ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `add_permissions_to_user` through the routes of `api::config`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "CREATED" (201).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_users_scope_repositories() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Permission".to_string(),
+                key_value: &"permission".to_string(),
+            }, vec![AccessType::Write])],
+        );
+
+        util::create_permissions_for_user(&test_app.group_repo, &vec![(NewUserPermission {
+            user_id: created_user.id,
+            group_id: None,
+            ressource: "permission".to_string()
+        }, vec![OptionalUserAccessType {
+            access_type: AccessType::Write,
+            permission: Some(false),
+            set_permission: Some(true),
+            set_set_permission: Some(false),
+        }])]);
+
+        let request_body = serde_json::json!({
+            "new_permissions": [{
+                "value": "permission",
+                "permission_addons": [{ "access_type": "Write", "permission": true }]
+            }]
+        });
+
+        let resp = test_app
+            .call_api(
+                &format!("/api/users/{}/permissions", created_user.id),
+                test_app.valid_authorizate(TestRequest::post().set_json(&request_body), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+    }
+
+    /// # Test: `test_tasks_scope_repositories`
+    ///
+    /// Validates that the `/tasks` scope registers the repositories `update_task` needs when it is routed like the
+    /// server in `main`, so updating a deleted task is rejected as "NOT FOUND" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_tasks_scope_repositories() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Write);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        test_app.app_state.mongodb.delete_task(&task.id).await.unwrap();
+
+        let request_body: Value = serde_json::json!({
+            "task": { "question": "Wie viele Bits ergeben ein Nibble?", "answers": ["4", "8"] },
+            "solution": { "solution": 0 }
+        });
+
+        let resp = test_app
+            .call_api(
+                &format!("/api/tasks/{}/", task.id),
+                test_app.valid_authorizate(TestRequest::patch().set_json(&request_body), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod fetch_answer_task_tests {
+    use std::collections::HashMap;
+
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, task::{NewTempTask, TaskResponse, TaskMetadata}}};
+
+    static SCOPE: &'static str = "/api/groups/{group_id}/answers/{answer_id}"; 
+    
+    fn get_path(group_id: &Uuid, answer_id: &Uuid) -> String {
+        format!("/api/groups/{}/answers/{}/task", group_id, answer_id)
+    }
+
+    /// # Test: `fetch_answer_task_template`
+    ///
+    /// Validates that the task of an answer to a task template is the instance generated from the seed of the answer.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the required permissions for reading answers in an example group.
+    ///    - Adds a multiple-choice template to a task package.
+    ///    - Starts a solution attempt with a fixed seed for the template.
+    ///
+    /// 2. Execution:
+    ///    - Calls `fetch_answer_task` for the answer to the template.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the response contains the instance of the seed without placeholders and parameters.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn fetch_answer_task_template() {
+        use crate::handlers::groups::group_id::answers::answer_id::answer_id::fetch_answer_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "user_answer", &created_user.id, &AccessType::Read);
+
+        let task = util::create_task_template_mc(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let seeds = HashMap::from([(task.id, 1234)]);
+
+        let solution_attempt = test_app.group_repo.create_solution_attempt(&created_user.id, &task_package.id, &created_groups[0].id, &None, &seeds, &HashMap::new()).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_list[0].answer_id),
+                SCOPE,
+                fetch_answer_task,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskResponse = test::read_body_json(resp).await;
+
+        let instance = test_app.app_state.generators.instantiate(&task, 1234, &test_app.app_state.graders).unwrap();
+
+        assert_eq!(response.id, task.id);
+        assert_eq!(response.task, instance.task);
+        assert!(!response.task.get_str("question").unwrap().contains("{{"));
+        assert!(response.parameters.is_none());
+    }
+
+    /// # Test: `fetch_answer_task_forbidden`
+    ///
+    /// Validates that users without the `Other` permission cannot fetch the task of the answer of another user
+    /// and get "FORBIDDEN" (403).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn fetch_answer_task_forbidden() {
+        use crate::handlers::groups::group_id::answers::answer_id::answer_id::fetch_answer_task;
+
+        let test_app = TestRepo::new().await;
+
+        let me = util::create_other_test_user(&test_app.user_repo);
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "user_answer", &me.id, &AccessType::Read);
+
+        let task = util::create_task_template_mc(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let seeds = HashMap::from([(task.id, 1234)]);
+
+        let solution_attempt = test_app.group_repo.create_solution_attempt(&created_user.id, &task_package.id, &created_groups[0].id, &None, &seeds, &HashMap::new()).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_list[0].answer_id),
+                SCOPE,
+                fetch_answer_task,
+                test_app.valid_authorizate(TestRequest::get(), &me.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    /// # Test: `fetch_answer_task_shuffled_options`
+    ///
+    /// Validates that the options of a task shuffling them are returned in the order stored with the answer.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the required permissions for reading answers in an example group.
+    ///    - Adds a multiple-choice task shuffling its options to a task package.
+    ///    - Starts a solution attempt showing the options in reverse order.
+    ///
+    /// 2. Execution:
+    ///    - Calls `fetch_answer_task` for the answer to the task.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the options are in reverse order.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn fetch_answer_task_shuffled_options() {
+        use mongodb::bson::doc;
+
+        use crate::handlers::groups::group_id::answers::answer_id::answer_id::fetch_answer_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "user_answer", &created_user.id, &AccessType::Read);
+
+        let task = test_app.app_state.mongodb.create_task("Multiple-Choice".to_string(), doc!{
+            "question": "Wie viele Bits ergeben ein Byte?",
+            "answers": ["8", "4", "2"],
+            "shuffle": true
+        }, doc!{
+            "solution": 0
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let permutations = HashMap::from([(task.id, vec![2, 1, 0])]);
+
+        let solution_attempt = test_app.group_repo.create_solution_attempt(&created_user.id, &task_package.id, &created_groups[0].id, &None, &HashMap::new(), &permutations).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_list[0].answer_id),
+                SCOPE,
+                fetch_answer_task,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.task.get_array("answers").unwrap(), &vec!["2".into(), "4".into(), "8".into()]);
+    }
+}
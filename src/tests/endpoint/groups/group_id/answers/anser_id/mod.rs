@@ -1,2 +1,3 @@
 pub mod fetch_answer;
+pub mod fetch_answer_task;
 pub mod update_answer;
\ No newline at end of file
@@ -301,4 +301,69 @@ mod update_answer_tests {
         .await;
         assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
     }
-}
\ No newline at end of file
+
+    /// # Test: `update_answer_automaton_undefined_state`
+    ///
+    /// Validates that answers to finite-automaton tasks using states they do not define are rejected with
+    /// "BAD REQUEST" (400), listing the invalid parts of the automaton.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the required permissions for updating answers in an example group.
+    ///    - Creates a task package with a finite-automaton task, its shipped schema and a solution attempt.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_answer` with an automaton whose transition leads to an undefined state.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "BAD_REQUEST" (400) and the error points to the target of the transition.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn update_answer_automaton_undefined_state() {
+        use crate::{handlers::groups::group_id::answers::answer_id::answer_id::update_answer, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "user_answer", &created_user.id, &AccessType::Write);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Finite-Automaton").await;
+
+        let task = util::create_task_automaton(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &solution_attempt.solution_list[0].answer_id),
+            SCOPE,
+            update_answer,
+            test_app.valid_authorizate(TestRequest::patch().set_json(serde_json::json!({
+                "solution": {
+                    "states": ["g"],
+                    "alphabet": ["a", "b"],
+                    "transitions": [
+                        { "from": "g", "symbol": "a", "to": "u" },
+                        { "from": "g", "symbol": "b", "to": "g" },
+                    ],
+                    "start": "g",
+                    "accepting": ["g"],
+                }
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].instance_path, "/transitions/0/to");
+    }
+}
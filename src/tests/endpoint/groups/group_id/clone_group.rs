@@ -0,0 +1,350 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod clone_group_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, NewGroup, CreateGroup, CloneGroupResponse}, util::{AccessType, JoinPolicy}, task::{NewTempTask, TaskPoints}, group_settings::GroupSettings}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/clone", group_id)
+    }
+
+    /// Creates a chain of groups where every group is the parent of the next one.
+    fn create_group_chain(group_repo: &GroupRepo, user_id: &Uuid, names: &[&str]) -> Vec<CreateGroup> {
+        let mut groups: Vec<CreateGroup> = vec![];
+
+        for name in names {
+            let parent = groups.last().map(|group| group.id);
+            groups.push(group_repo.create_group(&NewGroup {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                created_from: *user_id,
+                updated_from: *user_id,
+                parent,
+            }).unwrap());
+        }
+
+        groups
+    }
+
+    fn create_group_permissions(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>, access_types: Vec<AccessType>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read, AccessType::Write, AccessType::Create])
+            ],
+        );
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &vec![(NewUserPermission {
+                    user_id: *user_id,
+                    group_id,
+                    ressource: "group".to_string()
+                },
+                access_types.into_iter().map(|access_type| OptionalUserAccessType {
+                    access_type,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }).collect())
+            ],
+        );
+    }
+
+    fn create_tasks(count: usize) -> Vec<NewTempTask> {
+        (0..count).map(|_| NewTempTask {
+            task_doc_id: Uuid::new_v4(),
+            task_type: "multiple_choice".to_string(),
+        }).collect()
+    }
+
+    /// # Test: `test_clone_group`
+    ///
+    /// Validates that a group is copied with its subtree, task packages, tasks and settings, but without members.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child > Grandchild` with task packages in `Root` and `Child`.
+    ///    - Pins a later revision and other points for the first task of `Root`.
+    ///    - Adds a member and settings to `Root` and grants the user the global `Read`, `Write` and `Create` access types.
+    ///
+    /// 2. Execution:
+    ///    - Clones `Root` with its subgroups to the top level under a new name.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "CREATED" (201) and the id mapping covers all groups, task packages and tasks.
+    ///    - Verifies the copied hierarchy, the name, the settings and the task documents of the copies.
+    ///    - Verifies the copied tasks keep the revision and the points of the originals.
+    ///    - Verifies the copy has no members.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_clone_group() {
+        use crate::handlers::groups::group_id::group_id::clone_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child", "Grandchild"]);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read, AccessType::Write, AccessType::Create]);
+
+        let root_tasks = create_tasks(2);
+        let root_task_package = util::create_task_package(&test_app.group_repo, &root_tasks, &groups[0].id);
+        let child_task_package = util::create_task_package(&test_app.group_repo, &create_tasks(1), &groups[1].id);
+
+        test_app.group_repo.update_task_revision(&root_tasks[0].task_doc_id, &3).unwrap();
+        test_app.group_repo.update_task_points(&root_task_package.id, &groups[0].id, &[TaskPoints {
+            task_doc_id: root_tasks[0].task_doc_id,
+            points: 2.5,
+        }]).unwrap();
+
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &groups[0].id);
+        util::set_join_policy(&test_app.group_repo, &groups[0].id, JoinPolicy::Open);
+
+        let settings = GroupSettings {
+            description: Some("Automata theory".to_string()),
+            catalogue_visible: true,
+            ..Default::default()
+        };
+        test_app.group_repo.update_group_settings(&groups[0].id, &settings, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                "name": "Root SS24",
+                "include_subgroups": true,
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: CloneGroupResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.groups.len(), 3);
+        assert_eq!(response.task_packages.len(), 2);
+        assert_eq!(response.tasks.len(), 3);
+        assert_eq!(response.groups[&groups[0].id], response.id);
+
+        let root_copy = test_app.group_repo.fetch_active_group_info(response.id).unwrap();
+        assert_eq!(root_copy.name, "Root SS24");
+        assert_eq!(root_copy.parent, None);
+
+        let child_copy = test_app.group_repo.fetch_active_group_info(response.groups[&groups[1].id]).unwrap();
+        assert_eq!(child_copy.name, "Child");
+        assert_eq!(child_copy.parent, Some(response.id));
+
+        let grandchild_copy = test_app.group_repo.fetch_active_group_info(response.groups[&groups[2].id]).unwrap();
+        assert_eq!(grandchild_copy.parent, Some(child_copy.id));
+
+        assert_eq!(test_app.group_repo.fetch_group_settings(&response.id).unwrap(), settings);
+        assert_eq!(test_app.group_repo.fetch_active_group_meta_data(response.id).unwrap().join_policy, JoinPolicy::Open);
+        assert!(test_app.group_repo.fetch_roster(&response.id).unwrap().is_empty());
+
+        let copied_tasks = test_app.group_repo.fetch_tasks_from_package(
+            &response.task_packages[&root_task_package.id],
+            &response.id,
+            &None,
+        ).unwrap();
+
+        let mut copied_task_docs: Vec<Uuid> = copied_tasks.iter().map(|task| task.task_doc_id).collect();
+        let mut original_task_docs: Vec<Uuid> = root_tasks.iter().map(|task| task.task_doc_id).collect();
+        copied_task_docs.sort();
+        original_task_docs.sort();
+        assert_eq!(copied_task_docs, original_task_docs);
+        assert!(copied_tasks.iter().all(|task| response.tasks.values().any(|copy_id| *copy_id == task.id)));
+
+        for task in &copied_tasks {
+            if task.task_doc_id == root_tasks[0].task_doc_id {
+                assert_eq!(task.task_revision, 3);
+                assert_eq!(task.points, 2.5);
+            } else {
+                assert_eq!(task.task_revision, 1);
+                assert_eq!(task.points, 1.0);
+            }
+        }
+
+        assert_eq!(
+            test_app.group_repo.fetch_task_packages(&child_copy.id).unwrap()[0].id,
+            response.task_packages[&child_task_package.id]
+        );
+    }
+
+    /// # Test: `test_clone_group_without_subgroups`
+    ///
+    /// Validates the defaults of a copy: it is placed next to the group, without subgroups, but with task packages.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child > Grandchild` with a task package in `Child`.
+    ///    - Grants the user the `Read` and `Write` access types scoped to `Root`.
+    ///
+    /// 2. Execution:
+    ///    - Clones `Child` without a body and once more without task packages.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies both responses have the status "CREATED" (201).
+    ///    - Verifies the copies are children of `Root` and `Grandchild` was not copied.
+    ///    - Verifies only the first copy contains the task package.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_clone_group_without_subgroups() {
+        use crate::handlers::groups::group_id::group_id::clone_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child", "Grandchild"]);
+
+        create_group_permissions(&test_app, &created_user.id, Some(groups[0].id), vec![AccessType::Read, AccessType::Write]);
+
+        util::create_task_package(&test_app.group_repo, &create_tasks(1), &groups[1].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[1].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: CloneGroupResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.groups.len(), 1);
+        assert_eq!(response.task_packages.len(), 1);
+        assert_eq!(response.tasks.len(), 1);
+
+        let copy = test_app.group_repo.fetch_active_group_info(response.id).unwrap();
+        assert_eq!(copy.name, "Child");
+        assert_eq!(copy.parent, Some(groups[0].id));
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[1].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                "include_task_packages": false,
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: CloneGroupResponse = test::read_body_json(resp).await;
+
+        assert!(response.task_packages.is_empty());
+        assert!(test_app.group_repo.fetch_task_packages(&response.id).unwrap().is_empty());
+    }
+
+    /// # Test: `test_clone_group_to_top_level_forbidden`
+    ///
+    /// Validates that a copy can only be created on the top level with the global `Create` access type.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and grants the user the `Read` and `Write` access types scoped to it.
+    ///
+    /// 2. Execution:
+    ///    - Clones the group, which places the copy on the top level.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "FORBIDDEN" (403) and no group was created.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_clone_group_to_top_level_forbidden() {
+        use crate::handlers::groups::group_id::group_id::clone_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_permissions(&test_app, &created_user.id, Some(groups[0].id), vec![AccessType::Read, AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    /// # Test: `test_clone_group_read_only_forbidden`
+    ///
+    /// Validates that reading a group is not enough to copy it.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and grants the user the global `Read` and `Create` access types.
+    ///
+    /// 2. Execution:
+    ///    - Clones the group to the top level.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "FORBIDDEN" (403).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_clone_group_read_only_forbidden() {
+        use crate::handlers::groups::group_id::group_id::clone_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read, AccessType::Create]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    /// # Test: `test_clone_deleted_group`
+    ///
+    /// Validates that a deleted group cannot be cloned.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_clone_deleted_group() {
+        use crate::handlers::groups::group_id::group_id::clone_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read, AccessType::Write, AccessType::Create]);
+
+        test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            clone_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -1 +1,227 @@
-// TODO: Test for delete group
\ No newline at end of file
+//docu written with chat-gpt
+#[cfg(test)]
+mod delete_group_tests {
+    use actix_web::{test::TestRequest, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, NewGroup, CreateGroup}, util::AccessType}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/", group_id)
+    }
+
+    /// Creates a chain of groups where every group is the parent of the next one.
+    fn create_group_chain(group_repo: &GroupRepo, user_id: &Uuid, names: &[&str]) -> Vec<CreateGroup> {
+        let mut groups: Vec<CreateGroup> = vec![];
+
+        for name in names {
+            let parent = groups.last().map(|group| group.id);
+            groups.push(group_repo.create_group(&NewGroup {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                created_from: *user_id,
+                updated_from: *user_id,
+                parent,
+            }).unwrap());
+        }
+
+        groups
+    }
+
+    fn create_group_ressource(test_app: &TestRepo) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read, AccessType::Delete])
+            ],
+        );
+    }
+
+    fn create_group_permissions(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>, access_types: Vec<AccessType>) {
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: *user_id,
+                group_id,
+                ressource: "group".to_string()
+            },
+            access_types.into_iter().map(|access_type| OptionalUserAccessType {
+                access_type,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }).collect())
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+    }
+
+    /// # Test: `test_delete_group_cascades`
+    ///
+    /// Validates that deleting a group deletes its whole subtree, its task packages and revokes group scoped permissions.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child > Grandchild` and a task package in `Child`.
+    ///    - Grants the user a global `Delete` permission and another user a `Read` permission scoped to `Child`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `delete_group` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "No Content" (204).
+    ///    - Verifies all three groups and the task package are no longer active.
+    ///    - Verifies the other user lost the permission on `Child`.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_delete_group_cascades() {
+        use crate::handlers::groups::group_id::group_id::delete_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child", "Grandchild"]);
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![], &groups[1].id);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+        create_group_permissions(&test_app, &other_user.id, Some(groups[1].id), vec![AccessType::Read]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            delete_group,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        for group in &groups {
+            assert!(test_app.group_repo.fetch_active_group_info(group.id).is_err());
+        }
+
+        assert!(test_app.group_repo.fetch_task_packages(&groups[1].id).unwrap().is_empty());
+        assert!(!test_app.group_repo.is_task_package_active(&task_package.id, &groups[1].id).unwrap());
+
+        let access_types = test_app.permission_repo.user_has_permission(
+            &other_user.id,
+            &"group".to_string(),
+            &Some(groups[1].id),
+        ).unwrap();
+        assert!(access_types.is_empty());
+    }
+
+    /// # Test: `test_delete_subgroup`
+    ///
+    /// Validates that deleting a subgroup leaves its parent untouched.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child` and a global `Delete` permission.
+    ///
+    /// 2. Execution:
+    ///    - Calls `delete_group` for `Child`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "No Content" (204).
+    ///    - Verifies `Root` is still active while `Child` is deleted.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_delete_subgroup() {
+        use crate::handlers::groups::group_id::group_id::delete_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[1].id),
+            SCOPE,
+            delete_group,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        assert!(test_app.group_repo.fetch_active_group_info(groups[0].id).is_ok());
+        assert!(test_app.group_repo.fetch_active_group_info(groups[1].id).is_err());
+    }
+
+    /// # Test: `test_delete_not_existing_group`
+    ///
+    /// Validates that deleting a group that does not exist returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_delete_not_existing_group() {
+        use crate::handlers::groups::group_id::group_id::delete_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        let resp = test_app
+        .call(
+            &get_path(&Uuid::new_v4()),
+            SCOPE,
+            delete_group,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_delete_group_no_permission`
+    ///
+    /// Validates that a user without the `Delete` access type cannot delete a group.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and grants the user only a global `Read` permission.
+    ///
+    /// 2. Execution:
+    ///    - Calls `delete_group` for the group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Forbidden" (403) and the group is still active.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_delete_group_no_permission() {
+        use crate::handlers::groups::group_id::group_id::delete_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            delete_group,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        assert!(test_app.group_repo.fetch_active_group_info(groups[0].id).is_ok());
+    }
+}
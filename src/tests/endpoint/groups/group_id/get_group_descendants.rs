@@ -0,0 +1,241 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_group_descendants_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, NewGroup, CreateGroup, GroupDescendantsResponse}, util::AccessType}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid, query: &str) -> String {
+        format!("/api/groups/{}/descendants{}", group_id, query)
+    }
+
+    fn create_group(group_repo: &GroupRepo, user_id: &Uuid, name: &str, parent: Option<Uuid>) -> CreateGroup {
+        group_repo.create_group(&NewGroup {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            created_from: *user_id,
+            updated_from: *user_id,
+            parent,
+        }).unwrap()
+    }
+
+    /// Creates the hierarchy `Root > (B > (A, D)), C` and returns it in the order root, a, b, c, d.
+    fn create_hierarchy(group_repo: &GroupRepo, user_id: &Uuid) -> (CreateGroup, CreateGroup, CreateGroup, CreateGroup, CreateGroup) {
+        let root = create_group(group_repo, user_id, "Root", None);
+        let c = create_group(group_repo, user_id, "C", Some(root.id));
+        let b = create_group(group_repo, user_id, "B", Some(root.id));
+        let d = create_group(group_repo, user_id, "D", Some(b.id));
+        let a = create_group(group_repo, user_id, "A", Some(b.id));
+
+        (root, a, b, c, d)
+    }
+
+    fn create_read_permissions(test_app: &TestRepo, user_id: &Uuid, group_ids: Vec<Option<Uuid>>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = group_ids.into_iter().map(|group_id| (NewUserPermission {
+                user_id: *user_id,
+                group_id,
+                ressource: "group".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }])
+        ).collect();
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+    }
+
+    /// # Test: `test_get_group_descendants`
+    ///
+    /// Validates that all descendants of a group are returned as flat list ordered by depth and name.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > (A, D)), C` and a global `Read` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_descendants` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the order `B`, `C`, `A`, `D`, the depths and that the root is not part of the list.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_descendants() {
+        use crate::handlers::groups::group_id::group_id::get_group_descendants;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, a, b, c, d) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_descendants,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupDescendantsResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 4);
+        assert_eq!(
+            response.groups.iter().map(|group| (group.id, group.depth)).collect::<Vec<(Uuid, i32)>>(),
+            vec![(b.id, 1), (c.id, 1), (a.id, 2), (d.id, 2)]
+        );
+        assert_eq!(response.groups[2].parent, Some(b.id));
+    }
+
+    /// # Test: `test_get_group_descendants_depth_and_paging`
+    ///
+    /// Validates that the depth limit is applied before paging.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > (A, D)), C` and a global `Read` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_descendants` for `Root` with a depth of 1, a limit of 1 and page 1.
+    ///    - Calls `get_group_descendants` for `B`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first call only returns `C` with a total count of 2.
+    ///    - Verifies the descendants of `B` have a depth relative to `B`.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_descendants_depth_and_paging() {
+        use crate::handlers::groups::group_id::group_id::get_group_descendants;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, a, b, c, d) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, "?depth=1&limit=1&page=1"),
+            SCOPE,
+            get_group_descendants,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupDescendantsResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.groups.len(), 1);
+        assert_eq!(response.groups[0].id, c.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&b.id, ""),
+            SCOPE,
+            get_group_descendants,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupDescendantsResponse = test::read_body_json(resp).await;
+
+        assert_eq!(
+            response.groups.iter().map(|group| (group.id, group.depth)).collect::<Vec<(Uuid, i32)>>(),
+            vec![(a.id, 1), (d.id, 1)]
+        );
+    }
+
+    /// # Test: `test_get_group_descendants_filtered_by_read_rights`
+    ///
+    /// Validates that descendants in branches without read rights are left out.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > (A, D)), C`.
+    ///    - Grants group scoped `Read` permissions on `Root`, `B` and `A`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_descendants` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies only `B` and `A` are returned.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_descendants_filtered_by_read_rights() {
+        use crate::handlers::groups::group_id::group_id::get_group_descendants;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, a, b, _, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![Some(root.id), Some(b.id), Some(a.id)]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_descendants,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupDescendantsResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        assert_eq!(
+            response.groups.iter().map(|group| group.id).collect::<Vec<Uuid>>(),
+            vec![b.id, a.id]
+        );
+    }
+
+    /// # Test: `test_get_not_existing_group_descendants`
+    ///
+    /// Validates that requesting the descendants of a group that does not exist returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_not_existing_group_descendants() {
+        use crate::handlers::groups::group_id::group_id::get_group_descendants;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&Uuid::new_v4(), ""),
+            SCOPE,
+            get_group_descendants,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
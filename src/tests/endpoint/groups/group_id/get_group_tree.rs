@@ -0,0 +1,405 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_group_tree_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, NewGroup, CreateGroup, GroupTreeResponse}, util::AccessType, task::NewTempTask}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid, query: &str) -> String {
+        format!("/api/groups/{}/tree{}", group_id, query)
+    }
+
+    fn create_group(group_repo: &GroupRepo, user_id: &Uuid, name: &str, parent: Option<Uuid>) -> CreateGroup {
+        group_repo.create_group(&NewGroup {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            created_from: *user_id,
+            updated_from: *user_id,
+            parent,
+        }).unwrap()
+    }
+
+    /// Creates the hierarchy `Root > (B > D), C` and returns it in the order root, b, c, d.
+    fn create_hierarchy(group_repo: &GroupRepo, user_id: &Uuid) -> (CreateGroup, CreateGroup, CreateGroup, CreateGroup) {
+        let root = create_group(group_repo, user_id, "Root", None);
+        let c = create_group(group_repo, user_id, "C", Some(root.id));
+        let b = create_group(group_repo, user_id, "B", Some(root.id));
+        let d = create_group(group_repo, user_id, "D", Some(b.id));
+
+        (root, b, c, d)
+    }
+
+    fn create_read_permissions(test_app: &TestRepo, user_id: &Uuid, group_ids: Vec<Option<Uuid>>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = group_ids.into_iter().map(|group_id| (NewUserPermission {
+                user_id: *user_id,
+                group_id,
+                ressource: "group".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }])
+        ).collect();
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+    }
+
+    /// # Test: `test_get_group_tree`
+    ///
+    /// Validates that the whole subtree of a group is returned as nested tree.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > D), C` and a global `Read` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_tree` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the children are ordered by name, have the correct depth and no counts.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, b, c, d) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, root.id);
+        assert_eq!(response.depth, 0);
+        assert_eq!(response.member_count, None);
+        assert_eq!(response.task_package_count, None);
+        assert_eq!(response.children.len(), 2);
+
+        assert_eq!(response.children[0].id, b.id);
+        assert_eq!(response.children[0].parent, Some(root.id));
+        assert_eq!(response.children[0].depth, 1);
+        assert_eq!(response.children[1].id, c.id);
+        assert!(response.children[1].children.is_empty());
+
+        assert_eq!(response.children[0].children.len(), 1);
+        assert_eq!(response.children[0].children[0].id, d.id);
+        assert_eq!(response.children[0].children[0].depth, 2);
+    }
+
+    /// # Test: `test_get_group_tree_depth`
+    ///
+    /// Validates that the `depth` parameter limits the levels below the root.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > D), C` and a global `Read` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_tree` for `Root` with a depth of 1 and of 0.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies a depth of 1 only contains `B` and `C` without their children.
+    ///    - Verifies a depth of 0 only contains the root.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_depth() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, b, c, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, "?depth=1"),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.children.len(), 2);
+        assert_eq!(response.children[0].id, b.id);
+        assert!(response.children[0].children.is_empty());
+        assert_eq!(response.children[1].id, c.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, "?depth=0"),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, root.id);
+        assert!(response.children.is_empty());
+    }
+
+    /// # Test: `test_get_group_tree_negative_depth`
+    ///
+    /// Validates that a negative depth is rejected with "Bad Request" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_negative_depth() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, _, _, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, "?depth=-1"),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    /// # Test: `test_get_group_tree_with_counts`
+    ///
+    /// Validates that `with_counts` adds the amount of members and task packages to every group.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > D), C` and a global `Read` permission on the "group" ressource.
+    ///    - Adds two members to `B` and a task package to `Root`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_tree` for `Root` with `with_counts=true`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the counts of `Root`, `B` and `D`, including zero counts.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_with_counts() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let (root, b, _, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        util::add_member_to_group(&test_app.group_repo, &created_user.id, &b.id);
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &b.id);
+        util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: Uuid::new_v4(),
+            task_type: "Multiple-Choice".to_string(),
+        }], &root.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, "?with_counts=true"),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.member_count, Some(0));
+        assert_eq!(response.task_package_count, Some(1));
+        assert_eq!(response.children[0].member_count, Some(2));
+        assert_eq!(response.children[0].task_package_count, Some(0));
+        assert_eq!(response.children[0].children[0].member_count, Some(0));
+    }
+
+    /// # Test: `test_get_group_tree_filtered_by_read_rights`
+    ///
+    /// Validates that branches without read rights are left out of the tree.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > D), C`.
+    ///    - Grants group scoped `Read` permissions on `Root`, `C` and `D`, but not on `B`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_tree` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies `C` is the only child of the root, because `B` is not readable
+    ///      and `D` can only be reached through `B`.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_filtered_by_read_rights() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, b, c, d) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![Some(root.id), Some(c.id), Some(d.id)]);
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, root.id);
+        assert_eq!(response.children.len(), 1);
+        assert_eq!(response.children[0].id, c.id);
+        assert!(response.children.iter().all(|child| child.id != b.id));
+    }
+
+    /// # Test: `test_get_group_tree_skips_deleted_groups`
+    ///
+    /// Validates that deleted groups and their subgroups are not part of the tree.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `Root > (B > D), C` and a global `Read` permission on the "group" ressource.
+    ///    - Deletes `B`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_tree` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies only `C` is left as child of the root.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_skips_deleted_groups() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, b, c, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        test_app.group_repo.delete_group(&b.id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupTreeResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.children.len(), 1);
+        assert_eq!(response.children[0].id, c.id);
+    }
+
+    /// # Test: `test_get_not_existing_group_tree`
+    ///
+    /// Validates that requesting the tree of a group that does not exist returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_not_existing_group_tree() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        create_read_permissions(&test_app, &created_user.id, vec![None]);
+
+        let resp = test_app
+        .call(
+            &get_path(&Uuid::new_v4(), ""),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_get_group_tree_no_permission`
+    ///
+    /// Validates that a user without read rights on groups gets "Forbidden" (403).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_tree_no_permission() {
+        use crate::handlers::groups::group_id::group_id::get_group_tree;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let (root, _, _, _) = create_hierarchy(&test_app.group_repo, &created_user.id);
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let resp = test_app
+        .call(
+            &get_path(&root.id, ""),
+            SCOPE,
+            get_group_tree,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
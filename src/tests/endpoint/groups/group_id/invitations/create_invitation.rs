@@ -0,0 +1,212 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod create_invitation_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::NewUserPermission, util::{AccessType, State}, invitations::InvitationResponse, roles::NewRole}, repository::group::INVITATION_CODE_LENGTH};
+
+    static SCOPE: &str = "/api/groups/{group_id}/invitations";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/invitations/", group_id)
+    }
+
+    fn create_group_member_permission(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"GroupMember".to_string(),
+                key_value: &"group_member".to_string(),
+            }, vec![AccessType::Read, AccessType::Write])
+            ],
+        );
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &vec![(NewUserPermission {
+                    user_id: *user_id,
+                    group_id,
+                    ressource: "group_member".to_string()
+                },
+                vec![OptionalUserAccessType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }])
+            ],
+        );
+    }
+
+    /// # Test: `test_create_invitation`
+    ///
+    /// Validates that a user with the `Write` access type on group members can create an invitation.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group, a "tutor" role and a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_invitation` with an email, a role, a maximum of 5 uses and an expiry date.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201).
+    ///    - Verifies the generated code and that the email is stored lowercased.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_invitation() {
+        use crate::handlers::groups::group_id::invitations::invitations::create_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Tutor".to_string(),
+            value_key: &"tutor".to_string(),
+        }]);
+
+        let body: Value = serde_json::json!({
+            "email": " Student@Test.de ",
+            "role": "tutor",
+            "max_uses": 5,
+            "expires_at": "2099-01-01T00:00:00Z",
+        });
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(body), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: InvitationResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.group_id, created_groups[0].id);
+        assert_eq!(response.code.len(), INVITATION_CODE_LENGTH);
+        assert_eq!(response.email, Some("student@test.de".to_string()));
+        assert_eq!(response.role, Some("tutor".to_string()));
+        assert_eq!(response.max_uses, Some(5));
+        assert_eq!(response.uses, 0);
+        assert_eq!(response.state, State::Active);
+        assert_eq!(response.created_from, created_user.id);
+    }
+
+    /// # Test: `test_create_invitation_invalid_restrictions`
+    ///
+    /// Validates that invitations with a non positive maximum of uses, an expiry date in the past or
+    /// an unknown role are rejected with "Bad Request" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_invitation_invalid_restrictions() {
+        use crate::handlers::groups::group_id::invitations::invitations::create_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        for body in [
+            serde_json::json!({"max_uses": 0}),
+            serde_json::json!({"expires_at": "2000-01-01T00:00:00Z"}),
+            serde_json::json!({"role": "unknown"}),
+        ] {
+            let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id),
+                SCOPE,
+                create_invitation,
+                test_app.valid_authorizate(TestRequest::post().set_json(body), &created_user.id),
+            )
+            .await;
+            assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        }
+    }
+
+    /// # Test: `test_create_invitation_with_role_group_scoped`
+    ///
+    /// Validates that a user who may only manage the members of the group itself cannot hand out another role.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group, a "tutor" role and a `Write` permission on "group_member" scoped to the group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_invitation` once with the "tutor" role and once without a role.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response is "Forbidden" (403) and the second one "Created" (201).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_invitation_with_role_group_scoped() {
+        use crate::handlers::groups::group_id::invitations::invitations::create_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, Some(created_groups[0].id));
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Tutor".to_string(),
+            value_key: &"tutor".to_string(),
+        }]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"role": "tutor"})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+    }
+
+    /// # Test: `test_create_invitation_no_permission`
+    ///
+    /// Validates that a user without the `Write` access type on group members cannot create an invitation.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_invitation_no_permission() {
+        use crate::handlers::groups::group_id::invitations::invitations::create_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
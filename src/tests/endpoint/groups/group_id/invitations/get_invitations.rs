@@ -0,0 +1,102 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_invitations_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, State}, invitations::{InvitationListResponse, NewGroupInvitation}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/invitations";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/invitations/", group_id)
+    }
+
+    /// # Test: `test_get_invitations`
+    ///
+    /// Validates that all invitations of a group, including revoked ones, are listed.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, two groups and a global `Read` permission on the "group_member" ressource.
+    ///    - Creates two invitations for the first group and revokes one of them, and one invitation for the second group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_invitations` for the first group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies only the two invitations of the first group are returned, one of them revoked.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_invitations() {
+        use crate::handlers::groups::group_id::invitations::invitations::get_invitations;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Read);
+
+        let mut invitation_ids = vec![];
+        for group in [&created_groups[0], &created_groups[0], &created_groups[1]] {
+            invitation_ids.push(test_app.group_repo.create_invitation(&NewGroupInvitation {
+                group_id: group.id,
+                email: None,
+                role: None,
+                max_uses: None,
+                expires_at: None,
+                created_from: created_user.id,
+            }).unwrap().id);
+        }
+
+        test_app.group_repo.revoke_invitation(&created_groups[0].id, &invitation_ids[0]).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_invitations,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: InvitationListResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.invitations.len(), 2);
+        assert!(response.invitations.iter().all(|invitation| invitation.group_id == created_groups[0].id));
+
+        let revoked = response.invitations.iter().find(|invitation| invitation.id == invitation_ids[0]).unwrap();
+        assert_eq!(revoked.state, State::Deleted);
+
+        let active = response.invitations.iter().find(|invitation| invitation.id == invitation_ids[1]).unwrap();
+        assert_eq!(active.state, State::Active);
+    }
+
+    /// # Test: `test_get_invitations_no_permission`
+    ///
+    /// Validates that a user without the `Read` access type on group members cannot list the invitations of a group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_invitations_no_permission() {
+        use crate::handlers::groups::group_id::invitations::invitations::get_invitations;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_invitations,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_invitation;
+pub mod get_invitations;
+pub mod revoke_invitation;
\ No newline at end of file
@@ -0,0 +1,115 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod revoke_invitation_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, State}, invitations::{InvitationResponse, NewGroupInvitation}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/invitations";
+
+    fn get_path(group_id: &Uuid, invitation_id: &Uuid) -> String {
+        format!("/api/groups/{}/invitations/{}/", group_id, invitation_id)
+    }
+
+    /// # Test: `test_revoke_invitation`
+    ///
+    /// Validates that a revoked invitation can no longer be redeemed and cannot be revoked twice.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group, an invitation and a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `revoke_invitation` twice for the invitation.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response status is "OK" (200) and the invitation is marked as deleted.
+    ///    - Verifies the code can no longer be looked up.
+    ///    - Verifies the second response status is "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_revoke_invitation() {
+        use crate::handlers::groups::group_id::invitations::invitations::revoke_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let invitation = test_app.group_repo.create_invitation(&NewGroupInvitation {
+            group_id: created_groups[0].id,
+            email: None,
+            role: None,
+            max_uses: None,
+            expires_at: None,
+            created_from: created_user.id,
+        }).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &invitation.id),
+            SCOPE,
+            revoke_invitation,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: InvitationResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, invitation.id);
+        assert_eq!(response.state, State::Deleted);
+        assert!(test_app.group_repo.fetch_invitation_by_code(&invitation.code).is_err());
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &invitation.id),
+            SCOPE,
+            revoke_invitation,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_revoke_invitation_of_other_group`
+    ///
+    /// Validates that an invitation cannot be revoked through the path of another group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_revoke_invitation_of_other_group() {
+        use crate::handlers::groups::group_id::invitations::invitations::revoke_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let invitation = test_app.group_repo.create_invitation(&NewGroupInvitation {
+            group_id: created_groups[0].id,
+            email: None,
+            role: None,
+            max_uses: None,
+            expires_at: None,
+            created_from: created_user.id,
+        }).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[1].id, &invitation.id),
+            SCOPE,
+            revoke_invitation,
+            test_app.valid_authorizate(TestRequest::delete(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        assert!(test_app.group_repo.fetch_invitation_by_code(&invitation.code).is_ok());
+    }
+}
@@ -0,0 +1,175 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod approve_join_request_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, JoinRequestState}, join_requests::{JoinRequestResponse, NewGroupJoinRequest, JoinRequestInfo}, roles::{NewRole, UpdateRolePermission, NewRolePermission, UpdateRoleAccesType}}, repository::group::ADD_MEMBER_ROLE_KEY};
+
+    static SCOPE: &str = "/api/groups/{group_id}/join_requests";
+
+    fn get_path(group_id: &Uuid, join_request_id: &Uuid) -> String {
+        format!("/api/groups/{}/join_requests/{}/approve", group_id, join_request_id)
+    }
+
+    fn create_pending_join_request(test_app: &TestRepo, group_id: &Uuid, user_id: &Uuid) -> JoinRequestInfo {
+        test_app.group_repo.create_join_request(&NewGroupJoinRequest {
+            group_id: *group_id,
+            user_id: *user_id,
+            message: None,
+            state: JoinRequestState::Pending,
+            decided_at: None,
+        }).unwrap()
+    }
+
+    /// # Test: `test_approve_join_request`
+    ///
+    /// Validates that approving a join request adds the user to the group with the default member role.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a global `Write` permission on the "group_member" ressource for the first user.
+    ///    - Creates the default member role which grants `Write` on "group_member" and a pending request of the second user.
+    ///
+    /// 2. Execution:
+    ///    - Calls `approve_join_request` twice as the first user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response status is "OK" (200) and the request is approved by the first user.
+    ///    - Verifies the second user is a member with the permissions of the role.
+    ///    - Verifies the second response status is "Conflict" (409).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_approve_join_request() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::approve_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Member".to_string(),
+            value_key: &ADD_MEMBER_ROLE_KEY.to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "group_member".to_string(),
+                role: ADD_MEMBER_ROLE_KEY.to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+
+        let join_request = create_pending_join_request(&test_app, &created_groups[0].id, &other_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &join_request.id),
+            SCOPE,
+            approve_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: JoinRequestResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, join_request.id);
+        assert_eq!(response.state, JoinRequestState::Approved);
+        assert_eq!(response.decided_from, Some(created_user.id));
+        assert!(response.decided_at.is_some());
+
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+
+        let access_types = test_app.permission_repo.user_has_permission(
+            &other_user.id,
+            &"group_member".to_string(),
+            &Some(created_groups[0].id),
+        ).unwrap();
+        assert_eq!(access_types, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &join_request.id),
+            SCOPE,
+            approve_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+    }
+
+    /// # Test: `test_approve_join_request_of_other_group`
+    ///
+    /// Validates that a join request cannot be approved through the path of another group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_approve_join_request_of_other_group() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::approve_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let join_request = create_pending_join_request(&test_app, &created_groups[0].id, &other_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[1].id, &join_request.id),
+            SCOPE,
+            approve_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[1].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_approve_join_request_no_permission`
+    ///
+    /// Validates that a user without the `Write` access type on group members cannot approve join requests,
+    /// in particular not their own.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_approve_join_request_no_permission() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::approve_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let join_request = create_pending_join_request(&test_app, &created_groups[0].id, &other_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &join_request.id),
+            SCOPE,
+            approve_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+}
@@ -0,0 +1,205 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod create_join_request_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{JoinPolicy, JoinRequestState}, join_requests::JoinRequestResponse}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/join_requests";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/join_requests/", group_id)
+    }
+
+    /// # Test: `test_create_join_request`
+    ///
+    /// Validates that a user can request to join a group with the "Request" join policy.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group of the first user with the "Request" join policy.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_join_request` as the second user with a message.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201) and the request is pending.
+    ///    - Verifies the user is not a member of the group yet.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_join_request() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::create_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::set_join_policy(&test_app.group_repo, &created_groups[0].id, JoinPolicy::Request);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_join_request,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"message": " Please let me in "})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: JoinRequestResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.group_id, created_groups[0].id);
+        assert_eq!(response.user.id, other_user.id);
+        assert_eq!(response.message, Some("Please let me in".to_string()));
+        assert_eq!(response.state, JoinRequestState::Pending);
+        assert_eq!(response.decided_at, None);
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_create_join_request_open_group`
+    ///
+    /// Validates that a user joins a group with the "Open" join policy right away.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group of the first user with the "Open" join policy.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_join_request` as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201) and the request is approved.
+    ///    - Verifies the user is a member of the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_join_request_open_group() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::create_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::set_join_policy(&test_app.group_repo, &created_groups[0].id, JoinPolicy::Open);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_join_request,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: JoinRequestResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.state, JoinRequestState::Approved);
+        assert!(response.decided_at.is_some());
+
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_create_join_request_closed_group`
+    ///
+    /// Validates that groups keep the default "Closed" join policy and reject join requests with "Forbidden" (403).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_join_request_closed_group() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::create_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_join_request,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        assert!(test_app.group_repo.fetch_join_requests(&created_groups[0].id, None).unwrap().is_empty());
+    }
+
+    /// # Test: `test_create_join_request_twice`
+    ///
+    /// Validates that a user cannot have two pending join requests for the same group.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group with the "Request" join policy.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_join_request` twice as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the second response status is "Conflict" (409) and only one request exists.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_join_request_twice() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::create_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::set_join_policy(&test_app.group_repo, &created_groups[0].id, JoinPolicy::Request);
+
+        for expected_status in [http::StatusCode::CREATED, http::StatusCode::CONFLICT] {
+            let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id),
+                SCOPE,
+                create_join_request,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &other_user.id),
+            )
+            .await;
+            assert_eq!(resp.status(), expected_status);
+        }
+
+        assert_eq!(test_app.group_repo.fetch_join_requests(&created_groups[0].id, None).unwrap().len(), 1);
+    }
+
+    /// # Test: `test_create_join_request_already_member`
+    ///
+    /// Validates that a member of a group cannot request to join it again.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_join_request_already_member() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::create_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::set_join_policy(&test_app.group_repo, &created_groups[0].id, JoinPolicy::Request);
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            create_join_request,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,115 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_join_requests_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, JoinRequestState}, join_requests::{JoinRequestListResponse, NewGroupJoinRequest}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/join_requests";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/join_requests/", group_id)
+    }
+
+    /// # Test: `test_get_join_requests`
+    ///
+    /// Validates that a user with the `Write` access type on group members can list the join requests of a group.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, two groups and a global `Write` permission on the "group_member" ressource.
+    ///    - Creates a pending and a rejected join request for the first group and a pending one for the second group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_join_requests` for the first group without and with the `state` filter.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies both requests of the first group are listed without a filter and only the pending one with it.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_join_requests() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::get_join_requests;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let create_join_request = |group_id: Uuid| test_app.group_repo.create_join_request(&NewGroupJoinRequest {
+            group_id,
+            user_id: other_user.id,
+            message: None,
+            state: JoinRequestState::Pending,
+            decided_at: None,
+        }).unwrap();
+
+        let rejected = create_join_request(created_groups[0].id);
+        test_app.group_repo.decide_join_request(&created_groups[0].id, &rejected.id, JoinRequestState::Rejected, &created_user.id).unwrap();
+
+        create_join_request(created_groups[0].id);
+        create_join_request(created_groups[1].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_join_requests,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: JoinRequestListResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.join_requests.len(), 2);
+        assert!(response.join_requests.iter().all(|join_request| join_request.group_id == created_groups[0].id));
+        assert!(response.join_requests.iter().all(|join_request| join_request.user.id == other_user.id));
+
+        let resp = test_app
+        .call(
+            &format!("{}?state=Pending", get_path(&created_groups[0].id)),
+            SCOPE,
+            get_join_requests,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: JoinRequestListResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.join_requests.len(), 1);
+        assert_eq!(response.join_requests[0].state, JoinRequestState::Pending);
+    }
+
+    /// # Test: `test_get_join_requests_read_permission`
+    ///
+    /// Validates that the `Read` access type on group members is not enough to list the join requests of a group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_join_requests_read_permission() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::get_join_requests;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Read);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_join_requests,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_join_request;
+pub mod get_join_requests;
+pub mod approve_join_request;
+pub mod reject_join_request;
\ No newline at end of file
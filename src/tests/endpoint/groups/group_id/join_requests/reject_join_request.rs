@@ -0,0 +1,111 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod reject_join_request_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, JoinRequestState, JoinPolicy}, join_requests::{JoinRequestResponse, NewGroupJoinRequest}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/join_requests";
+
+    fn get_path(group_id: &Uuid, join_request_id: &Uuid) -> String {
+        format!("/api/groups/{}/join_requests/{}/reject", group_id, join_request_id)
+    }
+
+    /// # Test: `test_reject_join_request`
+    ///
+    /// Validates that rejecting a join request does not add the user and allows a new request afterwards.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group with the "Request" join policy and a global `Write` permission
+    ///      on the "group_member" ressource for the first user.
+    ///    - Creates a pending request of the second user.
+    ///
+    /// 2. Execution:
+    ///    - Calls `reject_join_request` twice as the first user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response status is "OK" (200) and the request is rejected.
+    ///    - Verifies the second user is not a member and can create a new pending request.
+    ///    - Verifies the second response status is "Conflict" (409).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_reject_join_request() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::reject_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::set_join_policy(&test_app.group_repo, &created_groups[0].id, JoinPolicy::Request);
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let new_join_request = NewGroupJoinRequest {
+            group_id: created_groups[0].id,
+            user_id: other_user.id,
+            message: None,
+            state: JoinRequestState::Pending,
+            decided_at: None,
+        };
+
+        let join_request = test_app.group_repo.create_join_request(&new_join_request).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &join_request.id),
+            SCOPE,
+            reject_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: JoinRequestResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.state, JoinRequestState::Rejected);
+        assert_eq!(response.decided_from, Some(created_user.id));
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+        assert!(test_app.group_repo.create_join_request(&new_join_request).is_ok());
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &join_request.id),
+            SCOPE,
+            reject_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+    }
+
+    /// # Test: `test_reject_not_existing_join_request`
+    ///
+    /// Validates that rejecting an unknown join request returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_reject_not_existing_join_request() {
+        use crate::handlers::groups::group_id::join_requests::join_requests::reject_join_request;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &Uuid::new_v4()),
+            SCOPE,
+            reject_join_request,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -5,7 +5,7 @@ mod add_member_to_group_tests {
     use serde_json::Value;
     use uuid::Uuid;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse}, util::{AccessType, PagingSchema, OrderDir}, task::TaskPagingResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, members::{MemberInfoResponse, MemberListResponse}}};
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse}, util::{AccessType, PagingSchema, OrderDir}, task::TaskPagingResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, members::{MemberInfoResponse, MemberListResponse, MemberBatchResponse, MemberEntryStatus}, group_settings::GroupSettings}};
 
     static SCOPE: &'static str = "/api/groups/{group_id}/members"; 
     
@@ -315,4 +315,292 @@ mod add_member_to_group_tests {
         assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
 
     }
+    /// # Test: `test_add_member_results`
+    ///
+    /// Validates that every entry of the request is reported with its status and the reason of failures.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group with the second user as member.
+    ///    - Sets up a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Adds the first user, an unknown email, the first user again and the member.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "CREATED" (201).
+    ///    - Verifies only the first user was added and the reasons of the failed entries.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_add_member_results() {
+        use crate::handlers::groups::group_id::members::members::add_member_to_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            add_member_to_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                "new_members": [created_user.email, "unknown@test.de", created_user.email, other_user.email],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members.len(), 1);
+        assert_eq!(response.members[0].user.id, created_user.id);
+        assert_eq!(response.failed_count, 3);
+
+        assert_eq!(response.results.len(), 4);
+        assert_eq!(response.results[0].status, MemberEntryStatus::Added);
+        assert_eq!(response.results[0].member.as_ref().unwrap().user.id, created_user.id);
+        assert_eq!(response.results[1].entry, "unknown@test.de");
+
+        let reasons: Vec<Option<String>> = response.results[1..].iter().map(|result| result.reason.clone()).collect();
+        assert_eq!(reasons, vec![
+            Some("unknown_email".to_string()),
+            Some("duplicate_entry".to_string()),
+            Some("already_member".to_string()),
+        ]);
+    }
+
+    /// # Test: `test_add_member_abort`
+    ///
+    /// Validates that with `on_error=abort` no member is added if one entry fails.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Adds both users and an unknown email with `on_error=abort`, then both users only.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response status is "BAD REQUEST" (400), the valid entries are skipped and no member was added.
+    ///    - Verifies the second response status is "CREATED" (201) and both users were added.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_add_member_abort() {
+        use crate::handlers::groups::group_id::members::members::add_member_to_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+
+        let path = format!("{}?on_error=abort", get_path(&created_groups[0].id));
+
+        let resp = test_app
+        .call(
+            &path,
+            SCOPE,
+            add_member_to_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                "new_members": [created_user.email, "unknown@test.de", other_user.email],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert!(response.members.is_empty());
+        assert_eq!(response.failed_count, 1);
+        assert_eq!(response.results[0].status, MemberEntryStatus::Skipped);
+        assert_eq!(response.results[1].reason, Some("unknown_email".to_string()));
+        assert_eq!(response.results[2].status, MemberEntryStatus::Skipped);
+        assert!(test_app.group_repo.fetch_roster(&created_groups[0].id).unwrap().is_empty());
+
+        let resp = test_app
+        .call(
+            &path,
+            SCOPE,
+            add_member_to_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                "new_members": [created_user.email, other_user.email],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members.len(), 2);
+        assert!(response.results.iter().all(|result| result.status == MemberEntryStatus::Added));
+        assert_eq!(test_app.group_repo.fetch_roster(&created_groups[0].id).unwrap().len(), 2);
+    }
+    /// # Test: `test_add_member_default_role`
+    ///
+    /// Validates that new members get the permissions of the default member role of the group settings.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a global `Write` permission on the "group_member" ressource.
+    ///    - Creates the role "tutor" with the `Write` access type on the "group" ressource and sets it as
+    ///      default member role of the group.
+    ///
+    /// 2. Execution:
+    ///    - Adds the second user to the group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "CREATED" (201).
+    ///    - Verifies the new member has the `Write` access type of the "tutor" role for the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_add_member_default_role() {
+        use crate::handlers::groups::group_id::members::members::add_member_to_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Write);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Tutor".to_string(),
+            value_key: &"tutor".to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "group".to_string(),
+                role: "tutor".to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+
+        test_app.group_repo.update_group_settings(&created_groups[0].id, &GroupSettings {
+            default_member_role: Some("tutor".to_string()),
+            ..Default::default()
+        }, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            add_member_to_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(create_body(other_user.email)), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let permissions = test_app.permission_repo
+        .fetch_user_permissions(&other_user.id, &PagingSchema {
+            limit: 200,
+            page: 0,
+            order: OrderDir::DESC,
+        }, &Some(created_groups[0].id), true, &None).unwrap();
+
+        assert_eq!(permissions.permission_list.len(), 1);
+        assert_eq!(permissions.permission_list[0].key_value, "group");
+        assert_eq!(permissions.permission_list[0].access_types[0].access_type, AccessType::Write);
+    }
+
+    /// # Test: `test_add_member_existing_group_permission`
+    ///
+    /// Validates that a user who already holds a permission for the group can be added as member.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a global `Write` permission on the "group_member" ressource.
+    ///    - Grants the second user the `Write` access type on the "group" ressource of the group.
+    ///    - Gives the "add_member" role the `Write` access type on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Adds the second user to the group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "CREATED" (201).
+    ///    - Verifies the member keeps a single "group" permission for the group with the `Write` access type.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_add_member_existing_group_permission() {
+        use crate::handlers::groups::group_id::members::members::add_member_to_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Write);
+
+        util::create_permissions_for_user(&test_app.group_repo, &vec![(NewUserPermission {
+            user_id: other_user.id,
+            group_id: Some(created_groups[0].id),
+            ressource: "group".to_string(),
+        }, vec![OptionalUserAccessType {
+            access_type: AccessType::Write,
+            permission: Some(true),
+            set_permission: None,
+            set_set_permission: None,
+        }])]);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Created Group".to_string(),
+            value_key: &"add_member".to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "group".to_string(),
+                role: "add_member".to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            add_member_to_group,
+            test_app.valid_authorizate(TestRequest::post().set_json(create_body(other_user.email)), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let permissions = test_app.permission_repo
+        .fetch_user_permissions(&other_user.id, &PagingSchema {
+            limit: 200,
+            page: 0,
+            order: OrderDir::DESC,
+        }, &Some(created_groups[0].id), true, &None).unwrap();
+
+        assert_eq!(permissions.permission_list.len(), 1);
+        assert_eq!(permissions.permission_list[0].key_value, "group");
+        assert_eq!(permissions.permission_list[0].access_types.len(), 1);
+        assert_eq!(permissions.permission_list[0].access_types[0].access_type, AccessType::Write);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod export_members_tests {
+    use actix_web::{test::{TestRequest, self}, http::{self, header::CONTENT_TYPE}};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::util::AccessType};
+
+    static SCOPE: &str = "/api/groups/{group_id}/members";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/members/export", group_id)
+    }
+
+    /// # Test: `test_export_members`
+    ///
+    /// Validates that the members of a group are exported as CSV file ordered by name.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group with both users as members and a global `Read` permission
+    ///      on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `export_members` for the group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) with the content type "text/csv".
+    ///    - Verifies the file contains the header and one row per member with the role the member joined with.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_export_members() {
+        use crate::handlers::groups::group_id::members::roster::export_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Read);
+        util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            export_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert!(resp.headers().get(CONTENT_TYPE).unwrap().to_str().unwrap().starts_with("text/csv"));
+
+        let body = test::read_body(resp).await;
+
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            "email,name,role\ntes@tes.de,Tes,add_member\ntest@test.de,Test,add_member\n"
+        );
+    }
+
+    /// # Test: `test_export_members_no_permission`
+    ///
+    /// Validates that a user without the `Read` access type on group members cannot export the members of a group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_export_members_no_permission() {
+        use crate::handlers::groups::group_id::members::roster::export_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            export_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -5,7 +5,7 @@ mod fetch_members_tests {
     use serde_json::Value;
     use uuid::Uuid;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir}, task::TaskPagingResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::TaskPackagesResponse, members::MemberListWithCountResponse, auth::RegisterUserSchema}};
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir}, task::TaskPagingResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::TaskPackagesResponse, members::MemberListWithCountResponse, auth::RegisterUserSchema, group_settings::GroupSettings}};
 
     static SCOPE: &'static str = "/api/groups/{group_id}/members"; 
     
@@ -317,4 +317,264 @@ mod fetch_members_tests {
         .await;
         assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
     }
-}
\ No newline at end of file
+
+    /// # Test: `test_fetch_members_roles_and_permissions`
+    ///
+    /// Validates that the member listing contains the role and the group scoped permissions of every member
+    /// and can be filtered by role and ressource.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group, the first user has a global `Read` permission on the "group_member" ressource.
+    ///    - Creates the role "tutor" with `Write` access for the "task_package" ressource.
+    ///    - Adds the first user as tutor and the second user with the default member role.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_members` without filter, filtered by role and filtered by ressource and access type.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the tutor is listed with the role and the `Write` access for "task_package".
+    ///    - Verifies the other member is listed with the default member role and without permissions.
+    ///    - Verifies the filters only return the tutor and that the total count respects the filters.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_members_roles_and_permissions() {
+        use crate::handlers::groups::group_id::members::members::get_group_members;
+        use crate::models::groups::NewGroupMember;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Read);
+
+        util::create_ressource(&test_app.permission_repo, &vec![(NewRessource {
+            key_name: &"task_package".to_string(),
+            key_value: &"task_package".to_string(),
+        }, vec![AccessType::Write])]);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Tutor".to_string(),
+            value_key: &"tutor".to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "task_package".to_string(),
+                role: "tutor".to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+
+        let tutor = test_app.group_repo.add_user_to_group_with_role(&NewGroupMember {
+            user_id: &created_user.id,
+            group_id: &created_groups[0].id,
+        }, "tutor").unwrap();
+        let student = util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, ""),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.members[0].id, tutor.member_id);
+        assert_eq!(response.members[0].role, Some("tutor".to_string()));
+        assert_eq!(response.members[0].permissions.len(), 1);
+        assert_eq!(response.members[0].permissions[0].ressource, "task_package");
+        assert_eq!(response.members[0].permissions[0].access_types, vec![AccessType::Write]);
+        assert_eq!(response.members[1].id, student.member_id);
+        assert_eq!(response.members[1].role, Some("add_member".to_string()));
+        assert!(response.members[1].permissions.is_empty());
+
+        for query in ["?role=tutor", "?ressource=task_package&access_type=Write"] {
+            let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, query),
+                SCOPE,
+                get_group_members,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+            let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+            assert_eq!(response.total_count, 1);
+            assert_eq!(response.members.len(), 1);
+            assert_eq!(response.members[0].id, tutor.member_id);
+        }
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, "?ressource=task_package&access_type=Read"),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 0);
+    }
+
+    /// # Test: `test_fetch_members_sorted`
+    ///
+    /// Validates that members are sorted by join date by default and can be sorted by name.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group, the first user has a global `Read` permission on the "group_member" ressource.
+    ///    - Adds the first user ("Test") and afterwards the second user ("Tes") to the group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_members` without sorting and sorted by name.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first user is listed first by default, as they joined first.
+    ///    - Verifies the second user is listed first when sorted by name and the join dates are returned.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_members_sorted() {
+        use crate::handlers::groups::group_id::members::members::get_group_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Read);
+
+        let member = util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+        let member_other = util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, ""),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members[0].id, member.member_id);
+        assert_eq!(response.members[1].id, member_other.member_id);
+        assert!(response.members[0].joined_at <= response.members[1].joined_at);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, "?sort=name"),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members[0].id, member_other.member_id);
+        assert_eq!(response.members[1].id, member.member_id);
+    }
+
+    /// # Test: `test_fetch_members_not_visible`
+    ///
+    /// Validates that the members of a group with `members_visible = false` can only be fetched with the
+    /// `Write` access type on group members.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group with the user as member and a global `Read` permission on the
+    ///      "group_member" ressource.
+    ///    - Sets `members_visible` of the group settings to `false`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_members` with the `Read` permission, then again after granting the `Write` permission.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "FORBIDDEN" (403) with only the `Read` access type.
+    ///    - Verifies the response status is "OK" (200) with the `Write` access type and the member is listed.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_members_not_visible() {
+        use crate::handlers::groups::group_id::members::members::get_group_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_ressource(&test_app.permission_repo, &vec![(NewRessource {
+            key_name: &"group_member".to_string(),
+            key_value: &"group_member".to_string(),
+        }, vec![AccessType::Read, AccessType::Write])]);
+
+        util::create_permissions_for_user(&test_app.group_repo, &vec![(NewUserPermission {
+            user_id: created_user.id,
+            group_id: None,
+            ressource: "group_member".to_string(),
+        }, vec![OptionalUserAccessType {
+            access_type: AccessType::Read,
+            permission: Some(true),
+            set_permission: None,
+            set_set_permission: None,
+        }])]);
+
+        let member = util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+
+        test_app.group_repo.update_group_settings(&created_groups[0].id, &GroupSettings {
+            members_visible: false,
+            ..Default::default()
+        }, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, ""),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        util::create_permissions_for_user(&test_app.group_repo, &vec![(NewUserPermission {
+            user_id: created_user.id,
+            group_id: None,
+            ressource: "group_member".to_string(),
+        }, vec![OptionalUserAccessType {
+            access_type: AccessType::Write,
+            permission: Some(true),
+            set_permission: None,
+            set_set_permission: None,
+        }])]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, ""),
+            SCOPE,
+            get_group_members,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: MemberListWithCountResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.members[0].id, member.member_id);
+    }
+}
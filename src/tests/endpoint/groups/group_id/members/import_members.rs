@@ -0,0 +1,348 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod import_members_tests {
+    use actix_web::{test::{TestRequest, self}, http::{self, header::CONTENT_TYPE}};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::NewUserPermission, util::AccessType, roster::{ImportMembersResponse, ImportRowStatus}, roles::NewRole}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/members";
+    static BOUNDARY: &str = "roster-import-boundary";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/members/import", group_id)
+    }
+
+    /// Builds a `multipart/form-data` request with the CSV content as `file` field and the given text fields.
+    fn create_upload(csv: &str, fields: &[(&str, &str)]) -> TestRequest {
+        let mut body = String::new();
+
+        for (name, value) in fields {
+            body.push_str(&format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+            ));
+        }
+
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"members.csv\"\r\nContent-Type: text/csv\r\n\r\n{csv}\r\n--{BOUNDARY}--\r\n"
+        ));
+
+        TestRequest::post()
+            .insert_header((CONTENT_TYPE, format!("multipart/form-data; boundary={BOUNDARY}")))
+            .set_payload(body)
+    }
+
+    fn create_group_member_permission(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"GroupMember".to_string(),
+                key_value: &"group_member".to_string(),
+            }, vec![AccessType::Read, AccessType::Write])
+            ],
+        );
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &vec![(NewUserPermission {
+                    user_id: *user_id,
+                    group_id,
+                    ressource: "group_member".to_string()
+                },
+                vec![OptionalUserAccessType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }])
+            ],
+        );
+    }
+
+    /// # Test: `test_import_members`
+    ///
+    /// Validates that an import adds existing users and reports every failed row with its reason.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Imports a CSV file with the second user, an unknown user, an invalid email, a duplicate row and an unknown role.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the status and reason of every row.
+    ///    - Verifies only the second user became a member.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        let csv = "Email,Name,Role\nTES@tes.de,Tes,\nunknown@test.de,Unknown,\nnot-an-email,,\ntes@tes.de,Tes,\nnew@test.de,New,tutor";
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload(csv, &[]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert!(!response.dry_run);
+        assert_eq!(response.failed_count, 4);
+        assert_eq!(response.rows.len(), 5);
+
+        assert_eq!(response.rows[0].row, 2);
+        assert_eq!(response.rows[0].email, "tes@tes.de");
+        assert_eq!(response.rows[0].status, ImportRowStatus::Added);
+        assert_eq!(response.rows[0].user_id, Some(other_user.id));
+
+        let reasons: Vec<Option<String>> = response.rows[1..].iter().map(|row| row.reason.clone()).collect();
+        assert_eq!(reasons, vec![
+            Some("unknown_user".to_string()),
+            Some("invalid_email".to_string()),
+            Some("duplicate_email".to_string()),
+            Some("unknown_role".to_string()),
+        ]);
+
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+        assert_eq!(test_app.group_repo.fetch_roster(&created_groups[0].id).unwrap().len(), 1);
+    }
+
+    /// # Test: `test_import_members_already_member`
+    ///
+    /// Validates that members of the group are reported as such and not added twice.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_already_member() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("email\ntes@tes.de", &[]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.failed_count, 0);
+        assert_eq!(response.rows[0].status, ImportRowStatus::AlreadyMember);
+    }
+
+    /// # Test: `test_import_members_with_password`
+    ///
+    /// Validates that missing accounts are created with a generated password when requested.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Imports a CSV file with an unknown user and with the form field `missing_users` set to `Password`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the row is reported as created together with the generated password.
+    ///    - Verifies the account uses that password and is a member of the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_with_password() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("email,name\nnew@test.de,New Student\nnameless@test.de,", &[("missing_users", "Password")]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.rows[0].status, ImportRowStatus::Created);
+        assert_eq!(response.rows[1].reason, Some("missing_name".to_string()));
+
+        let user_id = response.rows[0].user_id.unwrap();
+        let password = response.rows[0].password.clone().unwrap();
+
+        let user = test_app.user_repo.fetch_user(&user_id).unwrap();
+        assert_eq!(user.name, "New Student");
+        assert!(util::password_is_valid(&user.password, &password));
+
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &user_id).unwrap());
+    }
+
+    /// # Test: `test_import_members_with_invitation`
+    ///
+    /// Validates that missing accounts get an invitation restricted to their email when requested.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_with_invitation() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("email\nNew@test.de", &[("missing_users", "Invite")]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.rows[0].status, ImportRowStatus::Invited);
+
+        let invitations = test_app.group_repo.fetch_open_invitations_for_email("new@test.de").unwrap();
+        assert_eq!(invitations.len(), 1);
+        assert_eq!(invitations[0].group_id, created_groups[0].id);
+        assert_eq!(invitations[0].max_uses, Some(1));
+        assert_eq!(Some(invitations[0].code.clone()), response.rows[0].invitation_code);
+    }
+
+    /// # Test: `test_import_members_dry_run`
+    ///
+    /// Validates that a dry run reports the outcome without adding members, creating accounts or invitations.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_dry_run() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("email,name\ntes@tes.de,Tes\nnew@test.de,New", &[("dry_run", "true"), ("missing_users", "Password")]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert!(response.dry_run);
+        assert_eq!(response.rows[0].status, ImportRowStatus::Added);
+        assert_eq!(response.rows[1].status, ImportRowStatus::Created);
+        assert_eq!(response.rows[1].password, None);
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+        assert!(!test_app.user_repo.exist_user_with_email("new@test.de".to_string()).unwrap());
+    }
+
+    /// # Test: `test_import_members_role_group_scoped`
+    ///
+    /// Validates that a user who may only manage the members of the group itself cannot assign other roles.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_role_group_scoped() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, Some(created_groups[0].id));
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Tutor".to_string(),
+            value_key: &"tutor".to_string(),
+        }]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("email,role\ntes@tes.de,tutor", &[]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: ImportMembersResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.rows[0].reason, Some("role_not_allowed".to_string()));
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_import_members_without_email_column`
+    ///
+    /// Validates that a CSV file without an `email` column is rejected with "Bad Request" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_import_members_without_email_column() {
+        use crate::handlers::groups::group_id::members::roster::import_members;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_member_permission(&test_app, &created_user.id, None);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            import_members,
+            test_app.valid_authorizate(create_upload("mail,name\ntes@tes.de,Tes", &[]), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
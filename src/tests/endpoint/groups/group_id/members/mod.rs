@@ -1,3 +1,5 @@
 pub mod get_group_members;
 pub mod remove_members;
-pub mod add_member_to_group;
\ No newline at end of file
+pub mod add_member_to_group;
+pub mod import_members;
+pub mod export_members;
\ No newline at end of file
@@ -1 +1,141 @@
-// TODO: Write tests
\ No newline at end of file
+//docu written with chat-gpt
+#[cfg(test)]
+mod remove_members_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, members::{MemberBatchResponse, MemberEntryStatus}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/members";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/members/", group_id)
+    }
+
+    /// # Test: `test_remove_members`
+    ///
+    /// Validates that members are removed and unknown member ids are reported instead of failing the request.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group with both users as members and a global `Write` permission
+    ///      on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Removes the second member, an unknown member id and the second member again.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the second member was removed and the reasons of the failed entries.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_remove_members() {
+        use crate::handlers::groups::group_id::members::members::remove_member_from_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+        util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+        let member = util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let unknown_member_id = Uuid::new_v4();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            remove_member_from_group,
+            test_app.valid_authorizate(TestRequest::delete().set_json(serde_json::json!({
+                "remove_members": [member.member_id, unknown_member_id, member.member_id],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members.len(), 1);
+        assert_eq!(response.members[0].user.id, other_user.id);
+        assert_eq!(response.failed_count, 2);
+        assert_eq!(response.results[0].status, MemberEntryStatus::Removed);
+        assert_eq!(response.results[1].entry, unknown_member_id.to_string());
+        assert_eq!(response.results[1].reason, Some("member_not_found".to_string()));
+        assert_eq!(response.results[2].reason, Some("duplicate_entry".to_string()));
+
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &created_user.id).unwrap());
+    }
+
+    /// # Test: `test_remove_members_abort`
+    ///
+    /// Validates that with `on_error=abort` no member is removed if one entry fails.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group with both users as members and a global `Write` permission
+    ///      on the "group_member" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Removes both members and an unknown member id with `on_error=abort`, then both members only.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first response status is "BAD REQUEST" (400) and both users are still members.
+    ///    - Verifies the second response status is "OK" (200) and the group has no members left.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_remove_members_abort() {
+        use crate::handlers::groups::group_id::members::members::remove_member_from_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group_member", &created_user.id, &AccessType::Write);
+        let first_member = util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+        let second_member = util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let path = format!("{}?on_error=abort", get_path(&created_groups[0].id));
+
+        let resp = test_app
+        .call(
+            &path,
+            SCOPE,
+            remove_member_from_group,
+            test_app.valid_authorizate(TestRequest::delete().set_json(serde_json::json!({
+                "remove_members": [first_member.member_id, Uuid::new_v4(), second_member.member_id],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert!(response.members.is_empty());
+        assert_eq!(response.results[0].status, MemberEntryStatus::Skipped);
+        assert_eq!(response.results[1].reason, Some("member_not_found".to_string()));
+        assert_eq!(test_app.group_repo.fetch_roster(&created_groups[0].id).unwrap().len(), 2);
+
+        let resp = test_app
+        .call(
+            &path,
+            SCOPE,
+            remove_member_from_group,
+            test_app.valid_authorizate(TestRequest::delete().set_json(serde_json::json!({
+                "remove_members": [first_member.member_id, second_member.member_id],
+            })), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: MemberBatchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.members.len(), 2);
+        assert!(test_app.group_repo.fetch_roster(&created_groups[0].id).unwrap().is_empty());
+    }
+}
@@ -1,9 +1,17 @@
 pub mod get_group;
 pub mod get_group_meta_data;
+pub mod get_group_tree;
+pub mod get_group_descendants;
+pub mod update_group;
 pub mod delete_group;
+pub mod restore_group;
+pub mod clone_group;
 pub mod user_id;
 pub mod user;
 pub mod task_packages;
 pub mod solution_attempts;
 pub mod members;
-pub mod answers;
\ No newline at end of file
+pub mod answers;
+pub mod invitations;
+pub mod join_requests;
+pub mod settings;
\ No newline at end of file
@@ -0,0 +1,324 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod restore_group_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, NewGroup, CreateGroup, GroupInfoResponse}, util::AccessType}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/restore", group_id)
+    }
+
+    /// Creates a chain of groups where every group is the parent of the next one.
+    fn create_group_chain(group_repo: &GroupRepo, user_id: &Uuid, names: &[&str]) -> Vec<CreateGroup> {
+        let mut groups: Vec<CreateGroup> = vec![];
+
+        for name in names {
+            let parent = groups.last().map(|group| group.id);
+            groups.push(group_repo.create_group(&NewGroup {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                created_from: *user_id,
+                updated_from: *user_id,
+                parent,
+            }).unwrap());
+        }
+
+        groups
+    }
+
+    fn create_group_ressource(test_app: &TestRepo) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read, AccessType::Delete])
+            ],
+        );
+    }
+
+    fn create_group_permissions(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>, access_types: Vec<AccessType>) {
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: *user_id,
+                group_id,
+                ressource: "group".to_string()
+            },
+            access_types.into_iter().map(|access_type| OptionalUserAccessType {
+                access_type,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }).collect())
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+    }
+
+    /// # Test: `test_restore_group`
+    ///
+    /// Validates that restoring a deleted group brings back its subtree, task packages and group scoped permissions.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child > Grandchild` and a task package in `Child`.
+    ///    - Grants the user a global `Delete` permission and another user a `Read` permission scoped to `Child`.
+    ///    - Deletes `Root`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `restore_group` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the restored group is returned.
+    ///    - Verifies all groups and the task package are active again.
+    ///    - Verifies the other user has the `Read` permission on `Child` again.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_group() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child", "Grandchild"]);
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![], &groups[1].id);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+        create_group_permissions(&test_app, &other_user.id, Some(groups[1].id), vec![AccessType::Read]);
+
+        assert_eq!(test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap(), 3);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupInfoResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, groups[0].id);
+        assert_eq!(response.parent, None);
+
+        for group in &groups {
+            assert!(test_app.group_repo.fetch_active_group_info(group.id).is_ok());
+        }
+
+        assert!(test_app.group_repo.is_task_package_active(&task_package.id, &groups[1].id).unwrap());
+
+        let access_types = test_app.permission_repo.user_has_permission(
+            &other_user.id,
+            &"group".to_string(),
+            &Some(groups[1].id),
+        ).unwrap();
+        assert_eq!(access_types, vec![AccessType::Read]);
+    }
+
+    /// # Test: `test_restore_group_keeps_earlier_deletions`
+    ///
+    /// Validates that subgroups which were deleted before their parent stay deleted when the parent is restored.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child` and a global `Delete` permission.
+    ///    - Deletes `Child` first and `Root` afterwards.
+    ///
+    /// 2. Execution:
+    ///    - Calls `restore_group` for `Root`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies `Root` is active while `Child` is still deleted.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_group_keeps_earlier_deletions() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        test_app.group_repo.delete_group(&groups[1].id, &created_user.id).unwrap();
+        test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        assert!(test_app.group_repo.fetch_active_group_info(groups[0].id).is_ok());
+        assert!(test_app.group_repo.fetch_active_group_info(groups[1].id).is_err());
+    }
+
+    /// # Test: `test_restore_subgroup_of_deleted_group`
+    ///
+    /// Validates that a group deleted together with its parent cannot be restored on its own.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child`, a global `Delete` permission and deletes `Root`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `restore_group` for `Child`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Conflict" (409) and `Child` is still deleted.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_subgroup_of_deleted_group() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[1].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+
+        assert!(test_app.group_repo.fetch_active_group_info(groups[1].id).is_err());
+    }
+
+    /// # Test: `test_restore_group_with_deleted_parent`
+    ///
+    /// Validates that a group cannot be restored while its parent is deleted.
+    ///
+    /// 1. Setup:
+    ///    - Creates the chain `Root > Child` and a global `Delete` permission.
+    ///    - Deletes `Child` first and `Root` afterwards.
+    ///
+    /// 2. Execution:
+    ///    - Calls `restore_group` for `Child`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Conflict" (409).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_group_with_deleted_parent() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root", "Child"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        test_app.group_repo.delete_group(&groups[1].id, &created_user.id).unwrap();
+        test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[1].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+    }
+
+    /// # Test: `test_restore_active_group`
+    ///
+    /// Validates that restoring a group that is not deleted returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_active_group() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Delete]);
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_restore_group_no_permission`
+    ///
+    /// Validates that a user without the `Delete` access type cannot restore a group.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group, deletes it and grants the user only a global `Read` permission.
+    ///
+    /// 2. Execution:
+    ///    - Calls `restore_group` for the group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Forbidden" (403) and the group is still deleted.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_restore_group_no_permission() {
+        use crate::handlers::groups::group_id::group_id::restore_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let groups = create_group_chain(&test_app.group_repo, &created_user.id, &["Root"]);
+
+        create_group_ressource(&test_app);
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read]);
+
+        test_app.group_repo.delete_group(&groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&groups[0].id),
+            SCOPE,
+            restore_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        assert!(test_app.group_repo.fetch_active_group_info(groups[0].id).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_group_settings_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::{AccessType, Visibility}, group_settings::GroupSettings}};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/settings", group_id)
+    }
+
+    /// # Test: `test_get_group_settings`
+    ///
+    /// Validates that the settings of a group are returned, with default values for settings that were never set.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, two groups and a global `Read` permission on the "group" ressource.
+    ///    - Stores settings for the first group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_group_settings` for both groups.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) for both groups.
+    ///    - Verifies the stored settings of the first group and the default settings of the second group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_settings() {
+        use crate::handlers::groups::group_id::settings::settings::get_group_settings;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Read);
+
+        let settings = GroupSettings {
+            description: Some("Introduction to formal languages".to_string()),
+            term_start: NaiveDate::from_ymd_opt(2024, 4, 1),
+            term_end: NaiveDate::from_ymd_opt(2024, 9, 30),
+            catalogue_visible: true,
+            default_visibility: Some(Visibility::Private),
+            default_member_role: None,
+            members_visible: true,
+            leaving_disabled: false,
+        };
+        test_app.group_repo.update_group_settings(&created_groups[0].id, &settings, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_group_settings,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSettings = test::read_body_json(resp).await;
+        assert_eq!(response, settings);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[1].id),
+            SCOPE,
+            get_group_settings,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSettings = test::read_body_json(resp).await;
+        assert_eq!(response, GroupSettings::default());
+    }
+
+    /// # Test: `test_get_group_settings_deleted_group`
+    ///
+    /// Validates that the settings of a deleted group cannot be fetched.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_settings_deleted_group() {
+        use crate::handlers::groups::group_id::settings::settings::get_group_settings;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Read);
+
+        test_app.group_repo.delete_group(&created_groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_group_settings,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_get_group_settings_no_permission`
+    ///
+    /// Validates that a user without the `Read` access type on the group cannot fetch its settings.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_group_settings_no_permission() {
+        use crate::handlers::groups::group_id::settings::settings::get_group_settings;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            get_group_settings,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
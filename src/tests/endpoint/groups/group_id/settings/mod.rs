@@ -0,0 +1,2 @@
+pub mod get_group_settings;
+pub mod update_group_settings;
\ No newline at end of file
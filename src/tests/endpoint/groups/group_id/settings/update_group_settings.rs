@@ -0,0 +1,229 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod update_group_settings_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use chrono::NaiveDate;
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::NewUserPermission, util::{AccessType, Visibility}, group_settings::GroupSettings, roles::NewRole}};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/settings", group_id)
+    }
+
+    async fn call_update(test_app: &TestRepo, group_id: &Uuid, user_id: &Uuid, body: Value) -> actix_web::dev::ServiceResponse {
+        use crate::handlers::groups::group_id::settings::settings::update_group_settings;
+
+        test_app
+        .call(
+            &get_path(group_id),
+            SCOPE,
+            update_group_settings,
+            test_app.valid_authorizate(TestRequest::patch().set_json(body), user_id),
+        )
+        .await
+    }
+
+    /// # Test: `test_update_group_settings`
+    ///
+    /// Validates that changes are merged into the current settings.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Sets the description, the catalogue visibility and the default visibility.
+    ///    - Sets the term dates and resets the default visibility with `null`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies both responses have the status "OK" (200).
+    ///    - Verifies the second response keeps the description and the catalogue visibility.
+    ///    - Verifies the stored settings equal the returned settings.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_settings() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Write);
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "description": "Automata theory",
+            "catalogue_visible": true,
+            "default_visibility": "Private",
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSettings = test::read_body_json(resp).await;
+        assert_eq!(response.default_visibility, Some(Visibility::Private));
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "term_start": "2024-04-01",
+            "term_end": "2024-09-30",
+            "default_visibility": null,
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSettings = test::read_body_json(resp).await;
+
+        assert_eq!(response, GroupSettings {
+            description: Some("Automata theory".to_string()),
+            term_start: NaiveDate::from_ymd_opt(2024, 4, 1),
+            term_end: NaiveDate::from_ymd_opt(2024, 9, 30),
+            catalogue_visible: true,
+            default_visibility: None,
+            default_member_role: None,
+            members_visible: true,
+            leaving_disabled: false,
+        });
+        assert_eq!(test_app.group_repo.fetch_group_settings(&created_groups[0].id).unwrap(), response);
+
+        let meta_data = test_app.group_repo.fetch_active_group_meta_data(created_groups[0].id).unwrap();
+        assert_eq!(meta_data.updated_from, created_user.id);
+    }
+
+    /// # Test: `test_update_group_settings_invalid`
+    ///
+    /// Validates that settings violating the settings schema are rejected with "Bad Request" (400) and not stored.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Sends an unknown field, a value of the wrong type, an invalid date, a term ending before it starts,
+    ///      an unknown default role and a body that is not an object.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies every response status is "Bad Request" (400) and the settings are unchanged.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_settings_invalid() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Write);
+
+        for body in [
+            serde_json::json!({"color": "red"}),
+            serde_json::json!({"color": null}),
+            serde_json::json!({"catalogue_visible": "yes"}),
+            serde_json::json!({"term_start": "first of april"}),
+            serde_json::json!({"term_start": "2024-09-30", "term_end": "2024-04-01"}),
+            serde_json::json!({"default_member_role": "unknown_role"}),
+            serde_json::json!(["description"]),
+        ] {
+            let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, body).await;
+            assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        }
+
+        assert_eq!(test_app.group_repo.fetch_group_settings(&created_groups[0].id).unwrap(), GroupSettings::default());
+    }
+
+    /// # Test: `test_update_group_settings_default_member_role`
+    ///
+    /// Validates that only users with the global `Write` access type on group members can set another default member role.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group, the roles "add_member" and "tutor" and a `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Sets the default member role to "add_member" and to "tutor".
+    ///    - Grants the global `Write` access type on group members and sets the default member role to "tutor" again.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies "add_member" is accepted with "OK" (200), "tutor" is rejected with "Forbidden" (403) and accepted
+    ///      after granting the permission.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_settings_default_member_role() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Write);
+
+        util::create_role(&test_app.permission_repo, &vec![
+            NewRole {
+                name: &"Member".to_string(),
+                value_key: &"add_member".to_string(),
+            },
+            NewRole {
+                name: &"Tutor".to_string(),
+                value_key: &"tutor".to_string(),
+            },
+        ]);
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "default_member_role": "add_member",
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "default_member_role": "tutor",
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"GroupMember".to_string(),
+                key_value: &"group_member".to_string(),
+            }, vec![AccessType::Write])
+            ],
+        );
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &vec![(NewUserPermission {
+                    user_id: created_user.id,
+                    group_id: None,
+                    ressource: "group_member".to_string()
+                },
+                vec![OptionalUserAccessType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }])
+            ],
+        );
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "default_member_role": "tutor",
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        assert_eq!(test_app.group_repo.fetch_default_member_role(&created_groups[0].id).unwrap(), "tutor");
+    }
+
+    /// # Test: `test_update_group_settings_no_permission`
+    ///
+    /// Validates that a user with only the `Read` access type on the group cannot update its settings.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_settings_no_permission() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Read);
+
+        let resp = call_update(&test_app, &created_groups[0].id, &created_user.id, serde_json::json!({
+            "catalogue_visible": true,
+        })).await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
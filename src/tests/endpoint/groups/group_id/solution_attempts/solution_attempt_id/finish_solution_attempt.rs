@@ -1,11 +1,14 @@
 //docu written with chat-gpt
 #[cfg(test)]
 mod finish_solution_attempt_tests {
-    use actix_web::{test::{TestRequest, self}, http};
+    use std::{collections::{HashMap, HashSet}, sync::Arc};
+
+    use actix_web::{test::{TestRequest, self}, http, web::Data};
+    use mongodb::bson::{Document, doc};
     use serde_json::Value;
     use uuid::Uuid;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir, Visibility, AnswerState}, task::{TaskPagingResponse, NewTempTask}, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::{TaskPackagesResponse, CreatedTaskPackageResponse}, solution_attempts::CreatedSolutionAttemptResponse}};
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir, Visibility, AnswerState}, task::{TaskPagingResponse, NewTempTask, TaskMetadata}, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::{TaskPackagesResponse, CreatedTaskPackageResponse}, solution_attempts::CreatedSolutionAttemptResponse}, grading::grader::{Grade, Grader}, handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::resume_background_grading};
 
     static SCOPE: &'static str = "/api/groups/{group_id}/solution_attempts/{solution_attempt_id}"; 
     
@@ -73,6 +76,8 @@ mod finish_solution_attempt_tests {
         assert_eq!(answers.len(), 1);
         assert_eq!(answers[0].state, AnswerState::Done);
         assert_eq!(answers[0].correct, true);
+        assert_eq!(answers[0].score, 1.0);
+        assert_eq!(answers[0].max_score, 1.0);
     }
 
     /// # Test: `finish_soltuion_attempts_wrong_answer`
@@ -135,6 +140,8 @@ mod finish_solution_attempt_tests {
         assert_eq!(answers.len(), 1);
         assert_eq!(answers[0].state, AnswerState::Done);
         assert_eq!(answers[0].correct, false);
+        assert_eq!(answers[0].score, 0.0);
+        assert_eq!(answers[0].max_score, 1.0);
     }
 
     // # Test: `finish_soltuion_attempts_other`
@@ -352,4 +359,740 @@ mod finish_solution_attempt_tests {
         .await;
         assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
     }
-}
\ No newline at end of file
+
+    /// A grader awarding half of the points to every answer.
+    struct HalfScoreGrader;
+
+    impl Grader for HalfScoreGrader {
+        fn grade(&self, _task: &Document, _solution: &Document, _answer: &Document) -> Grade {
+            Grade::new(1.0, 2.0, vec![])
+        }
+    }
+
+    /// # Test: `finish_soltuion_attempts_registered_grader`
+    ///
+    /// Validates that answers are graded by the grader registered for their task type instead of by exact match.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Registers a grader awarding half of the points for the task type of a multiple-choice task.
+    ///    - Adds the task to a task package and answers it correctly in a solution attempt.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer is done with half of the points, so not correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_registered_grader() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let mut test_app = TestRepo::new().await;
+
+        test_app.app_state.graders.register("Multiple-Choice", Arc::new(HalfScoreGrader));
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        util::create_correct_answer(&test_app.app_state.mongodb, &test_app.group_repo, &task, &solution_attempt.solution_list[0].answer_id).await;
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].state, AnswerState::Done);
+        assert!(!answers[0].correct);
+        assert_eq!(answers[0].score, 0.5);
+    }
+
+    /// # Test: `finish_soltuion_attempts_cyk_cells`
+    ///
+    /// Validates that CYK answers are scored per cell of the table and the feedback names the wrong cells.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a CYK task for the word `abba` to a task package.
+    ///    - Answers it in a solution attempt with the correct table except for the cell of the whole word.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer scores 9 of 10 cells, so it is not correct.
+    ///    - Verifies the stored feedback marks only the last cell as wrong.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_cyk_cells() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = util::create_task_cyk(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let mut table = task.solution.get_array("solution").unwrap().clone();
+        table[9] = doc!{ "A": null }.into();
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{ "solution": table }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(!answers[0].correct);
+        assert!((answers[0].score - 0.9).abs() < 1e-9);
+
+        let answer_doc = test_app.app_state.mongodb.fetch_answer_doc(answer_doc.id).await.unwrap().unwrap();
+
+        assert_eq!(answer_doc.feedback.len(), 10);
+        assert_eq!(answer_doc.feedback.iter().filter(|feedback| !feedback.correct).map(|feedback| feedback.path.as_str()).collect::<Vec<_>>(), vec!["/solution/9"]);
+    }
+
+    /// # Test: `finish_soltuion_attempts_template_instance`
+    ///
+    /// Validates that answers to task templates are graded against the instance generated from the seed of the answer.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a multiple-choice template, whose correct answer is drawn from a parameter, to a task package.
+    ///    - Starts a solution attempt with a fixed seed for the template and answers with the solution of that instance.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer is correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_template_instance() {
+        use std::collections::HashMap;
+
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = test_app.app_state.mongodb.create_task("Multiple-Choice".to_string(), doc!{
+            "question": "Welche Antwort ist die {{c + 1}}.?",
+            "answers": ["1", "2", "3", "4", "5"]
+        }, doc!{
+            "solution": "{{c}}"
+        }, Some(doc!{
+            "c": { "type": "integer", "min": 0, "max": 4 },
+        }), 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let seeds = HashMap::from([(task.id, 7)]);
+
+        let solution_attempt = test_app.group_repo.create_solution_attempt(&created_user.id, &task_package.id, &created_groups[0].id, &None, &seeds, &HashMap::new()).unwrap();
+
+        assert_eq!(solution_attempt.solution_list[0].seed, Some(7));
+
+        let instance = test_app.app_state.generators.instantiate(&task, 7, &test_app.app_state.graders).unwrap();
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(instance.solution).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(answers[0].correct);
+        assert_eq!(answers[0].seed, Some(7));
+    }
+
+    /// # Test: `finish_soltuion_attempts_numeric_tolerance`
+    ///
+    /// Validates that numeric answers in another accepted unit are converted and accepted within the tolerance of the task.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a numeric task with the solution `1500 m`, accepting `km` and a relative tolerance of one percent.
+    ///    - Answers it in a solution attempt with `1.51 km`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer is correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_numeric_tolerance() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = test_app.app_state.mongodb.create_task("Numeric".to_string(), doc!{
+            "question": "Wie lang ist die Strecke?",
+            "unit": "m",
+            "units": { "km": 1000 },
+            "tolerance": { "relative": 0.01 }
+        }, doc!{
+            "solution": 1500
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{ "solution": 1.51, "unit": "km" }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(answers[0].correct);
+    }
+
+    /// # Test: `finish_soltuion_attempts_ordering_pairwise`
+    ///
+    /// Validates that ordering answers are scored by the pairs of items in the correct order with the `pairwise` scoring.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds an ordering task of four items with the `pairwise` scoring.
+    ///    - Answers it in a solution attempt with the first two items swapped.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer scores 5 of 6 pairs, so it is not correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_ordering_pairwise() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = test_app.app_state.mongodb.create_task("Ordering".to_string(), doc!{
+            "question": "Ordne die Einheiten aufsteigend nach ihrer Größe.",
+            "items": ["Kilobyte", "Bit", "Megabyte", "Byte"],
+            "scoring": "pairwise"
+        }, doc!{
+            "solution": [1, 3, 0, 2]
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{ "solution": [3, 1, 0, 2] }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(!answers[0].correct);
+        assert!((answers[0].score - 5.0 / 6.0).abs() < 1e-9);
+    }
+
+    /// # Test: `finish_soltuion_attempts_multiple_choice_partial`
+    ///
+    /// Validates that multiple-choice answers with several correct options score partial credit, where wrong picks cost points.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a multiple-choice task with three correct options of five and the `partial` scoring to a task package.
+    ///    - Answers it in a solution attempt with two correct options and one wrong option.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer scores one of three points, so it is not correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_multiple_choice_partial() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = test_app.app_state.mongodb.create_task("Multiple-Choice".to_string(), doc!{
+            "question": "Welche Zahlen sind Primzahlen?",
+            "answers": ["2", "4", "5", "7", "9"],
+            "scoring": "partial"
+        }, doc!{
+            "solution": [0, 2, 3]
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{ "solution": [0, 1, 3] }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(!answers[0].correct);
+        assert!((answers[0].score - 1.0 / 3.0).abs() < 1e-9);
+
+        let answer_doc = test_app.app_state.mongodb.fetch_answer_doc(answer_doc.id).await.unwrap().unwrap();
+
+        assert_eq!(answer_doc.feedback.iter().filter(|feedback| !feedback.correct).map(|feedback| feedback.path.as_str()).collect::<Vec<_>>(), vec!["/solution/1", "/solution"]);
+    }
+
+    /// # Test: `finish_soltuion_attempts_shuffled_options`
+    ///
+    /// Validates that answers to tasks with shuffled options refer to the shown order and are mapped back before grading.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a multiple-choice task shuffling its options to a task package.
+    ///    - Starts a solution attempt showing the options in reverse order and answers with the shown position of the correct option.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer is correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_shuffled_options() {
+        use std::collections::HashMap;
+
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = test_app.app_state.mongodb.create_task("Multiple-Choice".to_string(), doc!{
+            "question": "Wie viele Bits ergeben ein Byte?",
+            "answers": ["8", "4", "2"],
+            "shuffle": true
+        }, doc!{
+            "solution": 0
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let permutations = HashMap::from([(task.id, vec![2, 1, 0])]);
+
+        let solution_attempt = test_app.group_repo.create_solution_attempt(&created_user.id, &task_package.id, &created_groups[0].id, &None, &HashMap::new(), &permutations).unwrap();
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{ "solution": 2 }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(answers[0].correct);
+        assert_eq!(answers[0].permutation, Some(vec![2, 1, 0]));
+    }
+
+    /// # Test: `finish_soltuion_attempts_automaton_distinguishing_word`
+    ///
+    /// Validates that automata are graded by their language and a mismatch is explained with a shortest distinguishing word.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a finite-automaton task for the words with an even number of `a` to a task package.
+    ///    - Answers it in a solution attempt with an automaton accepting the words with an odd number of `a`.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204) and the answer is not correct.
+    ///    - Verifies the stored feedback names the empty word, which is the shortest word the automata disagree on.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_automaton_distinguishing_word() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = util::create_task_automaton(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let mut answer = task.solution.clone();
+        answer.insert("accepting", vec!["u"]);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(answer).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(!answers[0].correct);
+
+        let answer_doc = test_app.app_state.mongodb.fetch_answer_doc(answer_doc.id).await.unwrap().unwrap();
+
+        assert_eq!(answer_doc.feedback.len(), 1);
+        assert_eq!(answer_doc.feedback[0].message, "The automaton does not accept \"ε\", which is in the language");
+    }
+
+    /// # Test: `finish_soltuion_attempts_programming_background`
+    ///
+    /// Validates that programming tasks are graded in the background by running the answer on the hidden test cases.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds a Python programming task with two test cases to a task package.
+    ///    - Answers it in a solution attempt with a program which only passes the first test case.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt` and waits until the answer is graded.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204).
+    ///    - Verifies the answer is done and scores half of the points once it is graded, with feedback for every test case.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_programming_background() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = util::create_task_programming(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{
+            "code": "print(3)\n",
+        }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let mut feedback = vec![];
+
+        for _ in 0..100 {
+            feedback = test_app.app_state.mongodb.fetch_answer_doc(answer_doc.id).await.unwrap().unwrap().feedback;
+
+            if feedback.len() == 2 {
+                break;
+            }
+
+            actix_web::rt::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        assert_eq!(feedback.len(), 2);
+        assert_eq!(feedback[0].message, "Test \"Kleine Zahlen\" passed");
+        assert!(!feedback[1].correct);
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].state, AnswerState::Done);
+        assert!(!answers[0].correct);
+        assert_eq!(answers[0].score, 0.5);
+    }
+
+    /// # Test: `finish_soltuion_attempts_resume_background_grading`
+    ///
+    /// Validates that answers which are still being graded when the server stops are graded when it starts again.
+    ///
+    /// 1. Setup:
+    ///    - Adds a Python programming task with two test cases to a task package.
+    ///    - Answers it in a solution attempt with a program which only passes the first test case.
+    ///    - Finishes the attempt without grading the answer, which leaves it in the `Grading` state.
+    ///
+    /// 2. Execution:
+    ///    - Calls `resume_background_grading` and waits until the answer is graded.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the answer is done and scores half of the points once it is graded.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_resume_background_grading() {
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let task = util::create_task_programming(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+        let answer_id = solution_attempt.solution_list[0].answer_id;
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{
+            "code": "print(3)\n",
+        }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &answer_id).unwrap();
+
+        test_app.group_repo.finish_solution_attempt(&solution_attempt.solution_attempt.id, &HashMap::from([(answer_id, 0.0)]), &HashSet::from([answer_id])).unwrap();
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+        assert_eq!(answers[0].state, AnswerState::Grading);
+
+        resume_background_grading(Data::new(test_app.group_repo.clone()), Data::new(test_app.app_state.clone())).await;
+
+        let mut answers = vec![];
+
+        for _ in 0..100 {
+            answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+            if answers[0].state == AnswerState::Done {
+                break;
+            }
+
+            actix_web::rt::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        assert_eq!(answers[0].state, AnswerState::Done);
+        assert_eq!(answers[0].score, 0.5);
+    }
+
+    /// # Test: `finish_soltuion_attempts_sql_result_diff`
+    ///
+    /// Validates that SQL queries are graded in the background by their result set and a mismatch lists the differing
+    /// rows.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for finishing solution attempts.
+    ///    - Adds an SQL task for the students in at least their third semester to a task package.
+    ///    - Answers it in a solution attempt with a query which also returns a student in the second semester.
+    ///
+    /// 2. Execution:
+    ///    - Calls `finish_solution_attempt`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "NO_CONTENT" (204).
+    ///    - Waits for the stored feedback and verifies it names the row which is not in the expected result set.
+    ///    - Verifies the answer is not correct.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn finish_soltuion_attempts_sql_result_diff() {
+        use crate::handlers::groups::group_id::solution_attempts::solution_attempt_id::solution_attempt_id::finish_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt_finish", &created_user.id, &AccessType::Write);
+
+        let task = util::create_task_sql(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_doc = test_app.app_state.mongodb.create_answer_doc(doc!{
+            "query": "SELECT name FROM studierende WHERE semester > 1 ORDER BY name",
+        }).await.unwrap();
+        test_app.group_repo.insert_answer_doc(&answer_doc.id, &solution_attempt.solution_list[0].answer_id).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &solution_attempt.solution_attempt.id),
+                SCOPE,
+                finish_solution_attempt,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let mut feedback = vec![];
+
+        for _ in 0..100 {
+            feedback = test_app.app_state.mongodb.fetch_answer_doc(answer_doc.id).await.unwrap().unwrap().feedback;
+
+            if feedback.len() == 2 {
+                break;
+            }
+
+            actix_web::rt::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        assert_eq!(feedback.len(), 2);
+        assert_eq!(feedback[0].message, "The result has 3 rows, the expected result has 2");
+        assert_eq!(feedback[1].message, "Unexpected row ('Dana')");
+
+        let answers = test_app.group_repo.fetch_answers_from_solution_attempt(&solution_attempt.solution_attempt.id).unwrap();
+
+        assert_eq!(answers.len(), 1);
+        assert!(!answers[0].correct);
+    }
+}
@@ -5,7 +5,7 @@ mod create_solution_attempt_tests {
     use serde_json::Value;
     use uuid::Uuid;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir, Visibility}, task::{TaskPagingResponse, NewTempTask}, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::{TaskPackagesResponse, CreatedTaskPackageResponse}, solution_attempts::CreatedSolutionAttemptResponse}};
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir, Visibility}, task::{TaskPagingResponse, NewTempTask}, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::{TaskPackagesResponse, CreatedTaskPackageResponse}, solution_attempts::CreatedSolutionAttemptResponse, group_settings::GroupSettings}};
 
     static SCOPE: &'static str = "/api/groups/{group_id}/task_packages/{task_package_id}/solution_attempts"; 
     
@@ -180,6 +180,48 @@ mod create_solution_attempt_tests {
         assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
     }
 
+    /// # Test: `create_soltuion_attempts_deleted_group`
+    ///
+    /// Validates that no solution attempt can be started for a task package whose group got deleted.
+    ///
+    /// 1. Setup:
+    ///    - Creates a test user, an example group with a task package and the permission to create solution attempts.
+    ///    - Deletes the group.
+    ///
+    /// 2. Execution:
+    ///    - Makes an API call to `create_solution_attempt` for the task package of the deleted group.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies that the response status is "NOT_FOUND" (404).
+    ///
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn create_soltuion_attempts_deleted_group() {
+        use crate::handlers::groups::group_id::task_packages::task_package_id::solution_attempts::solution_attempts::create_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt", &created_user.id, &AccessType::Create);
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![], &created_groups[0].id);
+
+        test_app.group_repo.delete_group(&created_groups[0].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &task_package.id),
+            SCOPE,
+            create_solution_attempt,
+            test_app.valid_authorizate(TestRequest::post().set_json(create_body()), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
     /// # Test: `create_soltuion_attempts_no_auth`
     ///
     /// Validates the behavior of the `create_solution_attempt` handler in Actix-Web when attempting to create a solution attempt without proper authentication. The test covers the following steps:
@@ -276,4 +318,63 @@ mod create_solution_attempt_tests {
         .await;
         assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
     }
+    /// # Test: `create_soltuion_attempts_default_visibility`
+    ///
+    /// Validates that solution attempts without a visibility in the request get the default visibility of the group settings.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group with the default visibility `Private`, a task package and the permission to create solution attempts.
+    ///
+    /// 2. Execution:
+    ///    - Creates a solution attempt without visibility and one with the visibility `Public`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies both response statuses are "CREATED" (201).
+    ///    - Verifies the first solution attempt is private and the second one is public.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn create_soltuion_attempts_default_visibility() {
+        use crate::handlers::groups::group_id::task_packages::task_package_id::solution_attempts::solution_attempts::create_solution_attempt;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "solution_attempt", &created_user.id, &AccessType::Create);
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![], &created_groups[0].id);
+
+        test_app.group_repo.update_group_settings(&created_groups[0].id, &GroupSettings {
+            default_visibility: Some(Visibility::Private),
+            ..Default::default()
+        }, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &task_package.id),
+            SCOPE,
+            create_solution_attempt,
+            test_app.valid_authorizate(TestRequest::post().set_json(create_body()), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: CreatedSolutionAttemptResponse = test::read_body_json(resp).await;
+        assert_eq!(response.visibility, Visibility::Private);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id, &task_package.id),
+            SCOPE,
+            create_solution_attempt,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"visibility": "Public"})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: CreatedSolutionAttemptResponse = test::read_body_json(resp).await;
+        assert_eq!(response.visibility, Visibility::Public);
+    }
 }
\ No newline at end of file
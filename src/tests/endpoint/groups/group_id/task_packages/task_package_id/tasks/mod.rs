@@ -1,3 +1,4 @@
 pub mod add_tasks_to_package;
 pub mod get_tasks_info_from_package;
-pub mod remove_tasks_from_package;
\ No newline at end of file
+pub mod remove_tasks_from_package;
+pub mod update_task_points;
\ No newline at end of file
@@ -0,0 +1,117 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod update_task_points_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, task::{NewTempTask, TasksFromPackageResponse}}};
+
+    static SCOPE: &str = "/api/groups/{group_id}/task_packages/{task_package_id}/tasks";
+
+    fn get_path(group_id: &Uuid, task_package_id: &Uuid) -> String {
+        format!("/api/groups/{}/task_packages/{}/tasks/", group_id, task_package_id)
+    }
+
+    /// # Test: `test_update_task_points`
+    ///
+    /// Validates that the points of the listed tasks of a task package are updated.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for task package tasks.
+    ///    - Creates a task package with two tasks.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_task_points` for the first task.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and only the first task is returned with its new points.
+    ///    - Verifies the second task keeps the default of one point.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_task_points() {
+        use crate::handlers::groups::group_id::task_packages::task_package_id::tasks::tasks::update_task_points;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task_package_task", &created_user.id, &AccessType::Write);
+
+        let task_doc_ids = [Uuid::new_v4(), Uuid::new_v4()];
+
+        let task_package = util::create_task_package(&test_app.group_repo, &task_doc_ids.iter().map(|task_doc_id| NewTempTask {
+            task_doc_id: *task_doc_id,
+            task_type: "Multiple-Choice".to_string(),
+        }).collect(), &created_groups[0].id);
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &task_package.id),
+                SCOPE,
+                update_task_points,
+                test_app.valid_authorizate(TestRequest::patch().set_json(serde_json::json!({
+                    "tasks": [{
+                        "task_doc_id": task_doc_ids[0],
+                        "points": 2.5,
+                    }]
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TasksFromPackageResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.tasks.len(), 1);
+        assert_eq!(response.tasks[0].task_doc_id, task_doc_ids[0]);
+        assert_eq!(response.tasks[0].points, 2.5);
+
+        let tasks = test_app.group_repo.fetch_tasks_from_package(&task_package.id, &created_groups[0].id, &None).unwrap();
+
+        assert_eq!(tasks.iter().find(|task| task.task_doc_id == task_doc_ids[1]).unwrap().points, 1.0);
+    }
+
+    /// # Test: `test_update_task_points_negative`
+    ///
+    /// Validates that negative points are rejected with "BAD REQUEST" (400) and the points stay unchanged.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_task_points_negative() {
+        use crate::handlers::groups::group_id::task_packages::task_package_id::tasks::tasks::update_task_points;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task_package_task", &created_user.id, &AccessType::Write);
+
+        let task_doc_id = Uuid::new_v4();
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id,
+            task_type: "Multiple-Choice".to_string(),
+        }], &created_groups[0].id);
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &task_package.id),
+                SCOPE,
+                update_task_points,
+                test_app.valid_authorizate(TestRequest::patch().set_json(serde_json::json!({
+                    "tasks": [{
+                        "task_doc_id": task_doc_id,
+                        "points": -1,
+                    }]
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let tasks = test_app.group_repo.fetch_tasks_from_package(&task_package.id, &created_groups[0].id, &None).unwrap();
+
+        assert_eq!(tasks[0].points, 1.0);
+    }
+}
\ No newline at end of file
@@ -1,12 +1,12 @@
-//TODO: test statistic
-
+//docu written with chat-gpt
 #[cfg(test)]
 mod fetch_task_packages_statistic_tests {
+    use std::collections::{HashMap, HashSet};
+
     use actix_web::{test::{TestRequest, self}, http};
-    use serde_json::Value;
     use uuid::Uuid;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, CreateGroupResponse, GroupInfoResponse, GroupMetaDataResponse}, util::{AccessType, PagingSchema, OrderDir}, task::TaskPagingResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, NewRoleAccessType, UpdateRoleAccesType}, task_package::TaskPackagesResponse}};
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, task::{NewTempTask, TaskPoints}, task_package::TaskPackageUserStatisticResponse}};
 
     static SCOPE: &'static str = "/api/groups/{group_id}/task_packages/{task_package_id}/users/{user_id}"; 
     
@@ -14,4 +14,75 @@ mod fetch_task_packages_statistic_tests {
         format!("/api/groups/{}/task_packages/{}/users/{}/statistic", group_id, task_package_id, user_id)
     }
 
+    /// # Test: `test_fetch_task_package_statistic_points`
+    ///
+    /// Validates that the statistic reports the points scored next to the amount of correct answers.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Read` permission for task package statistics.
+    ///    - Creates a task package with a task worth two points and a task worth one point.
+    ///    - Finishes a solution attempt with all points for the first and half of the points for the second task.
+    ///
+    /// 2. Execution:
+    ///    - Calls `fetch_task_package_statistic` for the user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies one answer is correct and 2.5 of 3 points are scored, as stored on the solution attempt.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_task_package_statistic_points() {
+        use crate::handlers::groups::group_id::task_packages::task_package_id::users::user_id::user_id::fetch_task_package_statistic;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task_package_statistic", &created_user.id, &AccessType::Read);
+
+        let task_doc_ids = [Uuid::new_v4(), Uuid::new_v4()];
+
+        let task_package = util::create_task_package(&test_app.group_repo, &task_doc_ids.iter().map(|task_doc_id| NewTempTask {
+            task_doc_id: *task_doc_id,
+            task_type: "Multiple-Choice".to_string(),
+        }).collect(), &created_groups[0].id);
+
+        test_app.group_repo.update_task_points(&task_package.id, &created_groups[0].id, &[TaskPoints {
+            task_doc_id: task_doc_ids[0],
+            points: 2.0,
+        }]).unwrap();
+
+        let solution_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let answer_scores: HashMap<Uuid, f64> = solution_attempt.solution_list.iter()
+            .map(|answer| (answer.answer_id, if answer.task_doc_id == task_doc_ids[0] { 1.0 } else { 0.5 }))
+            .collect();
+
+        test_app.group_repo.finish_solution_attempt(&solution_attempt.solution_attempt.id, &answer_scores, &HashSet::new()).unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&created_groups[0].id, &task_package.id, &created_user.id),
+                SCOPE,
+                fetch_task_package_statistic,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPackageUserStatisticResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.amount_tasks, 2);
+        assert_eq!(response.values.len(), 1);
+        assert_eq!(response.values[0].amount_correct, 1);
+        assert_eq!(response.values[0].score, 2.5);
+        assert_eq!(response.values[0].max_score, 3.0);
+
+        let finished_attempt = test_app.group_repo.fetch_solution_attempt(&solution_attempt.solution_attempt.id, &created_groups[0].id).unwrap();
+
+        assert_eq!(finished_attempt.solution_attempt.score, 2.5);
+        assert_eq!(finished_attempt.solution_attempt.max_score, 3.0);
+    }
 }
\ No newline at end of file
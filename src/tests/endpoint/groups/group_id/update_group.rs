@@ -0,0 +1,539 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod update_group_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::{NewUserPermission, GroupInfoResponse, NewGroup, CreateGroup}, util::{AccessType, JoinPolicy}}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups/{group_id}";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/groups/{}/", group_id)
+    }
+
+    /// Creates a chain of groups where every group is the parent of the next one.
+    fn create_group_chain(group_repo: &GroupRepo, user_id: &Uuid, names: &[&str]) -> Vec<CreateGroup> {
+        let mut groups: Vec<CreateGroup> = vec![];
+
+        for name in names {
+            let parent = groups.last().map(|group| group.id);
+            groups.push(group_repo.create_group(&NewGroup {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                created_from: *user_id,
+                updated_from: *user_id,
+                parent,
+            }).unwrap());
+        }
+
+        groups
+    }
+
+    fn create_group_permissions(test_app: &TestRepo, user_id: &Uuid, group_id: Option<Uuid>, access_types: Vec<AccessType>) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Create, AccessType::Read, AccessType::Write])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: *user_id,
+                group_id,
+                ressource: "group".to_string()
+            },
+            access_types.into_iter().map(|access_type| OptionalUserAccessType {
+                access_type,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }).collect())
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+    }
+
+    fn assert_ancestors(group_repo: &GroupRepo, group_id: &Uuid, expected: &[Uuid]) {
+        let mut ancestors = group_repo.fetch_group_ancestors(group_id).unwrap();
+        let mut expected = expected.to_vec();
+        ancestors.sort();
+        expected.sort();
+        assert_eq!(ancestors, expected);
+    }
+
+    /// # Test: `test_update_group_name`
+    ///
+    /// Validates that a user with the `Write` access type on groups can rename a group.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_group` with a new name.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and contains the new name with the unchanged parent.
+    ///    - Verifies `updated_from` of the group metadata is set to the user.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_name() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, other_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"name": "Renamed"})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupInfoResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, created_groups[0].id);
+        assert_eq!(response.name, "Renamed");
+        assert_eq!(response.parent, None);
+
+        let meta_data = test_app.group_repo.fetch_active_group_meta_data(created_groups[0].id).unwrap();
+        assert_eq!(meta_data.updated_from, created_user.id);
+        assert_eq!(meta_data.created_from, other_user.id);
+    }
+
+    /// # Test: `test_update_group_empty_name`
+    ///
+    /// Validates that a group cannot be renamed to an empty name.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_group` with a name containing only whitespace.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Bad Request" (400) and the name did not change.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_empty_name() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"name": "  "})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let group_info = test_app.group_repo.fetch_active_group_info(created_groups[0].id).unwrap();
+        assert_eq!(group_info.name, created_groups[0].name);
+    }
+
+    /// # Test: `test_update_group_join_policy`
+    ///
+    /// Validates that the join policy of a group can be changed without touching its name and parent.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_group` with the join policy "Request".
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the name did not change.
+    ///    - Verifies the group metadata contains the new join policy.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_join_policy() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        assert_eq!(test_app.group_repo.fetch_active_group_meta_data(created_groups[0].id).unwrap().join_policy, JoinPolicy::Closed);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"join_policy": "Request"})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupInfoResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.name, created_groups[0].name);
+
+        let meta_data = test_app.group_repo.fetch_active_group_meta_data(created_groups[0].id).unwrap();
+        assert_eq!(meta_data.join_policy, JoinPolicy::Request);
+    }
+
+    /// # Test: `test_move_group_deep_hierarchy`
+    ///
+    /// Validates that moving a group recomputes the ancestors of the whole moved subtree.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchies `A > B > C > D` and `E > F`.
+    ///    - Grants the user a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Moves `B` below `F`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the new parent is `F`.
+    ///    - Verifies the ancestors of `B`, `C` and `D` now end in `F > E` and no longer contain `A`.
+    ///    - Verifies the ancestors of `A`, `E` and `F` did not change.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_deep_hierarchy() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let chain = create_group_chain(&test_app.group_repo, &created_user.id, &["A", "B", "C", "D"]);
+        let other_chain = create_group_chain(&test_app.group_repo, &created_user.id, &["E", "F"]);
+        let (a, b, c, d) = (chain[0].id, chain[1].id, chain[2].id, chain[3].id);
+        let (e, f) = (other_chain[0].id, other_chain[1].id);
+
+        assert_ancestors(&test_app.group_repo, &d, &[a, b, c]);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&b),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": f})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupInfoResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, b);
+        assert_eq!(response.name, "B");
+        assert_eq!(response.parent, Some(f));
+
+        assert_ancestors(&test_app.group_repo, &a, &[]);
+        assert_ancestors(&test_app.group_repo, &b, &[e, f]);
+        assert_ancestors(&test_app.group_repo, &c, &[e, f, b]);
+        assert_ancestors(&test_app.group_repo, &d, &[e, f, b, c]);
+        assert_ancestors(&test_app.group_repo, &e, &[]);
+        assert_ancestors(&test_app.group_repo, &f, &[e]);
+    }
+
+    /// # Test: `test_move_group_to_top_level`
+    ///
+    /// Validates that `parent: null` moves a group and its subgroups to the top level.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `A > B > C`.
+    ///    - Grants the user global `Write` and `Create` permissions on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Moves `B` to the top level.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and `B` has no parent.
+    ///    - Verifies `B` has no ancestors and `C` only keeps `B`.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_to_top_level() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let chain = create_group_chain(&test_app.group_repo, &created_user.id, &["A", "B", "C"]);
+        let (b, c) = (chain[1].id, chain[2].id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write, AccessType::Create]);
+
+        let resp = test_app
+        .call(
+            &get_path(&b),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": Value::Null})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupInfoResponse = test::read_body_json(resp).await;
+        assert_eq!(response.parent, None);
+
+        assert_ancestors(&test_app.group_repo, &b, &[]);
+        assert_ancestors(&test_app.group_repo, &c, &[b]);
+    }
+
+    /// # Test: `test_move_group_to_top_level_no_create_permission`
+    ///
+    /// Validates that moving a group to the top level requires the global `Create` access type.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `A > B`.
+    ///    - Grants the user a `Write` permission on the "group" ressource scoped to `B`.
+    ///
+    /// 2. Execution:
+    ///    - Moves `B` to the top level.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Forbidden" (403) and `B` keeps its parent.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_to_top_level_no_create_permission() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let chain = create_group_chain(&test_app.group_repo, &created_user.id, &["A", "B"]);
+        let (a, b) = (chain[0].id, chain[1].id);
+
+        create_group_permissions(&test_app, &created_user.id, Some(b), vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&b),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": Value::Null})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        let group_info = test_app.group_repo.fetch_active_group_info(b).unwrap();
+        assert_eq!(group_info.parent, Some(a));
+    }
+
+    /// # Test: `test_move_group_into_own_subtree`
+    ///
+    /// Validates that a group cannot be moved below one of its own subgroups.
+    ///
+    /// 1. Setup:
+    ///    - Creates the hierarchy `A > B > C`.
+    ///    - Grants the user a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Tries to move `A` below `C`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Conflict" (409).
+    ///    - Verifies the ancestors of all groups did not change.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_into_own_subtree() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let chain = create_group_chain(&test_app.group_repo, &created_user.id, &["A", "B", "C"]);
+        let (a, b, c) = (chain[0].id, chain[1].id, chain[2].id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&a),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": c})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+
+        assert_ancestors(&test_app.group_repo, &a, &[]);
+        assert_ancestors(&test_app.group_repo, &b, &[a]);
+        assert_ancestors(&test_app.group_repo, &c, &[a, b]);
+    }
+
+    /// # Test: `test_move_group_into_itself`
+    ///
+    /// Validates that a group cannot become its own parent.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Tries to set the parent of the group to the group itself.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Conflict" (409) and the group keeps no parent.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_into_itself() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": created_groups[0].id})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+
+        let group_info = test_app.group_repo.fetch_active_group_info(created_groups[0].id).unwrap();
+        assert_eq!(group_info.parent, None);
+    }
+
+    /// # Test: `test_move_group_to_not_existing_parent`
+    ///
+    /// Validates that a group cannot be moved below a group that does not exist.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and a global `Write` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Tries to move the group below a random id.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_move_group_to_not_existing_parent() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"parent": Uuid::new_v4()})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_update_not_existing_group`
+    ///
+    /// Validates that updating a group that does not exist returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_not_existing_group() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Write]);
+
+        let resp = test_app
+        .call(
+            &get_path(&Uuid::new_v4()),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"name": "Renamed"})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_update_group_no_permission`
+    ///
+    /// Validates that a user without the `Write` access type cannot update a group.
+    ///
+    /// 1. Setup:
+    ///    - Creates a group and a global `Read` permission on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_group` with a new name.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Forbidden" (403) and the name did not change.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_group_no_permission() {
+        use crate::handlers::groups::group_id::group_id::update_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_permissions(&test_app, &created_user.id, None, vec![AccessType::Read]);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            update_group,
+            test_app.valid_authorizate(TestRequest::patch(), &created_user.id)
+                .set_json(serde_json::json!({"name": "Renamed"})),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        let group_info = test_app.group_repo.fetch_active_group_info(created_groups[0].id).unwrap();
+        assert_eq!(group_info.name, created_groups[0].name);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod create_group;
+pub mod search_groups;
 pub mod group_id;
\ No newline at end of file
@@ -0,0 +1,260 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod search_groups_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{groups::{NewGroup, GroupSearchResponse, CreateGroup}, group_settings::GroupSettings, util::AccessType}, repository::group::GroupRepo};
+
+    static SCOPE: &str = "/api/groups";
+
+    fn get_path(query: &str) -> String {
+        format!("/api/groups/search?{}", query)
+    }
+
+    fn create_named_groups(group_repo: &GroupRepo, names: &[&str], parent: Option<Uuid>, user_id: &Uuid) -> Vec<CreateGroup> {
+        util::create_groups(group_repo, names.iter().map(|name| NewGroup {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            created_from: *user_id,
+            updated_from: *user_id,
+            parent,
+        }).collect())
+    }
+
+    fn set_settings(group_repo: &GroupRepo, group_id: &Uuid, description: Option<&str>, catalogue_visible: bool, user_id: &Uuid) {
+        group_repo.update_group_settings(group_id, &GroupSettings {
+            description: description.map(str::to_string),
+            catalogue_visible,
+            ..Default::default()
+        }, user_id).unwrap();
+    }
+
+    /// # Test: `test_search_groups`
+    ///
+    /// Validates that groups are found by words, prefixes and parts of their name and description,
+    /// ordered by relevance.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Read` permission for groups.
+    ///    - Creates the groups "Angewandte Mathematik", "Mathematik", "Theoretische Informatik" and "Physik",
+    ///      the description of "Physik" mentions "Informatik".
+    ///
+    /// 2. Execution:
+    ///    - Searches for "mathe", "theo inf", "informatik" and without search text.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies "Mathematik" is ranked before "Angewandte Mathematik" as its name starts with the search text.
+    ///    - Verifies every word of the search text has to match.
+    ///    - Verifies the description is searched and returned.
+    ///    - Verifies all groups are found ordered by name without search text.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_search_groups() {
+        use crate::handlers::groups::groups::search_groups;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Read);
+
+        let created_groups = create_named_groups(
+            &test_app.group_repo,
+            &["Angewandte Mathematik", "Mathematik", "Theoretische Informatik", "Physik"],
+            None,
+            &created_user.id,
+        );
+
+        set_settings(&test_app.group_repo, &created_groups[3].id, Some("Physik für Informatik"), false, &created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path("q=mathe"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.groups[0].id, created_groups[1].id);
+        assert_eq!(response.groups[1].id, created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path("q=theo%20inf"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.groups[0].id, created_groups[2].id);
+
+        let resp = test_app
+        .call(
+            &get_path("q=informatik"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        let physics = response.groups.iter().find(|group| group.id == created_groups[3].id).unwrap();
+        assert_eq!(physics.description, Some("Physik für Informatik".to_string()));
+
+        let resp = test_app
+        .call(
+            &get_path("limit=3"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 4);
+        assert_eq!(
+            response.groups.iter().map(|group| group.name.as_str()).collect::<Vec<_>>(),
+            vec!["Angewandte Mathematik", "Mathematik", "Physik"]
+        );
+    }
+
+    /// # Test: `test_search_groups_catalogue`
+    ///
+    /// Validates that a user without the global `Read` permission only finds groups listed in the course catalogue.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and two groups, only the first group is listed in the catalogue.
+    ///
+    /// 2. Execution:
+    ///    - Searches all groups as the second user, once without and once with `catalogue=false`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies only the listed group is found.
+    ///    - Verifies no group is found when searching unlisted groups.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_search_groups_catalogue() {
+        use crate::handlers::groups::groups::search_groups;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 2, created_user.id);
+
+        set_settings(&test_app.group_repo, &created_groups[0].id, None, true, &created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path("q=gruppe"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.groups[0].id, created_groups[0].id);
+        assert!(response.groups[0].catalogue_visible);
+        assert!(!response.groups[0].member);
+
+        let resp = test_app
+        .call(
+            &get_path("catalogue=false"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &other_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 0);
+    }
+
+    /// # Test: `test_search_groups_ancestor_and_member`
+    ///
+    /// Validates the filters for the ancestor and the membership of the user.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Read` permission for groups.
+    ///    - Creates a group with two subgroups and another top level group, the user is a member of the first subgroup.
+    ///
+    /// 2. Execution:
+    ///    - Searches the groups below the first group, the groups the user is a member of and the groups
+    ///      below the first group the user is not a member of.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies each search returns the matching groups.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_search_groups_ancestor_and_member() {
+        use crate::handlers::groups::groups::search_groups;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "group", &created_user.id, &AccessType::Read);
+
+        let created_groups = create_named_groups(&test_app.group_repo, &["Informatik", "Physik"], None, &created_user.id);
+        let sub_groups = create_named_groups(&test_app.group_repo, &["Übung 1", "Übung 2"], Some(created_groups[0].id), &created_user.id);
+
+        util::add_member_to_group(&test_app.group_repo, &created_user.id, &sub_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&format!("ancestor={}", created_groups[0].id)),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 2);
+        assert!(response.groups.iter().all(|group| group.parent == Some(created_groups[0].id)));
+
+        let resp = test_app
+        .call(
+            &get_path("member=true"),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.groups[0].id, sub_groups[0].id);
+        assert!(response.groups[0].member);
+
+        let resp = test_app
+        .call(
+            &get_path(&format!("ancestor={}&member=false", created_groups[0].id)),
+            SCOPE,
+            search_groups,
+            test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+        )
+        .await;
+        let response: GroupSearchResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.groups[0].id, sub_groups[1].id);
+    }
+}
@@ -341,4 +341,68 @@ mod create_schema_tests {
 
         assert_eq!(schemas.len(), 0);
     }
+
+    /// # Test: `test_create_schema_validation_errors`
+    ///
+    /// Validates that a schema which cannot be compiled is rejected with every error against its meta schema listed.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Create` permission for schemas.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_schema` with a task schema using an unknown type and a string as minimum.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "BAD REQUEST" (400) and both errors are listed with their JSON pointers.
+    ///    - Verifies no schema was created.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_schema_validation_errors() {
+        use crate::{handlers::tasks::tasks::create_schema, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "schema", &created_user.id, &AccessType::Create);
+
+        let (_, solution_schema) = util::create_example_schema();
+
+        let resp = test_app
+            .call(
+                &get_path(),
+                SCOPE,
+                create_schema,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Multiple-Choice",
+                    "task_schema": {
+                        "type": "objec",
+                        "properties": {
+                            "answers": {
+                                "type": "integer",
+                                "minimum": "0",
+                            }
+                        }
+                    },
+                    "solution_schema": solution_schema,
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "Task schema could not be parsed");
+        assert_eq!(response.errors.len(), 2);
+        assert!(response.errors.iter().any(|error| error.instance_path == "/type"));
+        assert!(response.errors.iter().any(|error| error.instance_path == "/properties/answers/minimum" && error.keyword == "type"));
+
+        let schemas = test_app.app_state.mongodb.fetch_all_schemas(&PagingSchema {
+            limit: 200,
+            page: 0,
+            order: OrderDir::DESC,
+        }, None).await.unwrap();
+
+        assert_eq!(schemas.len(), 0);
+    }
 }
\ No newline at end of file
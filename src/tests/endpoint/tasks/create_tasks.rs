@@ -53,6 +53,7 @@ mod create_tasks_tests {
     /// 2. Assertions:
     ///    - Verifies that the response status is "OK" (200), indicating that the task creation was successful.
     ///    - Fetches all tasks from the MongoDB database and compares the result with the created task from the API response to ensure consistency.
+    ///    - Verifies that the user is recorded as the author of the task along with its creation date.
     ///
     /// 3. Cleanup:
     ///    - Clears the MongoDB database to leave no side effects.
@@ -116,6 +117,8 @@ mod create_tasks_tests {
         assert_eq!(&response.task.task, &task[0].task);
         assert_eq!(&response.task.solution, &task[0].solution);
         assert_eq!(&response.task.id, &task[0].id);
+        assert_eq!(task[0].author, Some(created_user.id));
+        assert!(task[0].created_at.is_some());
     }
 
     /// # Test: `test_create_tasks_no_schema`
@@ -306,4 +309,616 @@ mod create_tasks_tests {
 
         assert_eq!(task.len(), 0);
     }
-}
\ No newline at end of file
+
+    /// # Test: `test_create_tasks_validation_errors`
+    ///
+    /// Validates that a task failing its schema is rejected with every validation error listed.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Create` permission for tasks and a schema.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_task` with a task missing the question, with a misspelled property and a number as answer.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "BAD REQUEST" (400) and the task is reported as having the wrong format.
+    ///    - Verifies the `required`, `additionalProperties` and `type` errors are listed with their JSON pointers.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_validation_errors() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Multiple-Choice",
+                    "task": {
+                        "questio": "h",
+                        "answers": ["f", 4],
+                    },
+                    "solution": {
+                        "solution": 0,
+                    }
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "task has wrong format");
+        assert_eq!(response.errors.len(), 3);
+        assert!(response.errors.iter().any(|error| error.keyword == "required" && error.instance_path.is_empty()));
+        assert!(response.errors.iter().any(|error| error.keyword == "additionalProperties" && error.instance_path.is_empty()));
+        assert!(response.errors.iter().any(|error| error.keyword == "type" && error.instance_path == "/answers/1"
+            && error.schema_path == "/properties/answers/items/type"));
+    }
+
+    fn create_cyk_body(productions: Value) -> Value {
+        serde_json::json!({
+            "task_type": "CYK-Algorithm",
+            "task": {
+                "V": ["S", "A", "B", "C"],
+                "T": ["a", "b"],
+                "P": productions,
+                "w": "abba",
+                "S": "S",
+            },
+        })
+    }
+
+    /// # Test: `test_create_tasks_cyk_computed_solution`
+    ///
+    /// Validates that the solution of a CYK task is computed from its grammar and word instead of being written by hand.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Create` permission for tasks and the shipped CYK schema.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_task` with a grammar in Chomsky normal form and without a solution.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the stored table has a cell per substring of the word, with the start symbol deriving the whole word.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_cyk_computed_solution() {
+        use crate::handlers::tasks::tasks::create_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "CYK-Algorithm").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_cyk_body(serde_json::json!([
+                    { "l": "S", "r": [["B", "A"], ["C", "A"], ["b"]] },
+                    { "l": "A", "r": [["B", "A"], ["a"]] },
+                    { "l": "B", "r": [["C", "C"], ["b"]] },
+                    { "l": "C", "r": [["A", "B"], ["a"]] },
+                ]))), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: CreateTaskResponse = test::read_body_json(resp).await;
+
+        let table = response.task.solution.get_array("solution").unwrap();
+
+        assert_eq!(table.len(), 10);
+        assert_eq!(table[0].as_document().unwrap(), &doc!{ "A": null, "C": null });
+        assert_eq!(table[5].as_document().unwrap(), &doc!{});
+        assert_eq!(table[9].as_document().unwrap(), &doc!{ "S": null });
+    }
+
+    /// # Test: `test_create_tasks_cyk_overlapping_terminals`
+    ///
+    /// Validates that the word of a CYK task is split into terminals even if the longest terminal at a position
+    /// does not lead to a split, e.g. `abc` with the terminals `a`, `ab` and `bc`.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_cyk_overlapping_terminals() {
+        use crate::handlers::tasks::tasks::create_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "CYK-Algorithm").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "CYK-Algorithm",
+                    "task": {
+                        "V": ["S", "A", "B"],
+                        "T": ["a", "ab", "bc"],
+                        "P": [
+                            { "l": "S", "r": [["A", "B"]] },
+                            { "l": "A", "r": [["a"]] },
+                            { "l": "B", "r": [["bc"]] },
+                        ],
+                        "w": "abc",
+                        "S": "S",
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: CreateTaskResponse = test::read_body_json(resp).await;
+
+        let table = response.task.solution.get_array("solution").unwrap();
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[0].as_document().unwrap(), &doc!{ "A": null });
+        assert_eq!(table[1].as_document().unwrap(), &doc!{ "B": null });
+        assert_eq!(table[2].as_document().unwrap(), &doc!{ "S": null });
+    }
+
+    /// # Test: `test_create_tasks_cyk_not_cnf`
+    ///
+    /// Validates that a CYK task whose grammar is not in Chomsky normal form is rejected with "BAD REQUEST" (400),
+    /// listing the productions violating it.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_cyk_not_cnf() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "CYK-Algorithm").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_cyk_body(serde_json::json!([
+                    { "l": "S", "r": [["B", "A", "C"], ["b"]] },
+                    { "l": "A", "r": [["a", "B"], ["a"]] },
+                    { "l": "B", "r": [["b"]] },
+                    { "l": "C", "r": [["a"]] },
+                ]))), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "task is invalid");
+        assert_eq!(response.errors.len(), 2);
+        assert!(response.errors.iter().any(|error| error.instance_path == "/P/0/r/0"));
+        assert!(response.errors.iter().any(|error| error.instance_path == "/P/1/r/0"));
+
+        let task = test_app.app_state.mongodb.fetch_all_tasks(&PagingSchema {
+            limit: 200,
+            page: 0,
+            order: OrderDir::DESC,
+        }, None, true).await.unwrap();
+
+        assert_eq!(task.len(), 0);
+    }
+
+    fn create_template_body(parameters: Value) -> Value {
+        serde_json::json!({
+            "task_type": "Multiple-Choice",
+            "task": {
+                "question": "Was ist {{a}} * {{b}}?",
+                "answers": ["{{a * b}} Bits", "{{a + b}} Bits"],
+            },
+            "solution": {
+                "solution": 0,
+            },
+            "parameters": parameters,
+        })
+    }
+
+    /// # Test: `test_create_tasks_template`
+    ///
+    /// Validates that a task template is stored with its parameter definitions and its placeholders.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Create` permission for tasks and the shipped multiple-choice schema.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_task` with a multiple-choice question whose factors are integer parameters.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the stored task keeps its placeholders and parameter definitions, so every student gets another instance.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_template() {
+        use crate::handlers::tasks::tasks::create_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Multiple-Choice").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_template_body(serde_json::json!({
+                    "a": { "type": "integer", "min": 2, "max": 9 },
+                    "b": { "type": "integer", "min": 2, "max": 9 },
+                }))), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: CreateTaskResponse = test::read_body_json(resp).await;
+
+        let task = test_app.app_state.mongodb.fetch_task_revision(response.task.id, response.task.revision).await.unwrap().unwrap();
+
+        assert_eq!(task.task.get_str("question").unwrap(), "Was ist {{a}} * {{b}}?");
+        assert_eq!(task.parameters.as_ref().unwrap().keys().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let instance = test_app.app_state.generators.instantiate(&task, 42, &test_app.app_state.graders).unwrap();
+
+        assert!(!instance.task.get_str("question").unwrap().contains("{{"));
+        assert!(instance.parameters.is_none());
+    }
+
+    /// # Test: `test_create_tasks_template_invalid_parameters`
+    ///
+    /// Validates that a task template with a parameter without any values is rejected with "BAD REQUEST" (400),
+    /// listing the invalid parameter.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_template_invalid_parameters() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Multiple-Choice").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_template_body(serde_json::json!({
+                    "a": { "type": "integer", "min": 9, "max": 2 },
+                    "b": { "type": "integer", "min": 2, "max": 9 },
+                }))), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "parameters are invalid");
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].instance_path, "/parameters/a");
+    }
+
+    /// # Test: `test_create_tasks_template_too_long_word`
+    ///
+    /// Validates that a task template drawing longer words than allowed is rejected with "BAD REQUEST" (400) before
+    /// any word is drawn.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_template_too_long_word() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Multiple-Choice").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_template_body(serde_json::json!({
+                    "a": { "type": "integer", "min": 2, "max": 9 },
+                    "b": { "type": "integer", "min": 2, "max": 9 },
+                    "w": { "type": "word", "alphabet": ["0", "1"], "min_length": 1, "max_length": 1_000_000_000_000u64 },
+                }))), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "parameters are invalid");
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].instance_path, "/parameters/w");
+    }
+
+    /// # Test: `test_create_tasks_template_nested_too_deeply`
+    ///
+    /// Validates that a placeholder nesting more parentheses than allowed is rejected with "BAD REQUEST" (400)
+    /// instead of overflowing the stack.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_template_nested_too_deeply() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Multiple-Choice").await;
+
+        let mut body = create_template_body(serde_json::json!({
+            "a": { "type": "integer", "min": 2, "max": 9 },
+            "b": { "type": "integer", "min": 2, "max": 9 },
+        }));
+        body["task"]["question"] = Value::String(format!("Was ist {{{{{}a{}}}}}?", "(".repeat(100_000), ")".repeat(100_000)));
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(body), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "parameters are invalid");
+        assert_eq!(response.errors[0].instance_path, "/question");
+        assert_eq!(response.errors[0].message, "Expression is nested too deeply");
+    }
+
+    /// # Test: `test_create_tasks_short_text_invalid_pattern`
+    ///
+    /// Validates that a short text task whose solution accepts answers by a regular expression which does not compile
+    /// is rejected with "BAD REQUEST" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_short_text_invalid_pattern() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Short-Text").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Short-Text",
+                    "task": {
+                        "question": "Wofür steht die Abkürzung CPU?",
+                    },
+                    "solution": {
+                        "solution": "Central Processing Unit",
+                        "pattern": "(zentrale recheneinheit",
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "solution is invalid");
+        assert_eq!(response.errors[0].instance_path, "/pattern");
+    }
+
+    /// # Test: `test_create_tasks_ordering_not_permutation`
+    ///
+    /// Validates that an ordering task whose solution does not list every item exactly once is rejected with
+    /// "BAD REQUEST" (400), listing the positions of the repeated items.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_ordering_not_permutation() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Ordering").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Ordering",
+                    "task": {
+                        "question": "Ordne die Einheiten aufsteigend nach ihrer Größe.",
+                        "items": ["Kilobyte", "Bit", "Byte"],
+                    },
+                    "solution": {
+                        "solution": [1, 2, 2],
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "solution is invalid");
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].instance_path, "/solution/2");
+    }
+
+    /// # Test: `test_create_tasks_programming_without_tests`
+    ///
+    /// Validates that a programming task whose solution has no test cases to grade answers with is rejected with
+    /// "BAD REQUEST" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_programming_without_tests() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "Programming").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Programming",
+                    "task": {
+                        "question": "Schreibe ein Programm, das zwei ganze Zahlen von der Standardeingabe liest und ihre Summe ausgibt.",
+                        "language": "python",
+                    },
+                    "solution": {
+                        "code": "a, b = map(int, input().split())\nprint(a + b)\n",
+                        "tests": [],
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "solution is invalid");
+        assert_eq!(response.errors[0].instance_path, "/tests");
+    }
+
+    /// # Test: `test_create_tasks_sql_modifying_solution`
+    ///
+    /// Validates that an SQL task whose reference query modifies the database instead of reading it is rejected with
+    /// "BAD REQUEST" (400).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_sql_modifying_solution() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "SQL").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "SQL",
+                    "task": {
+                        "question": "Gib die Namen aller Studierenden aus, die mindestens im dritten Semester sind.",
+                        "schema": "CREATE TABLE studierende (id INTEGER PRIMARY KEY, name TEXT NOT NULL, semester INTEGER NOT NULL);",
+                    },
+                    "solution": {
+                        "query": "DELETE FROM studierende WHERE semester < 3;",
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "solution is invalid");
+        assert_eq!(response.errors[0].instance_path, "/query");
+        assert_eq!(response.errors[0].message, "Only SELECT queries are allowed");
+    }
+
+    /// # Test: `test_create_tasks_sql_endless_data`
+    ///
+    /// Validates that an SQL task whose data runs an endless recursive query is interrupted and rejected with
+    /// "BAD REQUEST" (400) instead of blocking the server.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_tasks_sql_endless_data() {
+        use crate::{handlers::tasks::tasks::create_task, models::validation::ValidationErrorSchema};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_asset_schema(&test_app.app_state.mongodb, "SQL").await;
+
+        let resp = test_app
+            .call(
+                &get_path(""),
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "SQL",
+                    "task": {
+                        "question": "Wie viele Zahlen gibt es?",
+                        "schema": "CREATE TABLE zahlen (anzahl INTEGER NOT NULL);",
+                        "data": "INSERT INTO zahlen SELECT COUNT(*) FROM (WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n) SELECT x FROM n);",
+                    },
+                    "solution": {
+                        "query": "SELECT anzahl FROM zahlen;",
+                    },
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let response: ValidationErrorSchema = test::read_body_json(resp).await;
+
+        assert_eq!(response.message, "task is invalid");
+        assert_eq!(response.errors[0].instance_path, "/data");
+    }
+}
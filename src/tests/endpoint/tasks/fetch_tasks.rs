@@ -2,8 +2,9 @@
 #[cfg(test)]
 mod fetch_tasks_tests {
     use actix_web::{test::{TestRequest, self}, http};
+    use mongodb::bson::doc;
 
-    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::NewUserPermission, util::AccessType, task::TaskPagingResponse}};
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::{NewRessource, OptionalUserAccessType}, groups::NewUserPermission, util::AccessType, task::{TaskPagingResponse, TaskMetadata, Difficulty, FacetCount}}};
 
     static SCOPE: &'static str = "/api/tasks"; 
     
@@ -242,6 +243,356 @@ mod fetch_tasks_tests {
         test_app.app_state.mongodb.clear_db().await;
     }
 
+    /// # Test: `test_fetch_tasks_by_metadata`
+    ///
+    /// Validates the behavior of the `fetch_tasks` handler in Actix-Web when filtering tasks by their metadata. The test covers the following steps:
+    ///
+    /// 1. Setup:
+    ///    - Creates a test environment with a `TestRepo` instance.
+    ///    - Generates a standard test user (`created_user`) with read permission for tasks and three tasks, two of them tagged with `bits` at different difficulties and one without metadata.
+    ///
+    /// 2. Test Execution:
+    ///    - Makes an API call to `fetch_tasks` filtering by the tag `bits`.
+    ///    - Makes an API call to `fetch_tasks` filtering by the tag `bits` and the difficulty `Easy`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies that both tagged tasks match the tag and that the facets count them by tag, topic, difficulty and language.
+    ///    - Verifies that only the easy task matches both filters while the total count still counts every task.
+    ///
+    /// 4. Cleanup:
+    ///    - Clears the MongoDB database to leave no side effects.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_tasks_by_metadata() {
+        use crate::handlers::tasks::tasks::fetch_tasks;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let easy_task = util::create_task_with_metadata(&test_app.app_state.mongodb, "Wie viele Bits ergeben ein Byte?", TaskMetadata {
+            tags: vec!["bits".to_string(), "bytes".to_string()],
+            topic: Some("Datendarstellung".to_string()),
+            difficulty: Some(Difficulty::Easy),
+            language: Some("de".to_string()),
+        }).await;
+        util::create_task_with_metadata(&test_app.app_state.mongodb, "How many bits does an IPv4 address have?", TaskMetadata {
+            tags: vec!["bits".to_string()],
+            topic: Some("Rechnernetze".to_string()),
+            difficulty: Some(Difficulty::Medium),
+            language: Some("en".to_string()),
+        }).await;
+        util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Task".to_string(),
+                key_value: &"task".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: created_user.id,
+                group_id: None,
+                ressource: "task".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }],)
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+
+        let resp = test_app
+            .call(
+                &get_path("?tags[]=bits"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        let count = |value: &str, count: u64| FacetCount { value: value.to_string(), count };
+
+        assert_eq!(response.total_count, 3);
+        assert_eq!(response.match_count, 2);
+        assert_eq!(response.tasks.len(), 2);
+        assert_eq!(response.facets.task_types, vec![count("Multiple-Choice", 2)]);
+        assert_eq!(response.facets.tags, vec![count("bits", 2), count("bytes", 1)]);
+        assert_eq!(response.facets.topics, vec![count("Datendarstellung", 1), count("Rechnernetze", 1)]);
+        assert_eq!(response.facets.difficulties, vec![count("Easy", 1), count("Medium", 1)]);
+        assert_eq!(response.facets.languages, vec![count("de", 1), count("en", 1)]);
+
+        let resp = test_app
+            .call(
+                &get_path("?tags[]=bits&difficulty=Easy"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 3);
+        assert_eq!(response.match_count, 1);
+        assert_eq!(response.tasks.len(), 1);
+        util::assert_task(&response.tasks[0], &easy_task);
+        assert_eq!(response.tasks[0].metadata, easy_task.metadata);
+        test_app.app_state.mongodb.clear_db().await;
+    }
+
+    /// # Test: `test_fetch_tasks_by_search`
+    ///
+    /// Validates the behavior of the `fetch_tasks` handler in Actix-Web when searching tasks by text. The test covers the following steps:
+    ///
+    /// 1. Setup:
+    ///    - Creates a test environment with a `TestRepo` instance.
+    ///    - Generates a standard test user (`created_user`) with read permission for tasks and two tasks with different questions.
+    ///
+    /// 2. Test Execution:
+    ///    - Makes an API call to `fetch_tasks` searching for a word of the question of one task.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies that the response status is "OK" (200) and that only the task containing the word is returned.
+    ///
+    /// 4. Cleanup:
+    ///    - Clears the MongoDB database to leave no side effects.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_tasks_by_search() {
+        use crate::handlers::tasks::tasks::fetch_tasks;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_task = util::create_task_with_metadata(&test_app.app_state.mongodb, "Wie viele Bytes ergeben ein Kibibyte?", TaskMetadata::default()).await;
+        util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Task".to_string(),
+                key_value: &"task".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: created_user.id,
+                group_id: None,
+                ressource: "task".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }],)
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+
+        let resp = test_app
+            .call(
+                &get_path("?search=Kibibyte"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.match_count, 1);
+        assert_eq!(response.tasks.len(), 1);
+        util::assert_task(&response.tasks[0], &created_task);
+        test_app.app_state.mongodb.clear_db().await;
+    }
+
+    /// # Test: `test_fetch_tasks_by_search_not_in_solution`
+    ///
+    /// Validates that the text search of the `fetch_tasks` handler does not look into the solutions of tasks, so it cannot be used to find the task a guessed solution belongs to. The test covers the following steps:
+    ///
+    /// 1. Setup:
+    ///    - Creates a test environment with a `TestRepo` instance.
+    ///    - Generates a standard test user (`created_user`) with read permission for tasks and a `Short-Text` task whose solution contains a word which occurs nowhere else.
+    ///
+    /// 2. Test Execution:
+    ///    - Makes an API call to `fetch_tasks` searching for the word of the solution.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies that the response status is "OK" (200) and that no task matches.
+    ///
+    /// 4. Cleanup:
+    ///    - Clears the MongoDB database to leave no side effects.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_tasks_by_search_not_in_solution() {
+        use crate::handlers::tasks::tasks::fetch_tasks;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        test_app.app_state.mongodb.create_task("Short-Text".to_string(), doc!{
+            "question": "Wofür steht die Abkürzung CPU?",
+        }, doc!{
+            "solution": "Zentraleinheit",
+        }, None, 1, TaskMetadata::default(), None).await.unwrap();
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Task".to_string(),
+                key_value: &"task".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: created_user.id,
+                group_id: None,
+                ressource: "task".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }],)
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+
+        let resp = test_app
+            .call(
+                &get_path("?search=Zentraleinheit"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.total_count, 1);
+        assert_eq!(response.match_count, 0);
+        assert!(response.tasks.is_empty());
+        test_app.app_state.mongodb.clear_db().await;
+    }
+
+    /// # Test: `test_fetch_tasks_sorted_by_creation_date`
+    ///
+    /// Validates the behavior of the `fetch_tasks` handler in Actix-Web when sorting tasks by their creation date. The test covers the following steps:
+    ///
+    /// 1. Setup:
+    ///    - Creates a test environment with a `TestRepo` instance.
+    ///    - Generates a standard test user (`created_user`) with read permission for tasks and two tasks created one after the other.
+    ///
+    /// 2. Test Execution:
+    ///    - Makes API calls to `fetch_tasks` sorting by `created_at` in ascending and in descending order.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies that the tasks are returned oldest first in ascending and newest first in descending order.
+    ///
+    /// 4. Cleanup:
+    ///    - Clears the MongoDB database to leave no side effects.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_fetch_tasks_sorted_by_creation_date() {
+        use crate::handlers::tasks::tasks::fetch_tasks;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let first_task = util::create_task_mc(&test_app.app_state.mongodb).await;
+        actix_web::rt::time::sleep(std::time::Duration::from_millis(10)).await;
+        let second_task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Task".to_string(),
+                key_value: &"task".to_string(),
+            }, vec![AccessType::Read])
+            ],
+        );
+
+        let user_permission_list: Vec<(NewUserPermission, Vec<OptionalUserAccessType>)> = vec![(NewUserPermission {
+                user_id: created_user.id,
+                group_id: None,
+                ressource: "task".to_string()
+            },
+            vec![OptionalUserAccessType {
+                access_type: AccessType::Read,
+                permission: Some(true),
+                set_permission: None,
+                set_set_permission: None,
+            }],)
+        ];
+
+        util::create_permissions_for_user(
+            &test_app.group_repo,
+            &user_permission_list,
+        );
+
+        let resp = test_app
+            .call(
+                &get_path("?sort=created_at&order=ASC"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.tasks.len(), 2);
+        util::assert_task(&response.tasks[0], &first_task);
+        util::assert_task(&response.tasks[1], &second_task);
+
+        let resp = test_app
+            .call(
+                &get_path("?sort=created_at&order=DESC"),
+                SCOPE,
+                fetch_tasks,
+                test_app.valid_authorizate(TestRequest::get(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: TaskPagingResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.tasks.len(), 2);
+        util::assert_task(&response.tasks[0], &second_task);
+        util::assert_task(&response.tasks[1], &first_task);
+        test_app.app_state.mongodb.clear_db().await;
+    }
+
     /// # Test: `test_fetch_tasks_no_permission`
     ///
     /// Validates the behavior of the `fetch_tasks` handler in Actix-Web when a user lacks the necessary permissions to read tasks. The test covers the following steps:
@@ -2,4 +2,4 @@ pub mod fetch_tasks;
 pub mod create_schema;
 pub mod fetch_schemas;
 pub mod create_tasks;
-pub mod task_id;
\ No newline at end of file
+pub mod task_id;pub mod schema_versions;
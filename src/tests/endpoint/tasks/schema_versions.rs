@@ -0,0 +1,267 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod schema_versions_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use mongodb::bson::doc;
+    use serde_json::Value;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, task::{NewTempTask, SchemaCheckResponse, TaskMetadata}}, repository::mongodb::SchemaDoc};
+
+    static SCOPE: &str = "/api/tasks";
+
+    fn get_path(task_type: &str, action: &str) -> String {
+        format!("/api/tasks/schemas/{}/{}", task_type, action)
+    }
+
+    /// The example schema of `util::create_example_schema` with a solution limited to the first ten answers.
+    fn create_limited_body() -> Value {
+        let (task_schema, mut solution_schema) = util::create_example_schema();
+
+        solution_schema.insert("properties", doc!{
+            "solution": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 9,
+            }
+        });
+
+        serde_json::json!({
+            "task_schema": task_schema,
+            "solution_schema": solution_schema,
+        })
+    }
+
+    /// # Test: `test_create_schema_version`
+    ///
+    /// Validates that a new schema version is stored next to the existing one and used for new tasks only.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for schemas, a schema and a task validated against it.
+    ///
+    /// 2. Execution:
+    ///    - Calls `create_schema_version` for the task type.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and version 2 is returned and fetched as latest schema.
+    ///    - Verifies version 1 and the task recording it are unchanged.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_create_schema_version() {
+        use crate::handlers::tasks::tasks::create_schema_version;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "schema", &created_user.id, &AccessType::Write);
+
+        let schema = util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let resp = test_app
+            .call(
+                &get_path("Multiple-Choice", "versions"),
+                SCOPE,
+                create_schema_version,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_limited_body()), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: SchemaDoc = test::read_body_json(resp).await;
+
+        assert_eq!(response.version, 2);
+        assert_eq!(test_app.app_state.mongodb.fetch_schema("Multiple-Choice".to_string()).await.unwrap().unwrap().id, response.id);
+
+        let first_version = test_app.app_state.mongodb.fetch_schema_version("Multiple-Choice".to_string(), 1).await.unwrap().unwrap();
+
+        util::assert_schma(&first_version, &schema);
+        assert_eq!(test_app.app_state.mongodb.fetch_task(task.id, false).await.unwrap().unwrap().schema_version, 1);
+    }
+
+    /// # Test: `test_deprecate_schema`
+    ///
+    /// Validates that a deprecated task type blocks new tasks while existing tasks stay readable.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Delete` permission for schemas and the `Create` permission for tasks.
+    ///    - Creates a schema and a task.
+    ///
+    /// 2. Execution:
+    ///    - Calls `deprecate_schema` for the task type and for an unknown task type, then `create_task`.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the task type is deprecated with "NO CONTENT" (204), the unknown one is "NOT FOUND" (404).
+    ///    - Verifies creating a task is rejected with "BAD REQUEST" (400) and the existing task can still be fetched.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_deprecate_schema() {
+        use crate::handlers::tasks::tasks::{deprecate_schema, create_task};
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "schema", &created_user.id, &AccessType::Delete);
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Create);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let resp = test_app
+            .call(
+                &get_path("Multiple-Choice", "deprecate"),
+                SCOPE,
+                deprecate_schema,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let resp = test_app
+            .call(
+                &get_path("Unknown", "deprecate"),
+                SCOPE,
+                deprecate_schema,
+                test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        let resp = test_app
+            .call(
+                "/api/tasks/",
+                SCOPE,
+                create_task,
+                test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({
+                    "task_type": "Multiple-Choice",
+                    "task": {
+                        "question": "h",
+                        "answers": ["f"],
+                    },
+                    "solution": {
+                        "solution": 0,
+                    }
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        assert!(test_app.app_state.mongodb.fetch_task(task.id, true).await.unwrap().is_some());
+    }
+
+    /// # Test: `test_check_schema`
+    ///
+    /// Validates that the tasks and answers failing a proposed schema are listed.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Read` permission for schemas, a schema and a task.
+    ///    - Adds the task to a task package and answers it in two solution attempts, once correct and once with
+    ///      an answer out of the proposed range.
+    ///
+    /// 2. Execution:
+    ///    - Calls `check_schema` with a solution schema limited to the first ten answers.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies the task is not listed, as it matches the proposed schema.
+    ///    - Verifies only the answer out of range is listed.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_check_schema() {
+        use crate::handlers::tasks::tasks::check_schema;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "schema", &created_user.id, &AccessType::Read);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let correct_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+        let incorrect_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        util::create_correct_answer(&test_app.app_state.mongodb, &test_app.group_repo, &task, &correct_attempt.solution_list[0].answer_id).await;
+        util::create_incorrect_answer(&test_app.app_state.mongodb, &test_app.group_repo, &incorrect_attempt.solution_list[0].answer_id).await;
+
+        let resp = test_app
+            .call(
+                &get_path("Multiple-Choice", "check"),
+                SCOPE,
+                check_schema,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_limited_body()), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: SchemaCheckResponse = test::read_body_json(resp).await;
+
+        assert!(response.tasks.is_empty());
+        assert_eq!(response.answers.len(), 1);
+        assert_eq!(response.answers[0].id, incorrect_attempt.solution_list[0].answer_id);
+    }
+
+    /// # Test: `test_check_schema_template`
+    ///
+    /// Validates that task templates are checked through their sample instance, as their placeholders do not fit
+    /// the schema.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Read` permission for schemas, a schema and a template whose solution is
+    ///      a placeholder for one of the first four answers.
+    ///
+    /// 2. Execution:
+    ///    - Calls `check_schema` with a solution schema limited to the first ten answers.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the template is not listed.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_check_schema_template() {
+        use crate::handlers::tasks::tasks::check_schema;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "schema", &created_user.id, &AccessType::Read);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        test_app.app_state.mongodb.create_task(
+            "Multiple-Choice".to_string(),
+            doc!{ "question": "Welche Antwort ist {{a}}?", "answers": ["0", "1", "2", "3"] },
+            doc!{ "solution": "{{a}}" },
+            Some(doc!{ "a": { "type": "integer", "min": 0, "max": 3 } }),
+            1,
+            TaskMetadata::default(),
+            None,
+        ).await.unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path("Multiple-Choice", "check"),
+                SCOPE,
+                check_schema,
+                test_app.valid_authorizate(TestRequest::post().set_json(create_limited_body()), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: SchemaCheckResponse = test::read_body_json(resp).await;
+
+        assert!(response.tasks.is_empty());
+    }
+}
@@ -1,3 +1,3 @@
 pub mod delete_task;
 pub mod fetch_task;
-pub mod fetch_solution;
\ No newline at end of file
+pub mod fetch_solution;pub mod update_task;
@@ -0,0 +1,173 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod update_task_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use serde_json::Value;
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::AccessType, task::{CreateTaskResponse, NewTempTask}}};
+
+    static SCOPE: &str = "/api/tasks/{task_id}";
+
+    fn get_path(task_id: &Uuid) -> String {
+        format!("/api/tasks/{}/", task_id)
+    }
+
+    fn create_body() -> Value {
+        serde_json::json!({
+            "task": {
+                "question": "Wie viele Bits ergeben ein Nibble?",
+                "answers": ["4", "8"],
+            },
+            "solution": {
+                "solution": 0,
+            }
+        })
+    }
+
+    /// # Test: `test_update_task`
+    ///
+    /// Validates that updating a task stores a new revision while existing answers stay pinned to the revision
+    /// they were given.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user with the global `Write` permission for tasks, a schema and a multiple-choice task.
+    ///    - Adds the task to a task package and starts a solution attempt.
+    ///
+    /// 2. Execution:
+    ///    - Calls `update_task` and starts a second solution attempt afterwards.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the task is at revision 2.
+    ///    - Verifies the answer of the first attempt is pinned to revision 1, the answer of the second to revision 2.
+    ///    - Verifies revision 1 still contains the original task and solution.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_task() {
+        use crate::handlers::tasks::task_id::task_id::update_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Write);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let task_package = util::create_task_package(&test_app.group_repo, &vec![NewTempTask {
+            task_doc_id: task.id,
+            task_type: task.task_type.clone(),
+        }], &created_groups[0].id);
+
+        let first_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let resp = test_app
+            .call(
+                &get_path(&task.id),
+                SCOPE,
+                update_task,
+                test_app.valid_authorizate(TestRequest::patch().set_json(create_body()), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: CreateTaskResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.task.id, task.id);
+        assert_eq!(response.task.revision, 2);
+        assert_eq!(response.task.task.get_str("question").unwrap(), "Wie viele Bits ergeben ein Nibble?");
+
+        let second_attempt = util::create_solution_attempt(&test_app.group_repo, &created_user.id, &task_package.id, &created_groups[0].id);
+
+        let first_answer = test_app.group_repo.fetch_answer(&first_attempt.solution_list[0].answer_id, &created_groups[0].id).unwrap();
+        let second_answer = test_app.group_repo.fetch_answer(&second_attempt.solution_list[0].answer_id, &created_groups[0].id).unwrap();
+
+        assert_eq!(first_answer.task_revision, 1);
+        assert_eq!(second_answer.task_revision, 2);
+
+        let first_revision = test_app.app_state.mongodb.fetch_task_revision(task.id, 1).await.unwrap().unwrap();
+
+        assert_eq!(first_revision.task, task.task);
+        assert_eq!(first_revision.solution, task.solution);
+    }
+
+    /// # Test: `test_update_task_wrong_format`
+    ///
+    /// Validates that a task not matching the schema of its task type is rejected with "BAD REQUEST" (400)
+    /// and no revision is stored.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_task_wrong_format() {
+        use crate::handlers::tasks::task_id::task_id::update_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Write);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        let resp = test_app
+            .call(
+                &get_path(&task.id),
+                SCOPE,
+                update_task,
+                test_app.valid_authorizate(TestRequest::patch().set_json(serde_json::json!({
+                    "task": {
+                        "questio": "h",
+                        "answers": ["f"],
+                    },
+                    "solution": {
+                        "solution": 0,
+                    }
+                })), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let fetched_task = test_app.app_state.mongodb.fetch_task(task.id, false).await.unwrap().unwrap();
+
+        assert_eq!(fetched_task.revision, 1);
+        assert_eq!(fetched_task.task, task.task);
+    }
+
+    /// # Test: `test_update_task_deleted`
+    ///
+    /// Validates that a deleted task cannot be updated and "NOT FOUND" (404) is returned.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_update_task_deleted() {
+        use crate::handlers::tasks::task_id::task_id::update_task;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        util::create_permissions(&test_app.permission_repo, &test_app.group_repo, "task", &created_user.id, &AccessType::Write);
+
+        util::create_schema(&test_app.app_state.mongodb).await;
+
+        let task = util::create_task_mc(&test_app.app_state.mongodb).await;
+
+        test_app.app_state.mongodb.delete_task(&task.id).await.unwrap();
+
+        let resp = test_app
+            .call(
+                &get_path(&task.id),
+                SCOPE,
+                update_task,
+                test_app.valid_authorizate(TestRequest::patch().set_json(create_body()), &created_user.id),
+            )
+            .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        assert!(test_app.app_state.mongodb.fetch_task_revision(task.id, 2).await.unwrap().is_none());
+    }
+}
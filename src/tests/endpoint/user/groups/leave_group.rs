@@ -0,0 +1,210 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod leave_group_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::NewRessource, util::AccessType, group_settings::GroupSettings, members::MemberInfoResponse, roles::{NewRole, UpdateRolePermission, NewRolePermission, UpdateRoleAccesType}}, repository::group::ADD_MEMBER_ROLE_KEY};
+
+    static SCOPE: &str = "/api/user/groups";
+
+    fn get_path(group_id: &Uuid) -> String {
+        format!("/api/user/groups/{}/leave", group_id)
+    }
+
+    /// Lets new members manage the members of their group.
+    fn create_member_manager_role(test_app: &TestRepo) {
+        util::create_ressource(&test_app.permission_repo, &vec![(NewRessource {
+            key_name: &"group_member".to_string(),
+            key_value: &"group_member".to_string(),
+        }, vec![AccessType::Write])]);
+
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &"Member".to_string(),
+            value_key: &ADD_MEMBER_ROLE_KEY.to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "group_member".to_string(),
+                role: ADD_MEMBER_ROLE_KEY.to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type: AccessType::Write,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+    }
+
+    /// # Test: `test_leave_group`
+    ///
+    /// Validates that a member can leave a group without any group permission.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group, the second user is a member of the group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `leave_group` as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200) and the former membership is returned.
+    ///    - Verifies the user is no longer a member of the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_leave_group() {
+        use crate::handlers::user::groups::groups::leave_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let member = util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            leave_group,
+            test_app.valid_authorizate(TestRequest::post(), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: MemberInfoResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.id, member.member_id);
+        assert!(!test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_leave_group_disabled`
+    ///
+    /// Validates that members cannot leave a group whose settings disable leaving.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group with `leaving_disabled`, the second user is a member of the group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `leave_group` as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "FORBIDDEN" (403) and the user is still a member.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_leave_group_disabled() {
+        use crate::handlers::user::groups::groups::leave_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        test_app.group_repo.update_group_settings(&created_groups[0].id, &GroupSettings {
+            leaving_disabled: true,
+            ..Default::default()
+        }, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            leave_group,
+            test_app.valid_authorizate(TestRequest::post(), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        assert!(test_app.group_repo.is_member_of_group(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_leave_group_last_manager`
+    ///
+    /// Validates that the last member able to manage the members of a group cannot leave it.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and a group, new members get the `Write` access type on the "group_member" ressource.
+    ///    - Adds the first user to the group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `leave_group` as the first user, adds the second user and calls `leave_group` again.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the first call is rejected with "CONFLICT" (409).
+    ///    - Verifies the second call succeeds with "OK" (200), as the second user can manage the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_leave_group_last_manager() {
+        use crate::handlers::user::groups::groups::leave_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_member_manager_role(&test_app);
+
+        util::add_member_to_group(&test_app.group_repo, &created_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            leave_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            leave_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        assert!(!test_app.group_repo.is_last_group_manager(&created_groups[0].id, &created_user.id).unwrap());
+        assert!(test_app.group_repo.is_last_group_manager(&created_groups[0].id, &other_user.id).unwrap());
+    }
+
+    /// # Test: `test_leave_group_not_member`
+    ///
+    /// Validates that a user who is not a member of a group gets "NOT FOUND" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_leave_group_not_member() {
+        use crate::handlers::user::groups::groups::leave_group;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let resp = test_app
+        .call(
+            &get_path(&created_groups[0].id),
+            SCOPE,
+            leave_group,
+            test_app.valid_authorizate(TestRequest::post(), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -1 +1,2 @@
-pub mod get_groups;
\ No newline at end of file
+pub mod get_groups;
+pub mod leave_group;
\ No newline at end of file
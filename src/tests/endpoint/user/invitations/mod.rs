@@ -0,0 +1 @@
+pub mod redeem_invitation;
\ No newline at end of file
@@ -0,0 +1,393 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod redeem_invitation_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+    use chrono::{Utc, Duration};
+    use uuid::Uuid;
+
+    use crate::{tests::{util, test::TestRepo}, models::{permissions::NewRessource, util::{AccessType, PagingSchema, OrderDir}, invitations::{NewGroupInvitation, RedeemedInvitationResponse, GroupInvitation}, members::MemberFilter, roles::{NewRole, UpdateRolePermission, NewRolePermission, UpdateRoleAccesType}}, repository::group::ADD_MEMBER_ROLE_KEY};
+
+    static SCOPE: &str = "/api/user/invitations";
+    static PATH: &str = "/api/user/invitations/redeem";
+
+    /// Creates the "group" ressource and a role which grants the given access type on it.
+    fn create_role_with_group_access(test_app: &TestRepo, role: &str, access_type: AccessType) {
+        util::create_role(&test_app.permission_repo, &vec![NewRole {
+            name: &role.to_string(),
+            value_key: &role.to_string(),
+        }]);
+
+        util::update_role(&test_app.permission_repo, &UpdateRolePermission {
+            role_permission: NewRolePermission {
+                ressource: "group".to_string(),
+                role: role.to_string(),
+            },
+            role_access_types: vec![
+                UpdateRoleAccesType {
+                    access_type,
+                    permission: Some(true),
+                    set_permission: None,
+                    set_set_permission: None,
+                }
+            ]
+        });
+    }
+
+    fn create_group_ressource(test_app: &TestRepo) {
+        util::create_ressource(
+            &test_app.permission_repo,
+            &vec![(NewRessource {
+                key_name: &"Group".to_string(),
+                key_value: &"group".to_string(),
+            }, vec![AccessType::Read, AccessType::Write])
+            ],
+        );
+    }
+
+    fn create_invitation(test_app: &TestRepo, group_id: &Uuid, created_from: &Uuid, new_invitation: NewGroupInvitation) -> GroupInvitation {
+        test_app.group_repo.create_invitation(&NewGroupInvitation {
+            group_id: *group_id,
+            created_from: *created_from,
+            ..new_invitation
+        }).unwrap()
+    }
+
+    fn empty_invitation() -> NewGroupInvitation {
+        NewGroupInvitation {
+            group_id: Uuid::nil(),
+            email: None,
+            role: None,
+            max_uses: None,
+            expires_at: None,
+            created_from: Uuid::nil(),
+        }
+    }
+
+    /// # Test: `test_redeem_invitation`
+    ///
+    /// Validates that a user joins the group with the permissions of the default member role when redeeming a code.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group of the first user and an invitation without a role.
+    ///    - Creates the default member role which grants `Read` on the "group" ressource.
+    ///
+    /// 2. Execution:
+    ///    - Calls `redeem_invitation` as the second user with the lowercased code.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201) and contains the new member.
+    ///    - Verifies the user received the `Read` permission for the group and the uses were incremented.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_ressource(&test_app);
+        create_role_with_group_access(&test_app, ADD_MEMBER_ROLE_KEY, AccessType::Read);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, empty_invitation());
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code.to_lowercase()})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let response: RedeemedInvitationResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.group_id, created_groups[0].id);
+        assert_eq!(response.member.user.id, other_user.id);
+
+        let access_types = test_app.permission_repo.user_has_permission(
+            &other_user.id,
+            &"group".to_string(),
+            &Some(created_groups[0].id),
+        ).unwrap();
+        assert_eq!(access_types, vec![AccessType::Read]);
+
+        let invitations = test_app.group_repo.fetch_invitations(&created_groups[0].id).unwrap();
+        assert_eq!(invitations[0].uses, 1);
+    }
+
+    /// # Test: `test_redeem_invitation_with_role`
+    ///
+    /// Validates that the member receives the permissions of the role of the invitation.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users, a group and a "tutor" role which grants `Write` on the "group" ressource.
+    ///    - Creates an invitation with the "tutor" role.
+    ///
+    /// 2. Execution:
+    ///    - Calls `redeem_invitation` as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201) and the user has the `Write` permission for the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation_with_role() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_group_ressource(&test_app);
+        create_role_with_group_access(&test_app, "tutor", AccessType::Write);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, NewGroupInvitation {
+            role: Some("tutor".to_string()),
+            ..empty_invitation()
+        });
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let access_types = test_app.permission_repo.user_has_permission(
+            &other_user.id,
+            &"group".to_string(),
+            &Some(created_groups[0].id),
+        ).unwrap();
+        assert_eq!(access_types, vec![AccessType::Write]);
+    }
+
+    /// # Test: `test_redeem_invitation_used_up`
+    ///
+    /// Validates that an invitation cannot be redeemed more often than its maximum of uses.
+    ///
+    /// 1. Setup:
+    ///    - Creates three users, a group and an invitation with a maximum of one use.
+    ///    - The second user redeems the invitation.
+    ///
+    /// 2. Execution:
+    ///    - Calls `redeem_invitation` as the third user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Gone" (410).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation_used_up() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+        use crate::models::auth::RegisterUserSchema;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+        let third_user = util::create_test_user(&RegisterUserSchema {
+            email: "third@test.de".to_string(),
+            name: "Third".to_string(),
+            password: "123".to_string(),
+        }, &test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, NewGroupInvitation {
+            max_uses: Some(1),
+            ..empty_invitation()
+        });
+
+        test_app.group_repo.redeem_invitation(&invitation, &other_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code})), &third_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::GONE);
+    }
+
+    /// # Test: `test_redeem_invitation_expired`
+    ///
+    /// Validates that an expired invitation cannot be redeemed.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation_expired() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, NewGroupInvitation {
+            expires_at: Some((Utc::now() - Duration::days(1)).naive_utc()),
+            ..empty_invitation()
+        });
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::GONE);
+    }
+
+    /// # Test: `test_redeem_invitation_other_email`
+    ///
+    /// Validates that an invitation restricted to an email cannot be redeemed by another user.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation_other_email() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, NewGroupInvitation {
+            email: Some("someone@test.de".to_string()),
+            ..empty_invitation()
+        });
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    /// # Test: `test_redeem_invitation_already_member`
+    ///
+    /// Validates that a member of the group cannot redeem an invitation of the group and no use is counted.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_invitation_already_member() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        util::add_member_to_group(&test_app.group_repo, &other_user.id, &created_groups[0].id);
+
+        let invitation = create_invitation(&test_app, &created_groups[0].id, &created_user.id, empty_invitation());
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": invitation.code})), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CONFLICT);
+
+        let invitations = test_app.group_repo.fetch_invitations(&created_groups[0].id).unwrap();
+        assert_eq!(invitations[0].uses, 0);
+    }
+
+    /// # Test: `test_redeem_unknown_invitation`
+    ///
+    /// Validates that redeeming an unknown code returns "Not Found" (404).
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_redeem_unknown_invitation() {
+        use crate::handlers::user::invitations::invitations::redeem_invitation;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            redeem_invitation,
+            test_app.valid_authorizate(TestRequest::post().set_json(serde_json::json!({"code": "UNKNOWN123"})), &created_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    /// # Test: `test_register_redeems_email_invitation`
+    ///
+    /// Validates that an invitation restricted to an email is redeemed when a user with that email registers.
+    ///
+    /// 1. Setup:
+    ///    - Creates a user, a group and an invitation for "New@Test.de".
+    ///
+    /// 2. Execution:
+    ///    - Calls `register` with the email "new@test.de".
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "Created" (201).
+    ///    - Verifies the registered user is a member of the group.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_register_redeems_email_invitation() {
+        use crate::handlers::auth::auth::register;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 1, created_user.id);
+
+        create_invitation(&test_app, &created_groups[0].id, &created_user.id, NewGroupInvitation {
+            email: Some("new@test.de".to_string()),
+            ..empty_invitation()
+        });
+
+        let resp = test_app
+        .call(
+            "/api/auth/register",
+            "/api/auth",
+            register,
+            TestRequest::post().set_json(serde_json::json!({
+                "email": "New@Test.de",
+                "name": "New",
+                "password": "123",
+            })),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let members = test_app.group_repo.fetch_all_member_of_group(&created_groups[0].id, &MemberFilter::default(), &PagingSchema {
+            limit: 10,
+            page: 0,
+            order: OrderDir::DESC,
+        }).unwrap();
+
+        assert_eq!(members.total_count, 1);
+        assert_eq!(members.member_list[0].email, "new@test.de");
+    }
+}
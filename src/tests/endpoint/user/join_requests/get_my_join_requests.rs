@@ -0,0 +1,71 @@
+//docu written with chat-gpt
+#[cfg(test)]
+mod get_my_join_requests_tests {
+    use actix_web::{test::{TestRequest, self}, http};
+
+    use crate::{tests::{util, test::TestRepo}, models::{util::JoinRequestState, join_requests::{JoinRequestListResponse, NewGroupJoinRequest}}};
+
+    static SCOPE: &str = "/api/user/join_requests";
+    static PATH: &str = "/api/user/join_requests/";
+
+    /// # Test: `test_get_my_join_requests`
+    ///
+    /// Validates that a user only sees their own join requests for active groups.
+    ///
+    /// 1. Setup:
+    ///    - Creates two users and three groups.
+    ///    - Creates join requests of the second user for all groups and of the first user for the first group.
+    ///    - Deletes the third group.
+    ///
+    /// 2. Execution:
+    ///    - Calls `get_my_join_requests` as the second user.
+    ///
+    /// 3. Assertions:
+    ///    - Verifies the response status is "OK" (200).
+    ///    - Verifies only the requests of the second user for the two active groups are returned.
+    #[actix_web::test]
+    #[serial_test::serial]
+    async fn test_get_my_join_requests() {
+        use crate::handlers::user::join_requests::join_requests::get_my_join_requests;
+
+        let test_app = TestRepo::new().await;
+
+        let created_user = util::create_standard_test_user(&test_app.user_repo);
+        let other_user = util::create_other_test_user(&test_app.user_repo);
+
+        let created_groups = util::create_example_groups(&test_app.group_repo, 3, created_user.id);
+
+        for (group, user) in [
+            (&created_groups[0], &other_user),
+            (&created_groups[1], &other_user),
+            (&created_groups[2], &other_user),
+            (&created_groups[0], &created_user),
+        ] {
+            test_app.group_repo.create_join_request(&NewGroupJoinRequest {
+                group_id: group.id,
+                user_id: user.id,
+                message: None,
+                state: JoinRequestState::Pending,
+                decided_at: None,
+            }).unwrap();
+        }
+
+        test_app.group_repo.delete_group(&created_groups[2].id, &created_user.id).unwrap();
+
+        let resp = test_app
+        .call(
+            PATH,
+            SCOPE,
+            get_my_join_requests,
+            test_app.valid_authorizate(TestRequest::get(), &other_user.id),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let response: JoinRequestListResponse = test::read_body_json(resp).await;
+
+        assert_eq!(response.join_requests.len(), 2);
+        assert!(response.join_requests.iter().all(|join_request| join_request.user.id == other_user.id));
+        assert!(response.join_requests.iter().all(|join_request| join_request.group_id != created_groups[2].id));
+    }
+}
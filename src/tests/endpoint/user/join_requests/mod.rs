@@ -0,0 +1 @@
+pub mod get_my_join_requests;
\ No newline at end of file
@@ -1,3 +1,5 @@
 pub mod me;
 pub mod permissions;
-pub mod groups;
\ No newline at end of file
+pub mod groups;
+pub mod invitations;
+pub mod join_requests;
\ No newline at end of file
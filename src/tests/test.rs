@@ -1,7 +1,7 @@
 use actix_web::{App, web::{Data, self}, test::{self, TestRequest}, dev::{ServiceResponse, HttpServiceFactory}, http::header::AUTHORIZATION};
 use uuid::Uuid;
 
-use crate::{AppState, repository::{users::UsersRepo, group::GroupRepo, permissions::PermissionsRepo}};
+use crate::{AppState, handlers::api, repository::{users::UsersRepo, group::GroupRepo, permissions::PermissionsRepo}};
 use crate::tests::util;
 
 
@@ -55,4 +55,16 @@ impl TestRepo{
 
         request.uri(path).send_request(&mut app).await
     }
+
+    /// Sends the request to an app routed like the server in `main`, so the handlers only get the repositories
+    /// the scopes of `api::config` register.
+    pub async fn call_api(&self, path: &str, request: TestRequest) -> ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(self.app_state.clone()))
+                .configure(|cfg| api::config(cfg, self.app_state.pgdb.clone())),
+        ).await;
+
+        request.uri(path).send_request(&app).await
+    }
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{Duration, Utc};
 use jsonwebtoken::{encode, EncodingKey, Header};
 
@@ -26,6 +28,7 @@ use crate::models::permissions::OptionalUserAccessType;
 use crate::models::permissions::PermissionListResponse;
 use crate::repository::mongodb::MongoDbRepo;
 use crate::models::task::TaskDoc;
+use crate::models::task::TaskMetadata;
 use mongodb::bson::doc;
 use crate::models::task::TaskResponse;
 
@@ -37,10 +40,12 @@ use crate::models::permissions::PermissionInfo;
 use crate::models::{util::TaskPackageType, task::NewTempTask};
 use crate::models::task_package::CreatedTaskPackage;
 use crate::models::util::Visibility;
+use crate::models::util::JoinPolicy;
 use crate::models::solution_attempts::SolutionAttempt;
 use crate::schema::solution_attempts::visibility;
 use crate::models::members::MemberInfo;
 use crate::models::answer::AnswerDoc;
+use crate::grading::{cyk::CykGrader, grader::Grader};
 
 pub fn create_standard_test_user(user_repo: &UsersRepo) -> UserInfo {
     create_test_user(
@@ -167,12 +172,12 @@ pub fn create_task_package(group_repo: &GroupRepo, tasks: &Vec<NewTempTask>, gro
 
 #[cfg(test)]
 pub fn create_solution_attempt(group_repo: &GroupRepo, user_id: &Uuid, task_package_id: &Uuid, group_id: &Uuid) -> SolutionAttempt {
-    group_repo.create_solution_attempt(user_id, task_package_id, group_id, &None).unwrap()
+    group_repo.create_solution_attempt(user_id, task_package_id, group_id, &None, &HashMap::new(), &HashMap::new()).unwrap()
 }
 
 #[cfg(test)]
 pub fn create_private_solution_attempt(group_repo: &GroupRepo, user_id: &Uuid, task_package_id: &Uuid, group_id: &Uuid) -> SolutionAttempt {
-    group_repo.create_solution_attempt(user_id, task_package_id, group_id, &Some(Visibility::Private)).unwrap()
+    group_repo.create_solution_attempt(user_id, task_package_id, group_id, &Some(Visibility::Private), &HashMap::new(), &HashMap::new()).unwrap()
 }
 
 #[cfg(test)]
@@ -185,6 +190,20 @@ pub fn add_member_to_group(group_repo: &GroupRepo, user_id: &Uuid, group_id: &Uu
     }).unwrap()
 }
 
+#[cfg(test)]
+pub fn set_join_policy(group_repo: &GroupRepo, group_id: &Uuid, join_policy: JoinPolicy) {
+    use crate::models::groups::UpdateGroup;
+
+    let group_meta_data = group_repo.fetch_active_group_meta_data(*group_id).unwrap();
+
+    group_repo.update_group(group_id, &UpdateGroup {
+        name: None,
+        parent: None,
+        join_policy: Some(join_policy),
+        updated_from: group_meta_data.updated_from,
+    }).unwrap();
+}
+
 #[cfg(test)]
 pub fn create_valid_jwt_token(user_id: &Uuid, app_state: &AppState) -> String {
     let now = Utc::now();
@@ -343,7 +362,22 @@ pub async fn create_task_mc(
         "answers": ["1", "2", "3"]
     }, doc!{
         "solution": 0
-    }).await.unwrap()
+    }, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates a `Multiple-Choice` task with the given question and metadata.
+#[cfg(test)]
+pub async fn create_task_with_metadata(
+    mongodb_repo: &MongoDbRepo,
+    question: &str,
+    metadata: TaskMetadata,
+) -> TaskDoc {
+    mongodb_repo.create_task("Multiple-Choice".to_string(), doc!{
+        "question": question,
+        "answers": ["1", "2", "3"]
+    }, doc!{
+        "solution": 0
+    }, None, 1, metadata, None).await.unwrap()
 }
 
 #[cfg(test)]
@@ -378,7 +412,117 @@ pub async fn create_task_other(
         "answers": ["1", "2", "3"]
     }, doc!{
         "solution": 0
-    }).await.unwrap()
+    }, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+#[cfg(test)]
+pub async fn create_task_cyk(
+    mongodb_repo: &MongoDbRepo
+) -> TaskDoc {
+    let task = doc!{
+        "V": ["S", "A", "B", "C"],
+        "T": ["a", "b"],
+        "P": [
+            { "l": "S", "r": [["B", "A"], ["C", "A"], ["b"]] },
+            { "l": "A", "r": [["B", "A"], ["a"]] },
+            { "l": "B", "r": [["C", "C"], ["b"]] },
+            { "l": "C", "r": [["A", "B"], ["a"]] },
+        ],
+        "w": "abba",
+        "S": "S",
+    };
+    let solution = CykGrader.solve(&task).unwrap();
+
+    mongodb_repo.create_task("CYK-Algorithm".to_string(), task, solution, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates a finite-automaton task whose solution is a DFA accepting the words over `{a, b}` with an even number of `a`.
+#[cfg(test)]
+pub async fn create_task_automaton(
+    mongodb_repo: &MongoDbRepo
+) -> TaskDoc {
+    mongodb_repo.create_task("Finite-Automaton".to_string(), doc!{
+        "question": "Gib einen DFA an, der alle Wörter über {a, b} mit einer geraden Anzahl an a akzeptiert.",
+        "alphabet": ["a", "b"],
+        "deterministic": true
+    }, doc!{
+        "states": ["g", "u"],
+        "alphabet": ["a", "b"],
+        "transitions": [
+            { "from": "g", "symbol": "a", "to": "u" },
+            { "from": "g", "symbol": "b", "to": "g" },
+            { "from": "u", "symbol": "a", "to": "g" },
+            { "from": "u", "symbol": "b", "to": "u" },
+        ],
+        "start": "g",
+        "accepting": ["g"]
+    }, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates a Python programming task asking for the sum of two numbers with two hidden test cases.
+#[cfg(test)]
+pub async fn create_task_programming(
+    mongodb_repo: &MongoDbRepo
+) -> TaskDoc {
+    mongodb_repo.create_task("Programming".to_string(), doc!{
+        "question": "Schreibe ein Programm, das zwei ganze Zahlen von der Standardeingabe liest und ihre Summe ausgibt.",
+        "language": "python",
+        "starter_code": "a, b = map(int, input().split())\n",
+        "limits": { "time_ms": 1000, "memory_mb": 64 }
+    }, doc!{
+        "code": "a, b = map(int, input().split())\nprint(a + b)\n",
+        "tests": [
+            { "name": "Kleine Zahlen", "input": "1 2\n", "output": "3\n" },
+            { "name": "Negative Zahlen", "input": "-4 7\n", "output": "3\n" },
+        ]
+    }, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates an SQL task asking for the students in at least their third semester, compared as multiset.
+#[cfg(test)]
+pub async fn create_task_sql(
+    mongodb_repo: &MongoDbRepo
+) -> TaskDoc {
+    mongodb_repo.create_task("SQL".to_string(), doc!{
+        "question": "Gib die Namen aller Studierenden aus, die mindestens im dritten Semester sind.",
+        "schema": "CREATE TABLE studierende (id INTEGER PRIMARY KEY, name TEXT NOT NULL, semester INTEGER NOT NULL);",
+        "data": "INSERT INTO studierende VALUES (1, 'Anna', 3), (2, 'Ben', 1), (3, 'Cem', 5), (4, 'Dana', 2);",
+        "ordered": false
+    }, doc!{
+        "query": "SELECT name FROM studierende WHERE semester >= 3;"
+    }, None, 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates a multiple-choice task template asking for the sum of two parameters, whose solution points to the correct answer.
+#[cfg(test)]
+pub async fn create_task_template_mc(
+    mongodb_repo: &MongoDbRepo
+) -> TaskDoc {
+    mongodb_repo.create_task("Multiple-Choice".to_string(), doc!{
+        "question": "Was ist {{a}} + {{b}}?",
+        "answers": ["{{a}} + {{b}} = {{a + b - 1}}", "{{a}} + {{b}} = {{a + b}}", "{{a}} + {{b}} = {{a + b + 1}}"]
+    }, doc!{
+        "solution": 1
+    }, Some(doc!{
+        "a": { "type": "integer", "min": 1, "max": 50 },
+        "b": { "type": "integer", "min": 1, "max": 50 },
+    }), 1, TaskMetadata::default(), None).await.unwrap()
+}
+
+/// Creates the schema of a built-in task type as shipped in `src/assets/task_schemas/schemas.json`.
+#[cfg(test)]
+pub async fn create_asset_schema(
+    mongodb_repo: &MongoDbRepo,
+    task_type: &str,
+) -> SchemaDoc {
+    let schemas: Vec<serde_json::Value> = serde_json::from_str(include_str!("../assets/task_schemas/schemas.json")).unwrap();
+    let schema = schemas.into_iter().find(|schema| schema["task_type"] == task_type).unwrap();
+
+    mongodb_repo.create_schema_doc(
+        mongodb::bson::to_document(&schema["task_schema"]).unwrap(),
+        mongodb::bson::to_document(&schema["solution_schema"]).unwrap(),
+        task_type.to_string(),
+    ).await.unwrap()
 }
 
 #[cfg(test)]